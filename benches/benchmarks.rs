@@ -1,5 +1,15 @@
+use std::convert::TryInto;
+
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use spaceform::base::{Matrix, Quaternion, Vector};
+use spaceform::{
+	base::{Matrix, Quaternion, Vector},
+	shapes::{Frustum, Sphere},
+	Bounds3,
+	Direction,
+	Point,
+	Rotation,
+	Transform,
+};
 
 fn component_arithmetic(c: &mut Criterion) {
 	let vec = black_box(Vector::new(1f32, 2f32, 3f32, 4f32));
@@ -329,6 +339,102 @@ fn quats_load(c: &mut Criterion) {
 	});
 }
 
+/// Map `x` to a pseudo-random value in [-1, 1).
+fn hash(x: u32) -> f32 { (x.wrapping_mul(2654435761) >> 8) as f32 / (1u32 << 24) as f32 * 2f32 - 1f32 }
+
+fn culling(c: &mut Criterion) {
+	let frustum = Frustum::new(Transform::perspective(std::f32::consts::FRAC_PI_2, 1f32, 1f32, 100f32));
+	let points: Vec<_> = (0..10000u32)
+		.map(|idx| {
+			Point::new(
+				hash(idx * 3) * 60f32,
+				hash(idx * 3 + 1) * 60f32,
+				hash(idx * 3 + 2) * 120f32,
+			)
+		})
+		.collect();
+	let spheres: Vec<_> = points.iter().map(|&point| Sphere::new(point, 2f32)).collect();
+	let bounds: Vec<_> = points
+		.iter()
+		.map(|&point| Bounds3::new(point, point + Direction::new(2f32, 2f32, 2f32)))
+		.collect();
+	let mut visible = vec![false; points.len()];
+
+	c.bench_function("10000 sphere culls", |b| {
+		b.iter(|| frustum.cull_spheres(black_box(&spheres), black_box(&mut visible)))
+	});
+
+	c.bench_function("10000 naive sphere culls", |b| {
+		b.iter(|| {
+			for (&sphere, visible) in black_box(&spheres).iter().zip(black_box(&mut visible).iter_mut()) {
+				*visible = frustum.cull_sphere(sphere);
+			}
+		})
+	});
+
+	c.bench_function("10000 aabb culls", |b| {
+		b.iter(|| frustum.cull_aabbs(black_box(&bounds), black_box(&mut visible)))
+	});
+
+	c.bench_function("10000 naive aabb culls", |b| {
+		b.iter(|| {
+			for (&bounds, visible) in black_box(&bounds).iter().zip(black_box(&mut visible).iter_mut()) {
+				*visible = frustum.cull_aabb(bounds);
+			}
+		})
+	});
+}
+
+fn bulk_transforms(c: &mut Criterion) {
+	let transform = Transform::translate(Direction::new(1f32, 2f32, 3f32))
+		* Transform::rotate(Rotation::axis_angle(Direction::new(1f32, 1f32, 0f32), 0.7f32));
+	let points: Vec<_> = (0..10000u32)
+		.map(|idx| Point::new(hash(idx * 3), hash(idx * 3 + 1), hash(idx * 3 + 2)))
+		.collect();
+	let mut out = points.clone();
+
+	c.bench_function("10000 point transforms", |b| {
+		b.iter(|| transform.transform_points(black_box(&points), black_box(&mut out)))
+	});
+
+	c.bench_function("10000 naive point transforms", |b| {
+		b.iter(|| {
+			for (&point, out) in black_box(&points).iter().zip(black_box(&mut out).iter_mut()) {
+				*out = point * transform;
+			}
+		})
+	});
+
+	// Interleaved vertices with a position, a normal, and texture coordinates.
+	let stride = 32;
+	let mut vertices = vec![0u8; points.len() * stride];
+	for (vertex, point) in vertices.chunks_exact_mut(stride).zip(points.iter()) {
+		for (bytes, val) in vertex.chunks_exact_mut(4).zip([point.x(), point.y(), point.z()].iter()) {
+			bytes.copy_from_slice(&val.to_ne_bytes());
+		}
+	}
+
+	c.bench_function("10000 strided point transforms", |b| {
+		b.iter(|| transform.transform_points_strided(black_box(&mut vertices), 0, stride))
+	});
+
+	c.bench_function("10000 naive strided point transforms", |b| {
+		b.iter(|| {
+			for vertex in black_box(&mut vertices).chunks_exact_mut(stride) {
+				let mut vals = [0f32; 3];
+				for (val, bytes) in vals.iter_mut().zip(vertex.chunks_exact(4)) {
+					*val = f32::from_ne_bytes(bytes.try_into().unwrap());
+				}
+
+				let point = Point::new(vals[0], vals[1], vals[2]) * transform;
+				for (bytes, val) in vertex.chunks_exact_mut(4).zip([point.x(), point.y(), point.z()].iter()) {
+					bytes.copy_from_slice(&val.to_ne_bytes());
+				}
+			}
+		})
+	});
+}
+
 criterion_group!(
 	vector,
 	component_arithmetic,
@@ -340,4 +446,6 @@ criterion_group!(
 );
 criterion_group!(matrix, mul, others, mul_load, others_load);
 criterion_group!(quaternion, quats, quats_load);
-criterion_main!(vector, matrix, quaternion);
+criterion_group!(shapes, culling);
+criterion_group!(transforms, bulk_transforms);
+criterion_main!(vector, matrix, quaternion, shapes, transforms);
@@ -0,0 +1,243 @@
+//! Fuzz checks for spaceform, shared by the `cargo fuzz` targets and the corpus replay test.
+//!
+//! Every check reads little-endian `f32`s from the input, so the fuzzer can produce any bit pattern, including NaNs,
+//! infinities, and subnormals. Any input is allowed to reach the library, which must not panic or trip a debug
+//! assertion. Inputs that are [`moderate`] must also give finite, sensible results.
+
+use spaceform::{
+	approx::{ApproxEq, Tolerance},
+	base::{Matrix, Quaternion, Vector},
+	coordinate_system::CoordinateSystem,
+	Direction,
+	EulerAngles,
+	Normal,
+	Point,
+	Rotation,
+	RotationOrder,
+	Transform,
+};
+
+/// The largest magnitude [`moderate`] allows.
+pub const LIMIT: f32 = 1e4f32;
+
+/// Read `N` little-endian `f32`s from the start of `data`, or `None` if it is too short.
+pub fn floats<const N: usize>(data: &[u8]) -> Option<[f32; N]> {
+	if data.len() < N * 4 {
+		return None;
+	}
+
+	let mut values = [0f32; N];
+	for (value, bytes) in values.iter_mut().zip(data.chunks_exact(4)) {
+		*value = f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+	}
+	Some(values)
+}
+
+/// Check if `val` is finite and small enough that the math done on it should not overflow.
+pub fn moderate(val: f32) -> bool { val.is_finite() && val.abs() <= LIMIT }
+
+fn finite(vec: Vector) -> bool { <[f32; 4]>::from(vec).iter().all(|val| val.is_finite()) }
+
+fn assert_identity(transform: Transform, what: &str) {
+	assert!(
+		(transform * transform.inverse()).approx_eq(&Transform::identity(), Tolerance::Absolute(0.001f32)),
+		"{} is not inverted by its inverse: {:?}",
+		what,
+		transform
+	);
+}
+
+fn assert_unit(dir: Direction, what: &str) {
+	assert!(
+		dir.length().approx_eq(&1f32, Tolerance::Absolute(0.001f32)),
+		"{} is not normalized: {:?}",
+		what,
+		dir
+	);
+}
+
+/// [`Matrix::det`] and [`Matrix::inverse`] on 16 floats, in row-major order.
+pub fn matrix(data: &[u8]) {
+	let values = match floats::<16>(data) {
+		Some(values) => values,
+		None => return,
+	};
+	let matrix = Matrix::rows([
+		[values[0], values[1], values[2], values[3]],
+		[values[4], values[5], values[6], values[7]],
+		[values[8], values[9], values[10], values[11]],
+		[values[12], values[13], values[14], values[15]],
+	]);
+	let det = matrix.det();
+	let inverse = matrix.inverse();
+
+	if !values.iter().all(|&val| moderate(val)) {
+		return;
+	}
+	assert!(det.is_finite(), "det of {:?} is {}", matrix, det);
+
+	// Every cofactor is at most 6 * LIMIT^3, so dividing by a determinant this far from zero stays finite.
+	if det.abs() < 0.001f32 {
+		return;
+	}
+	assert!(
+		(0..4).all(|row| finite(inverse.get_row(row))),
+		"inverse of {:?} is {:?}",
+		matrix,
+		inverse
+	);
+}
+
+/// [`Quaternion::slerp`] on two quaternions and a factor, as 9 floats.
+pub fn slerp(data: &[u8]) {
+	let values = match floats::<9>(data) {
+		Some(values) => values,
+		None => return,
+	};
+	if !values.iter().all(|&val| moderate(val)) {
+		return;
+	}
+
+	// `slerp` asserts that its inputs are normalized, which needs them to not be too close to zero.
+	let from = Quaternion::new(values[0], values[1], values[2], values[3]);
+	let to = Quaternion::new(values[4], values[5], values[6], values[7]);
+	if Quaternion::dot(from, from) < 0.001f32 || Quaternion::dot(to, to) < 0.001f32 {
+		return;
+	}
+	let (from, to) = (from.normalize(), to.normalize());
+	let t = values[8];
+	if !(0f32..=1f32).contains(&t) {
+		return;
+	}
+
+	let result = Quaternion::slerp(from, to, t);
+	assert!(
+		Quaternion::dot(result, result).approx_eq(&1f32, Tolerance::Absolute(0.001f32)),
+		"slerp from {:?} to {:?} by {} is {:?}",
+		from,
+		to,
+		t,
+		result
+	);
+}
+
+/// [`Rotation::euler`] on pitch, yaw, and roll as 3 floats, followed by a byte for the [`RotationOrder`] and a byte for
+/// the [`CoordinateSystem`].
+pub fn euler(data: &[u8]) {
+	let [pitch, yaw, roll] = match floats::<3>(data) {
+		Some(values) => values,
+		None => return,
+	};
+	let order = match data.get(12).copied().unwrap_or(0) % 6 {
+		0 => RotationOrder::PYR,
+		1 => RotationOrder::PRY,
+		2 => RotationOrder::RPY,
+		3 => RotationOrder::RYP,
+		4 => RotationOrder::YPR,
+		_ => RotationOrder::YRP,
+	};
+	let system = if data.get(13).copied().unwrap_or(0) % 2 == 0 {
+		CoordinateSystem {
+			right: Direction::new(1f32, 0f32, 0f32),
+			forward: Direction::new(0f32, 0f32, 1f32),
+			up: Direction::new(0f32, 1f32, 0f32),
+		}
+	} else {
+		CoordinateSystem {
+			right: Direction::new(1f32, 0f32, 0f32),
+			forward: Direction::new(0f32, 1f32, 0f32),
+			up: Direction::new(0f32, 0f32, 1f32),
+		}
+	};
+
+	let rotation = Rotation::euler(
+		EulerAngles {
+			pitch,
+			yaw,
+			roll,
+			order,
+		},
+		system,
+	);
+	if ![pitch, yaw, roll].iter().all(|&val| moderate(val)) {
+		return;
+	}
+
+	// NaNs are never approximately equal, even to themselves.
+	assert!(
+		rotation.approx_eq(&rotation, Tolerance::Ulps(0)),
+		"euler rotation by {:?} is {:?}",
+		(pitch, yaw, roll),
+		rotation
+	);
+	let transform = Transform::rotate(rotation);
+	assert_identity(transform, "euler rotation");
+	assert_unit(system.forward * transform, "rotated forward direction");
+}
+
+/// [`Transform`] construction, and [`Direction`] and [`Normal`] normalization, on 17 floats: a translation, a scale,
+/// an axis and an angle, the field of view, aspect ratio, near, and far planes of a perspective projection, and a
+/// direction.
+pub fn transform(data: &[u8]) {
+	let values = match floats::<17>(data) {
+		Some(values) => values,
+		None => return,
+	};
+	let translation = Direction::new(values[0], values[1], values[2]);
+	let scale = Direction::new(values[3], values[4], values[5]);
+	let axis = Direction::new(values[6], values[7], values[8]);
+	let angle = values[9];
+	let [fov_y, aspect, near, far] = [values[10], values[11], values[12], values[13]];
+	let dir = Direction::new(values[14], values[15], values[16]);
+
+	let translate = Transform::translate(translation);
+	let scaled = Transform::scale(scale);
+	let rotate = Transform::rotate(Rotation::axis_angle(axis, angle));
+	let perspective = Transform::perspective(fov_y, aspect, near, far);
+	let composed = translate * rotate * scaled;
+	let _ = Point::new(values[14], values[15], values[16]) * composed;
+	let _ = Point::new(values[14], values[15], values[16]) * perspective;
+
+	let normalized = dir.normalize();
+	let normal = Normal::new(values[14], values[15], values[16]).normalize();
+	let mut all = [dir, translation, scale, axis, dir];
+	Direction::normalize_all(&mut all);
+
+	if !values.iter().all(|&val| moderate(val)) {
+		return;
+	}
+
+	assert_identity(translate, "translation");
+	if [scale.x(), scale.y(), scale.z()]
+		.iter()
+		.all(|val| val.abs() >= 0.001f32)
+	{
+		assert_identity(scaled, "scale");
+	}
+	let valid_axis = axis.length_square() >= 0.001f32;
+	if valid_axis {
+		assert_identity(rotate, "rotation");
+	}
+	if (0.01f32..=3.1f32).contains(&fov_y)
+		&& (0.01f32..=100f32).contains(&aspect)
+		&& (0.01f32..=100f32).contains(&near)
+		&& far >= near * 1.01f32
+	{
+		assert_identity(perspective, "perspective projection");
+	}
+
+	if dir.length_square() >= 0.001f32 {
+		assert_unit(normalized, "normalized direction");
+		assert_unit(Direction::new(normal.x(), normal.y(), normal.z()), "normalized normal");
+		if valid_axis {
+			assert_unit(normalized * rotate, "rotated direction");
+		}
+		assert!(
+			all[0].approx_eq(&normalized, Tolerance::Absolute(0.001f32))
+				&& all[4].approx_eq(&normalized, Tolerance::Absolute(0.001f32)),
+			"normalize_all disagrees with normalize: {:?} and {:?}",
+			all,
+			normalized
+		);
+	}
+}
@@ -0,0 +1,30 @@
+//! Replay the seed corpus through every check, without needing libFuzzer.
+
+use std::{fs, panic, path::Path};
+
+fn replay(target: &str, check: fn(&[u8])) {
+	let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("corpus").join(target);
+	let mut count = 0;
+	for entry in fs::read_dir(&dir).unwrap() {
+		let path = entry.unwrap().path();
+		let data = fs::read(&path).unwrap();
+		// The check's own panic message is printed first, so this only needs to say which seed it was.
+		if panic::catch_unwind(|| check(&data)).is_err() {
+			panic!("seed {} failed", path.display());
+		}
+		count += 1;
+	}
+	assert!(count > 0, "no seeds in {}", dir.display());
+}
+
+#[test]
+fn matrix() { replay("matrix", spaceform_fuzz::matrix) }
+
+#[test]
+fn slerp() { replay("slerp", spaceform_fuzz::slerp) }
+
+#[test]
+fn euler() { replay("euler", spaceform_fuzz::euler) }
+
+#[test]
+fn transform() { replay("transform", spaceform_fuzz::transform) }
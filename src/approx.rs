@@ -0,0 +1,294 @@
+//! Approximate equality, for comparing the results of floating-point math.
+//!
+//! Use [`assert_approx_eq!`](crate::assert_approx_eq) and [`assert_approx_ne!`](crate::assert_approx_ne) in tests
+//! instead of `assert_eq!`, since different backends can round differently.
+
+use crate::{
+	base::{DualQuaternion, Matrix, Quaternion, Vector, Vector8},
+	bounds::Bounds3,
+	direction::Direction,
+	normal::Normal,
+	point::Point,
+	rotation::Rotation,
+	transform::Transform,
+	wide::{Point3x4, Point3x8, Vector3x4, Vector3x8},
+};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+/// How far apart two floats can be while still being considered equal.
+///
+/// Equal floats, including infinities, are always approximately equal, and NaNs never are.
+pub enum Tolerance {
+	/// The difference must be at most this value. Best for values close to zero.
+	Absolute(f32),
+	/// The difference must be at most this fraction of the larger magnitude. Best for values far from zero.
+	Relative(f32),
+	/// There must be at most this many representable floats between the values. `-0` and `+0` are equal, but other
+	/// values with different signs are not.
+	Ulps(u32),
+}
+
+impl Default for Tolerance {
+	#[inline(always)]
+	fn default() -> Self { Self::Absolute(0.0001f32) }
+}
+
+impl Tolerance {
+	#[inline(always)]
+	/// Check if two floats are approximately equal.
+	pub fn eq(self, lhs: f32, rhs: f32) -> bool {
+		if lhs == rhs {
+			return true;
+		}
+		if lhs.is_nan() || rhs.is_nan() || lhs.is_infinite() || rhs.is_infinite() {
+			return false;
+		}
+
+		match self {
+			Self::Absolute(epsilon) => (lhs - rhs).abs() <= epsilon,
+			Self::Relative(epsilon) => (lhs - rhs).abs() <= epsilon * lhs.abs().max(rhs.abs()),
+			Self::Ulps(ulps) => {
+				lhs.is_sign_positive() == rhs.is_sign_positive()
+					&& (lhs.to_bits() as i64 - rhs.to_bits() as i64).unsigned_abs() <= ulps as u64
+			},
+		}
+	}
+}
+
+/// Approximate equality, component-wise, with a [`Tolerance`].
+pub trait ApproxEq {
+	/// Check if every component of `self` is approximately equal to `other`.
+	fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool;
+
+	#[inline(always)]
+	/// Check with [`Tolerance::Absolute`].
+	fn abs_eq(&self, other: &Self, epsilon: f32) -> bool { self.approx_eq(other, Tolerance::Absolute(epsilon)) }
+
+	#[inline(always)]
+	/// Check with [`Tolerance::Relative`].
+	fn relative_eq(&self, other: &Self, epsilon: f32) -> bool { self.approx_eq(other, Tolerance::Relative(epsilon)) }
+
+	#[inline(always)]
+	/// Check with [`Tolerance::Ulps`].
+	fn ulps_eq(&self, other: &Self, ulps: u32) -> bool { self.approx_eq(other, Tolerance::Ulps(ulps)) }
+}
+
+impl ApproxEq for f32 {
+	#[inline(always)]
+	fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool { tolerance.eq(*self, *other) }
+}
+
+impl<T: ApproxEq> ApproxEq for [T] {
+	#[inline(always)]
+	fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool {
+		self.len() == other.len()
+			&& self
+				.iter()
+				.zip(other.iter())
+				.all(|(lhs, rhs)| lhs.approx_eq(rhs, tolerance))
+	}
+}
+
+impl<T: ApproxEq, const N: usize> ApproxEq for [T; N] {
+	#[inline(always)]
+	fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool { self[..].approx_eq(&other[..], tolerance) }
+}
+
+impl ApproxEq for Vector {
+	#[inline(always)]
+	fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool {
+		<[f32; 4]>::from(*self).approx_eq(&(*other).into(), tolerance)
+	}
+}
+
+/// Implement [`ApproxEq`] for a 3D type by comparing x, y, and z.
+macro_rules! impl_xyz {
+	($($ty:ty),*) => {
+		$(
+			impl ApproxEq for $ty {
+				#[inline(always)]
+				fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool {
+					[self.x(), self.y(), self.z()].approx_eq(&[other.x(), other.y(), other.z()], tolerance)
+				}
+			}
+		)*
+	};
+}
+
+impl_xyz!(Point, Direction, Normal);
+
+impl ApproxEq for Quaternion {
+	#[inline(always)]
+	fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool { self.0.approx_eq(&other.0, tolerance) }
+}
+
+impl ApproxEq for Rotation {
+	#[inline(always)]
+	/// A [`Quaternion`] and its negation represent the same [`Rotation`], so either sign is equal.
+	fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool {
+		self.0.approx_eq(&other.0, tolerance) || self.0 .0.approx_eq(&-other.0 .0, tolerance)
+	}
+}
+
+impl ApproxEq for DualQuaternion {
+	#[inline(always)]
+	fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool {
+		self.real.approx_eq(&other.real, tolerance) && self.dual.approx_eq(&other.dual, tolerance)
+	}
+}
+
+impl ApproxEq for Matrix {
+	#[inline(always)]
+	fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool {
+		(0..4).all(|row| self.get_row(row).approx_eq(&other.get_row(row), tolerance))
+	}
+}
+
+impl ApproxEq for Transform {
+	#[inline(always)]
+	/// Only the matrices are compared, since the inverses follow from them.
+	fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool { self.matrix.approx_eq(&other.matrix, tolerance) }
+}
+
+impl ApproxEq for Bounds3 {
+	#[inline(always)]
+	fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool {
+		self.min.approx_eq(&other.min, tolerance) && self.max.approx_eq(&other.max, tolerance)
+	}
+}
+
+impl ApproxEq for Vector3x4 {
+	#[inline(always)]
+	fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool {
+		[self.x, self.y, self.z].approx_eq(&[other.x, other.y, other.z], tolerance)
+	}
+}
+
+impl ApproxEq for Point3x4 {
+	#[inline(always)]
+	fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool {
+		[self.x, self.y, self.z].approx_eq(&[other.x, other.y, other.z], tolerance)
+	}
+}
+
+impl ApproxEq for Vector8 {
+	#[inline(always)]
+	fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool {
+		[self.low(), self.high()].approx_eq(&[other.low(), other.high()], tolerance)
+	}
+}
+
+impl ApproxEq for Vector3x8 {
+	#[inline(always)]
+	fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool {
+		[self.x, self.y, self.z].approx_eq(&[other.x, other.y, other.z], tolerance)
+	}
+}
+
+impl ApproxEq for Point3x8 {
+	#[inline(always)]
+	fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool {
+		[self.x, self.y, self.z].approx_eq(&[other.x, other.y, other.z], tolerance)
+	}
+}
+
+#[macro_export]
+/// Assert that two values are approximately equal, using [`ApproxEq`](crate::approx::ApproxEq).
+///
+/// The [`Tolerance`](crate::approx::Tolerance) defaults to an absolute difference of `0.0001`.
+///
+/// ```
+/// use spaceform::{approx::Tolerance, assert_approx_eq};
+///
+/// assert_approx_eq!(0.1f32 + 0.2f32, 0.3f32);
+/// assert_approx_eq!(1e9f32, 1.00001e9f32, Tolerance::Relative(0.0001f32));
+/// ```
+macro_rules! assert_approx_eq {
+	($lhs:expr, $rhs:expr $(,)?) => {
+		$crate::assert_approx_eq!($lhs, $rhs, $crate::approx::Tolerance::default())
+	};
+	($lhs:expr, $rhs:expr, $tolerance:expr $(,)?) => {
+		match (&$lhs, &$rhs, $tolerance) {
+			(lhs, rhs, tolerance) => {
+				if !$crate::approx::ApproxEq::approx_eq(lhs, rhs, tolerance) {
+					panic!(
+						"assertion failed: `left ≈ right` ({:?})\n  left: `{:?}`\n right: `{:?}`",
+						tolerance, lhs, rhs
+					);
+				}
+			},
+		}
+	};
+}
+
+#[macro_export]
+/// Assert that two values are not approximately equal, using [`ApproxEq`](crate::approx::ApproxEq).
+///
+/// The [`Tolerance`](crate::approx::Tolerance) defaults to an absolute difference of `0.0001`.
+macro_rules! assert_approx_ne {
+	($lhs:expr, $rhs:expr $(,)?) => {
+		$crate::assert_approx_ne!($lhs, $rhs, $crate::approx::Tolerance::default())
+	};
+	($lhs:expr, $rhs:expr, $tolerance:expr $(,)?) => {
+		match (&$lhs, &$rhs, $tolerance) {
+			(lhs, rhs, tolerance) => {
+				if $crate::approx::ApproxEq::approx_eq(lhs, rhs, tolerance) {
+					panic!(
+						"assertion failed: `left ≉ right` ({:?})\n  left: `{:?}`\n right: `{:?}`",
+						tolerance, lhs, rhs
+					);
+				}
+			},
+		}
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn tolerances() {
+		assert!(Tolerance::Absolute(0.01f32).eq(1f32, 1.005f32));
+		assert!(!Tolerance::Absolute(0.01f32).eq(1000f32, 1000.5f32));
+		assert!(Tolerance::Relative(0.001f32).eq(1000f32, 1000.5f32));
+		assert!(!Tolerance::Relative(0.001f32).eq(0f32, 1e-9f32));
+
+		let next = f32::from_bits(1f32.to_bits() + 2);
+		assert!(Tolerance::Ulps(2).eq(1f32, next));
+		assert!(!Tolerance::Ulps(1).eq(1f32, next));
+		assert!(Tolerance::Ulps(0).eq(0f32, -0f32));
+		assert!(!Tolerance::Ulps(u32::MAX).eq(f32::MIN_POSITIVE, -f32::MIN_POSITIVE));
+
+		for &tolerance in [Tolerance::Absolute(1f32), Tolerance::Relative(1f32), Tolerance::Ulps(8)].iter() {
+			assert!(tolerance.eq(f32::INFINITY, f32::INFINITY));
+			assert!(!tolerance.eq(f32::INFINITY, f32::MAX));
+			assert!(!tolerance.eq(f32::NAN, f32::NAN));
+		}
+	}
+
+	#[test]
+	fn types() {
+		let vec = Vector::new(1f32, 2f32, 3f32, 4f32);
+		assert_approx_eq!(vec, vec + Vector::splat(0.00001f32));
+		assert_approx_ne!(vec, vec + Vector::new(0f32, 0f32, 0f32, 0.1f32));
+
+		// Only x, y, and z are compared for 3D types.
+		assert_approx_eq!(Normal(vec), Normal(Vector::new(1f32, 2f32, 3f32, 0f32)));
+
+		let rotation = Rotation::axis_angle(Direction::new(1f32, 2f32, 3f32), 0.4f32);
+		assert_approx_eq!(rotation, Rotation(rotation.0 * -1f32));
+		assert_approx_ne!(rotation.0, rotation.0 * -1f32);
+
+		let transform = Transform::rotate(rotation) * Transform::translate(Direction::new(1f32, 2f32, 3f32));
+		assert_approx_eq!(transform * transform.inverse(), Transform::identity());
+		assert_approx_eq!([transform.matrix; 2], [transform.matrix; 2], Tolerance::Ulps(0));
+		assert!(![1f32][..].abs_eq(&[1f32, 2f32][..], 1f32));
+	}
+
+	#[test]
+	#[should_panic]
+	fn assert_fails() {
+		assert_approx_eq!(Point::new(0f32, 0f32, 0f32), Point::new(0f32, 0f32, 0.1f32));
+	}
+}
@@ -0,0 +1,407 @@
+//! Dual quaternions.
+
+use std::{
+	fmt::{Debug, Display, Formatter, Result},
+	ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use super::{nearly_equal, Matrix, Quaternion, Vector};
+use crate::{Direction, Rotation, Transform};
+
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+/// A dual quaternion. When normalized, it represents a rigid transformation: a rotation followed by a translation.
+pub struct DualQuaternion {
+	pub(crate) real: Quaternion,
+	pub(crate) dual: Quaternion,
+}
+
+impl Add for DualQuaternion {
+	type Output = Self;
+
+	#[inline(always)]
+	fn add(self, rhs: Self) -> Self {
+		Self {
+			real: self.real + rhs.real,
+			dual: self.dual + rhs.dual,
+		}
+	}
+}
+
+impl AddAssign for DualQuaternion {
+	#[inline(always)]
+	fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+}
+
+impl Debug for DualQuaternion {
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result { write!(f, "{} + e{}", self.real, self.dual) }
+}
+
+impl Default for DualQuaternion {
+	#[inline(always)]
+	fn default() -> Self { Self::identity() }
+}
+
+impl Display for DualQuaternion {
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result { write!(f, "{} + e{}", self.real, self.dual) }
+}
+
+impl From<DualQuaternion> for Transform {
+	#[inline(always)]
+	fn from(val: DualQuaternion) -> Self { Self::rotate(val.rotation()) * Self::translate(val.translation()) }
+}
+
+impl From<Transform> for DualQuaternion {
+	#[inline(always)]
+	/// Any scale in the [`Transform`] is removed by normalizing the rows of its upper 3x3. Shear and reflection can't
+	/// be represented, so they give an approximate, but still normalized, rotation.
+	fn from(val: Transform) -> Self {
+		let xyz = Vector::new(1f32, 1f32, 1f32, 0f32);
+		let row = |idx: u8| (val.matrix.get_row(idx) * xyz).normalize();
+		let rotation = Matrix::row_vectors([row(0), row(1), row(2), Vector::new(0f32, 0f32, 0f32, 1f32)]);
+		let translation = val.matrix.get_row(3) * xyz;
+		Self::new(
+			Rotation(Rotation::from_matrix(rotation).0.normalize()),
+			Direction(translation),
+		)
+	}
+}
+
+impl Mul for DualQuaternion {
+	type Output = Self;
+
+	#[inline(always)]
+	/// Combine two [`DualQuaternion`]s. Like [`Quaternion`]s, `lhs * rhs` applies `rhs` first, and then `lhs`.
+	fn mul(self, rhs: Self) -> Self {
+		Self {
+			real: self.real * rhs.real,
+			dual: self.real * rhs.dual + self.dual * rhs.real,
+		}
+	}
+}
+
+impl MulAssign for DualQuaternion {
+	#[inline(always)]
+	fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+}
+
+impl Mul<f32> for DualQuaternion {
+	type Output = Self;
+
+	#[inline(always)]
+	fn mul(self, rhs: f32) -> Self {
+		Self {
+			real: self.real * rhs,
+			dual: self.dual * rhs,
+		}
+	}
+}
+
+impl MulAssign<f32> for DualQuaternion {
+	#[inline(always)]
+	fn mul_assign(&mut self, rhs: f32) { *self = *self * rhs; }
+}
+
+impl Neg for DualQuaternion {
+	type Output = Self;
+
+	#[inline(always)]
+	fn neg(self) -> Self { self * -1f32 }
+}
+
+impl Sub for DualQuaternion {
+	type Output = Self;
+
+	#[inline(always)]
+	fn sub(self, rhs: Self) -> Self {
+		Self {
+			real: self.real - rhs.real,
+			dual: self.dual - rhs.dual,
+		}
+	}
+}
+
+impl SubAssign for DualQuaternion {
+	#[inline(always)]
+	fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+}
+
+impl DualQuaternion {
+	#[inline(always)]
+	/// Create a [`DualQuaternion`] that applies `rotation`, and then `translation`.
+	pub fn new(rotation: Rotation, translation: Direction) -> Self {
+		Self {
+			real: rotation.0,
+			dual: Quaternion(translation.0) * rotation.0 * 0.5f32,
+		}
+	}
+
+	#[inline(always)]
+	/// Create a [`DualQuaternion`] from its real and dual parts.
+	pub const fn from_parts(real: Quaternion, dual: Quaternion) -> Self { Self { real, dual } }
+
+	#[inline(always)]
+	/// Get the identity [`DualQuaternion`] that leaves everything unchanged.
+	pub fn identity() -> Self {
+		Self {
+			real: Quaternion::default(),
+			dual: Quaternion::new(0f32, 0f32, 0f32, 0f32),
+		}
+	}
+
+	#[inline(always)]
+	/// Get the real part of the [`DualQuaternion`].
+	pub const fn real(self) -> Quaternion { self.real }
+
+	#[inline(always)]
+	/// Get the dual part of the [`DualQuaternion`].
+	pub const fn dual(self) -> Quaternion { self.dual }
+
+	#[inline(always)]
+	/// Get the [`Rotation`] applied by a normalized [`DualQuaternion`].
+	pub const fn rotation(self) -> Rotation { Rotation(self.real) }
+
+	#[inline(always)]
+	/// Get the translation applied by a normalized [`DualQuaternion`].
+	pub fn translation(self) -> Direction {
+		Direction((self.dual * self.real.conjugate()).0 * Vector::new(2f32, 2f32, 2f32, 0f32))
+	}
+
+	#[inline(always)]
+	/// Get the quaternion conjugate, which conjugates both the real and dual parts.
+	/// This is the inverse of a normalized [`DualQuaternion`].
+	pub fn conjugate(self) -> Self {
+		Self {
+			real: self.real.conjugate(),
+			dual: self.dual.conjugate(),
+		}
+	}
+
+	#[inline(always)]
+	/// Get the dual conjugate, which negates the dual part.
+	pub fn dual_conjugate(self) -> Self {
+		Self {
+			real: self.real,
+			dual: self.dual * -1f32,
+		}
+	}
+
+	#[inline(always)]
+	/// Get the combined quaternion and dual conjugate.
+	pub fn combined_conjugate(self) -> Self {
+		Self {
+			real: self.real.conjugate(),
+			dual: self.dual.conjugate() * -1f32,
+		}
+	}
+
+	#[inline(always)]
+	/// Get the dot product of the real parts of two [`DualQuaternion`]s.
+	pub fn dot(lhs: Self, rhs: Self) -> f32 { Quaternion::dot(lhs.real, rhs.real) }
+
+	#[inline(always)]
+	/// Get the normalized [`DualQuaternion`].
+	/// The real part is made unit-length, and the dual part is made orthogonal to it.
+	pub fn normalize(self) -> Self {
+		let inv_length = 1f32 / Quaternion::dot(self.real, self.real).sqrt();
+		let real = self.real * inv_length;
+		let dual = self.dual * inv_length;
+
+		Self {
+			real,
+			dual: dual - real * Quaternion::dot(real, dual),
+		}
+	}
+
+	#[inline(always)]
+	/// Screw linear interpolate from `from` to `to` with a factor `t`.
+	/// Takes the shortest path, with a constant rotational and translational velocity.
+	/// # Panics in debug mode
+	/// If either `from` or `to` is not normalized.
+	pub fn sclerp(from: Self, to: Self, t: f32) -> Self {
+		debug_assert!(nearly_equal(Self::dot(from, from), 1f32, 0.0001f32));
+		debug_assert!(nearly_equal(Self::dot(to, to), 1f32, 0.0001f32));
+
+		let diff = from.conjugate() * to;
+		let diff = if diff.real.w() < 0f32 { -diff } else { diff };
+
+		from * diff.pow(t)
+	}
+
+	#[inline(always)]
+	/// Dual-quaternion linear blend of weighted [`DualQuaternion`]s.
+	/// Every [`DualQuaternion`] is flipped into the hemisphere of the first, so that `q` and `-q` blend the same.
+	/// Returns the identity if `weighted` is empty.
+	pub fn blend(weighted: &[(Self, f32)]) -> Self {
+		let pivot = match weighted.first() {
+			Some(&(pivot, _)) => pivot,
+			None => return Self::identity(),
+		};
+
+		let mut sum = Self::from_parts(
+			Quaternion::new(0f32, 0f32, 0f32, 0f32),
+			Quaternion::new(0f32, 0f32, 0f32, 0f32),
+		);
+		for &(dq, weight) in weighted {
+			if Self::dot(pivot, dq) < 0f32 {
+				sum -= dq * weight;
+			} else {
+				sum += dq * weight;
+			}
+		}
+
+		sum.normalize()
+	}
+
+	#[inline(always)]
+	/// Raise a normalized [`DualQuaternion`] to the power `t`, by scaling its screw parameters.
+	fn pow(self, t: f32) -> Self {
+		let xyz = Vector::new(1f32, 1f32, 1f32, 0f32);
+
+		let cos_half = self.real.w().clamp(-1f32, 1f32);
+		let half = cos_half.acos();
+		let sin_half = half.sin();
+
+		if sin_half.abs() < 0.0001f32 {
+			// Pure translation, so interpolate it linearly.
+			return Self {
+				real: Quaternion::default(),
+				dual: Quaternion(self.dual.0 * xyz * t),
+			};
+		}
+
+		let axis = self.real.0 * xyz / sin_half;
+		let pitch = -2f32 * self.dual.w() / sin_half;
+		let moment = (self.dual.0 * xyz - axis * (pitch / 2f32 * cos_half)) / sin_half;
+
+		let half = half * t;
+		let pitch = pitch * t;
+		let (sin_half, cos_half) = half.sin_cos();
+
+		let mut real = Quaternion(axis * sin_half);
+		real.set_w(cos_half);
+		let mut dual = Quaternion(moment * sin_half + axis * (pitch / 2f32 * cos_half));
+		dual.set_w(-pitch / 2f32 * sin_half);
+
+		Self { real, dual }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::f32::consts::FRAC_PI_2;
+
+	use super::*;
+	use crate::{assert_approx_eq, Point};
+
+	fn rigid() -> (Rotation, Direction) {
+		(
+			Rotation::axis_angle(Direction::new(0f32, 0f32, 1f32), FRAC_PI_2),
+			Direction::new(1f32, 2f32, 3f32),
+		)
+	}
+
+	#[test]
+	fn transform_point() {
+		let (rotation, translation) = rigid();
+		let dq = DualQuaternion::new(rotation, translation);
+		let transform = Transform::rotate(rotation) * Transform::translate(translation);
+		let point = Point::new(1f32, 0f32, 0f32);
+
+		assert_approx_eq!(point * dq, point * transform);
+		assert_approx_eq!(point * dq, Point::new(1f32, 3f32, 3f32));
+		assert_approx_eq!(point * dq * dq.conjugate(), point);
+	}
+
+	#[test]
+	fn transform_direction() {
+		let (rotation, translation) = rigid();
+		let dq = DualQuaternion::new(rotation, translation);
+		let dir = Direction::new(1f32, 0f32, 0f32) * dq;
+
+		assert_approx_eq!(Point::default() + dir, Point::new(0f32, 1f32, 0f32));
+	}
+
+	#[test]
+	fn compose() {
+		let (rotation, translation) = rigid();
+		let first = DualQuaternion::new(rotation, translation);
+		let second = DualQuaternion::new(Rotation::identity(), Direction::new(0f32, 0f32, 5f32));
+		let point = Point::new(1f32, 1f32, 1f32);
+
+		assert_approx_eq!(point * (second * first), point * first * second);
+	}
+
+	#[test]
+	fn transform_round_trip() {
+		let (rotation, translation) = rigid();
+		let dq = DualQuaternion::new(rotation, translation);
+		let round_trip = DualQuaternion::from(Transform::from(dq));
+		let point = Point::new(4f32, -2f32, 7f32);
+
+		assert_approx_eq!(point * round_trip, point * dq);
+		assert_approx_eq!(
+			Point::default() + round_trip.translation(),
+			Point::default() + translation,
+		);
+	}
+
+	#[test]
+	fn scaled_transform() {
+		let (rotation, translation) = rigid();
+		let transform = Transform::scale(Direction::new(2f32, 2f32, 2f32))
+			* Transform::rotate(rotation)
+			* Transform::translate(translation);
+		let dq = DualQuaternion::from(transform);
+
+		assert_approx_eq!(Quaternion::dot(dq.real, dq.real), 1f32);
+		assert_approx_eq!(dq.rotation(), rotation);
+		assert_approx_eq!(Point::default() + dq.translation(), Point::default() + translation);
+
+		let stretched = Transform::scale(Direction::new(0.5f32, 3f32, 1f32)) * transform;
+		assert_approx_eq!(DualQuaternion::from(stretched).rotation(), rotation);
+	}
+
+	#[test]
+	fn normalize() {
+		let (rotation, translation) = rigid();
+		let dq = DualQuaternion::new(rotation, translation);
+		let point = Point::new(1f32, 0f32, 0f32);
+
+		assert_approx_eq!(point * (dq * 3f32).normalize(), point * dq);
+	}
+
+	#[test]
+	fn sclerp() {
+		let (rotation, translation) = rigid();
+		let from = DualQuaternion::identity();
+		let to = DualQuaternion::new(rotation, translation);
+		let point = Point::new(1f32, 0f32, 0f32);
+
+		assert_approx_eq!(point * DualQuaternion::sclerp(from, to, 0f32), point);
+		assert_approx_eq!(point * DualQuaternion::sclerp(from, to, 1f32), point * to);
+		assert_approx_eq!(point * DualQuaternion::sclerp(from, -to, 1f32), point * to);
+
+		let translate = DualQuaternion::new(Rotation::identity(), Direction::new(2f32, 0f32, 0f32));
+		assert_approx_eq!(
+			point * DualQuaternion::sclerp(from, translate, 0.5f32),
+			Point::new(2f32, 0f32, 0f32),
+		);
+	}
+
+	#[test]
+	fn blend() {
+		let (rotation, translation) = rigid();
+		let dq = DualQuaternion::new(rotation, translation);
+		let point = Point::new(1f32, 0f32, 0f32);
+
+		assert_approx_eq!(point * DualQuaternion::blend(&[(dq, 1f32)]), point * dq);
+		assert_approx_eq!(
+			point * DualQuaternion::blend(&[(dq, 0.5f32), (-dq, 0.5f32)]),
+			point * dq,
+		);
+		assert_eq!(DualQuaternion::blend(&[]), DualQuaternion::identity());
+	}
+}
@@ -6,7 +6,12 @@ use std::{
 	ops::{Mul, MulAssign},
 };
 
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
 use crate::base::Vector;
+#[cfg(feature = "rayon")]
+use crate::PAR_CHUNK;
 
 #[repr(transparent)]
 #[derive(Copy, Clone, PartialEq)]
@@ -48,6 +53,11 @@ impl Display for Matrix {
 	}
 }
 
+impl From<Matrix> for [[f32; 4]; 4] {
+	#[inline(always)]
+	fn from(val: Matrix) -> Self { val.rows.map(Into::into) }
+}
+
 impl Mul for Matrix {
 	type Output = Self;
 
@@ -200,6 +210,31 @@ impl Matrix {
 		}
 	}
 
+	/// Multiply every [`Matrix`] in `lhs` by the one at the same index in `rhs`, writing the products into `out`.
+	///
+	/// # Panics
+	/// If the slices have different lengths.
+	pub fn mul_slices(lhs: &[Self], rhs: &[Self], out: &mut [Self]) {
+		assert!(lhs.len() == rhs.len() && lhs.len() == out.len(), "slice lengths differ");
+		for ((&lhs, &rhs), out) in lhs.iter().zip(rhs.iter()).zip(out.iter_mut()) {
+			*out = lhs * rhs;
+		}
+	}
+
+	#[cfg(feature = "rayon")]
+	/// Multiply every [`Matrix`] in `lhs` by the one at the same index in `rhs`, writing the products into `out`, in
+	/// parallel. Gives the same results as [`Matrix::mul_slices`].
+	///
+	/// # Panics
+	/// If the slices have different lengths.
+	pub fn par_mul_slices(lhs: &[Self], rhs: &[Self], out: &mut [Self]) {
+		assert!(lhs.len() == rhs.len() && lhs.len() == out.len(), "slice lengths differ");
+		lhs.par_chunks(PAR_CHUNK)
+			.zip(rhs.par_chunks(PAR_CHUNK))
+			.zip(out.par_chunks_mut(PAR_CHUNK))
+			.for_each(|((lhs, rhs), out)| Self::mul_slices(lhs, rhs, out));
+	}
+
 	#[inline(always)]
 	/// Get a row of the [`Matrix`].
 	/// Panics if idx is not in the range [0, 3].
@@ -289,4 +324,34 @@ mod tests {
 
 		assert_eq!(mat * mat.inverse(), Matrix::default())
 	}
+
+	#[test]
+	fn mul_slices() {
+		let lhs: Vec<_> = (0..7)
+			.map(|i| Matrix::rows([[i as f32, 1f32, 0f32, 0f32]; 4]))
+			.collect();
+		let rhs: Vec<_> = (0..7)
+			.map(|i| Matrix::rows([[1f32, 0f32, i as f32, 2f32]; 4]))
+			.collect();
+		let mut out = vec![Matrix::default(); 7];
+		Matrix::mul_slices(&lhs, &rhs, &mut out);
+		for ((&lhs, &rhs), &out) in lhs.iter().zip(rhs.iter()).zip(out.iter()) {
+			assert_eq!(out, lhs * rhs);
+		}
+	}
+
+	#[cfg(feature = "rayon")]
+	#[test]
+	fn par_mul_slices() {
+		let lhs: Vec<_> = (0..5003)
+			.map(|i| Matrix::rows([[i as f32, 1f32, 0.5f32, 0f32]; 4]))
+			.collect();
+		let rhs: Vec<_> = (0..5003)
+			.map(|i| Matrix::rows([[1f32, -0.25f32, i as f32, 2f32]; 4]))
+			.collect();
+		let (mut serial, mut parallel) = (vec![Matrix::default(); 5003], vec![Matrix::default(); 5003]);
+		Matrix::mul_slices(&lhs, &rhs, &mut serial);
+		Matrix::par_mul_slices(&lhs, &rhs, &mut parallel);
+		assert!(serial.iter().zip(parallel.iter()).all(|(lhs, rhs)| lhs == rhs));
+	}
 }
@@ -1,11 +1,13 @@
 //! These are the base mathematical types, which are then abstracted by more usable types.
 
+pub mod dual_quaternion;
 pub mod matrix;
 pub mod quaternion;
 pub mod vector;
 
 use core::f32;
 
+pub use dual_quaternion::*;
 pub use matrix::*;
 pub use quaternion::*;
 pub use vector::*;
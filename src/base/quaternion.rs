@@ -74,7 +74,7 @@ impl Mul for Quaternion {
 			l_w * r_x + l_x * r_w + l_y * r_z - l_z * r_y,
 			l_w * r_y + l_y * r_w + l_z * r_x - l_x * r_z,
 			l_w * r_z + l_z * r_w + l_x * r_y - l_y * r_x,
-			l_w * r_w - l_x * r_x - l_y * l_y - l_z * r_z,
+			l_w * r_w - l_x * r_x - l_y * r_y - l_z * r_z,
 		))
 	}
 }
@@ -149,6 +149,22 @@ impl Quaternion {
 	/// Get the normalized [`Quaternion`].
 	pub fn normalize(self) -> Self { Self(self.0.normalize()) }
 
+	#[inline(always)]
+	/// Get the conjugate of the [`Quaternion`], which is its inverse if it is normalized.
+	pub fn conjugate(self) -> Self { Self(self.0 * Vector::new(-1f32, -1f32, -1f32, 1f32)) }
+
+	#[inline(always)]
+	/// Rotate the x, y, and z values of a [`Vector`] by the [`Quaternion`]. The w value is left unchanged.
+	/// # Panics in debug mode
+	/// If the [`Quaternion`] is not normalized.
+	pub fn rotate(self, vec: Vector) -> Vector {
+		debug_assert!(nearly_equal(Self::dot(self, self), 1f32, 0.0001f32));
+
+		let axis = self.0 * Vector::new(1f32, 1f32, 1f32, 0f32);
+		let temp = Vector::cross(axis, vec) * 2f32;
+		vec + temp * self.w() + Vector::cross(axis, temp)
+	}
+
 	#[inline(always)]
 	/// Get the dot product of two [`Quaternion`]s.
 	pub fn dot(lhs: Quaternion, rhs: Quaternion) -> f32 { Vector::dot(lhs.0, rhs.0) }
@@ -165,9 +181,16 @@ impl Quaternion {
 		if cos > 0.9995f32 {
 			(from * (1f32 - t) + to * t).normalize()
 		} else {
-			let theta = cos.acos();
+			// Rounding can push the dot product of normalized quaternions slightly past -1.
+			let theta = cos.clamp(-1f32, 1f32).acos();
 			let dtheta = theta * t;
-			let qperp = (to - from * cos).normalize();
+			let perp = to - from * cos;
+			// Opposite quaternions have no unique path between them, so pick any perpendicular one.
+			let qperp = if Self::dot(perp, perp) > f32::EPSILON {
+				perp.normalize()
+			} else {
+				Self::new(-from.y(), from.x(), -from.w(), from.z())
+			};
 			from * dtheta.cos() + qperp * dtheta.sin()
 		}
 	}
@@ -182,5 +205,42 @@ mod tests {
 		let q = Quaternion::new(1f32, 2f32, 3f32, 4f32);
 
 		assert_eq!(q * q, Quaternion::new(8f32, 16f32, 24f32, 2f32));
+		assert_eq!(
+			q * Quaternion::new(5f32, 6f32, 7f32, 8f32),
+			Quaternion::new(24f32, 48f32, 48f32, -6f32)
+		);
+	}
+
+	#[test]
+	fn conjugate() {
+		let q = Quaternion::new(1f32, 2f32, 3f32, 4f32);
+
+		assert_eq!(q.conjugate(), Quaternion::new(-1f32, -2f32, -3f32, 4f32));
+		assert_eq!(q * q.conjugate(), Quaternion::new(0f32, 0f32, 0f32, 30f32));
+	}
+
+	#[test]
+	fn rotate() {
+		let half = std::f32::consts::FRAC_1_SQRT_2;
+		let q = Quaternion::new(0f32, 0f32, half, half);
+		let rotated = q.rotate(Vector::new(1f32, 0f32, 0f32, 1f32));
+
+		assert!(nearly_equal(rotated.x(), 0f32, 0.0001f32));
+		assert!(nearly_equal(rotated.y(), 1f32, 0.0001f32));
+		assert!(nearly_equal(rotated.z(), 0f32, 0.0001f32));
+		assert_eq!(rotated.w(), 1f32);
+	}
+
+	#[test]
+	fn slerp_opposite() {
+		for idx in 0..1000 {
+			let idx = idx as f32;
+			let from = Quaternion::new(idx.sin(), (idx * 1.3f32).cos(), (idx * 0.7f32).sin(), 0.5f32).normalize();
+			let half = Quaternion::slerp(from, from * -1f32, 0.5f32);
+
+			assert!(nearly_equal(Quaternion::dot(half, half), 1f32, 0.0001f32), "{:?}", from);
+			// The rounded dot product is a few ulps from -1, which is a fraction of a milliradian.
+			assert!(nearly_equal(Quaternion::dot(from, half), 0f32, 0.001f32), "{:?}", from);
+		}
 	}
 }
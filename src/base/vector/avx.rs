@@ -0,0 +1,118 @@
+//! Implementation of [`Vector8`] using AVX intrinsics for x86 processors.
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use std::ops::{Add, Div, Mul, Sub};
+
+use super::Vector;
+
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+/// Eight lanes of floats, for structure-of-arrays math.
+pub struct Vector8 {
+	data: __m256,
+}
+
+impl Add for Vector8 {
+	type Output = Self;
+
+	#[inline(always)]
+	fn add(self, rhs: Self) -> Self {
+		Self {
+			data: unsafe { _mm256_add_ps(self.data, rhs.data) },
+		}
+	}
+}
+
+impl Default for Vector8 {
+	#[inline(always)]
+	fn default() -> Self {
+		Self {
+			data: unsafe { _mm256_setzero_ps() },
+		}
+	}
+}
+
+impl Div for Vector8 {
+	type Output = Self;
+
+	#[inline(always)]
+	fn div(self, rhs: Self) -> Self {
+		Self {
+			data: unsafe { _mm256_div_ps(self.data, rhs.data) },
+		}
+	}
+}
+
+impl Mul for Vector8 {
+	type Output = Self;
+
+	#[inline(always)]
+	fn mul(self, rhs: Self) -> Self {
+		Self {
+			data: unsafe { _mm256_mul_ps(self.data, rhs.data) },
+		}
+	}
+}
+
+impl PartialEq for Vector8 {
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool {
+		unsafe { _mm256_movemask_ps(_mm256_cmp_ps::<_CMP_EQ_OQ>(self.data, other.data)) == 0xff }
+	}
+}
+
+impl Sub for Vector8 {
+	type Output = Self;
+
+	#[inline(always)]
+	fn sub(self, rhs: Self) -> Self {
+		Self {
+			data: unsafe { _mm256_sub_ps(self.data, rhs.data) },
+		}
+	}
+}
+
+impl Vector8 {
+	#[inline(always)]
+	/// Create a [`Vector8`] from the [`Vector`]s of lanes 0 to 3 and 4 to 7.
+	pub fn from_halves(low: Vector, high: Vector) -> Self {
+		Self {
+			data: unsafe { _mm256_set_m128(high.data, low.data) },
+		}
+	}
+
+	#[inline(always)]
+	/// Get lanes 0 to 3.
+	pub fn low(self) -> Vector {
+		Vector {
+			data: unsafe { _mm256_castps256_ps128(self.data) },
+		}
+	}
+
+	#[inline(always)]
+	/// Get lanes 4 to 7.
+	pub fn high(self) -> Vector {
+		Vector {
+			data: unsafe { _mm256_extractf128_ps::<1>(self.data) },
+		}
+	}
+
+	#[inline(always)]
+	/// Create a [`Vector8`] with all eight lanes set to `val`.
+	pub fn splat(val: f32) -> Self {
+		Self {
+			data: unsafe { _mm256_set1_ps(val) },
+		}
+	}
+
+	#[inline(always)]
+	/// Get a [`Vector8`] containing the square roots of every lane.
+	pub fn sqrt(self) -> Self {
+		Self {
+			data: unsafe { _mm256_sqrt_ps(self.data) },
+		}
+	}
+}
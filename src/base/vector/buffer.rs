@@ -0,0 +1,125 @@
+//! Aligned storage for loading and storing [`Vector`]s.
+
+use std::{
+	fmt::{Debug, Formatter, Result},
+	ops::{Deref, DerefMut},
+	slice::{from_raw_parts, from_raw_parts_mut},
+};
+
+use super::Vector;
+
+#[repr(C, align(16))]
+#[derive(Copy, Clone, Default)]
+/// Four values, aligned to 16 bytes.
+struct Block([f32; 4]);
+
+#[derive(Clone, Default)]
+/// A growable buffer of `f32`s that starts on a 16-byte boundary.
+///
+/// The values are padded with zeros to a multiple of four, so every group of four can be used with
+/// [`Vector::load_aligned`] and [`Vector::store_aligned`].
+pub struct AlignedBuffer {
+	blocks: Vec<Block>,
+	len: usize,
+}
+
+impl Debug for AlignedBuffer {
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result { Debug::fmt(self.as_slice(), f) }
+}
+
+impl Deref for AlignedBuffer {
+	type Target = [f32];
+
+	#[inline(always)]
+	fn deref(&self) -> &[f32] { self.as_slice() }
+}
+
+impl DerefMut for AlignedBuffer {
+	#[inline(always)]
+	fn deref_mut(&mut self) -> &mut [f32] { self.as_mut_slice() }
+}
+
+impl From<&[f32]> for AlignedBuffer {
+	#[inline(always)]
+	fn from(src: &[f32]) -> Self { Self::from_slice(src) }
+}
+
+impl PartialEq for AlignedBuffer {
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool { self.as_slice() == other.as_slice() }
+}
+
+impl AlignedBuffer {
+	#[inline(always)]
+	/// Create an empty [`AlignedBuffer`].
+	pub fn new() -> Self { Self::default() }
+
+	#[inline(always)]
+	/// Create an [`AlignedBuffer`] of `len` zeros.
+	pub fn zeroed(len: usize) -> Self {
+		Self {
+			blocks: vec![Block::default(); len.div_ceil(4)],
+			len,
+		}
+	}
+
+	#[inline(always)]
+	/// Create an [`AlignedBuffer`] with a copy of `src`.
+	pub fn from_slice(src: &[f32]) -> Self {
+		let mut buffer = Self::zeroed(src.len());
+		buffer.copy_from_slice(src);
+		buffer
+	}
+
+	#[inline(always)]
+	/// Get the number of values in the [`AlignedBuffer`], not including the padding.
+	pub const fn len(&self) -> usize { self.len }
+
+	#[inline(always)]
+	/// Check if the [`AlignedBuffer`] has no values.
+	pub const fn is_empty(&self) -> bool { self.len == 0 }
+
+	#[inline(always)]
+	/// Append a value to the end of the [`AlignedBuffer`].
+	pub fn push(&mut self, val: f32) {
+		if self.len.is_multiple_of(4) {
+			self.blocks.push(Block::default());
+		}
+		self.blocks[self.len / 4].0[self.len % 4] = val;
+		self.len += 1;
+	}
+
+	#[inline(always)]
+	/// Get the values as a slice.
+	pub const fn as_slice(&self) -> &[f32] { unsafe { from_raw_parts(self.blocks.as_ptr() as *const f32, self.len) } }
+
+	#[inline(always)]
+	/// Get the values as a mutable slice.
+	pub const fn as_mut_slice(&mut self) -> &mut [f32] {
+		unsafe { from_raw_parts_mut(self.blocks.as_mut_ptr() as *mut f32, self.len) }
+	}
+
+	#[inline(always)]
+	/// Get the `idx`th group of four values as a [`Vector`]. Values past the end of the buffer are zero.
+	/// Panics if the group is out of bounds.
+	pub fn vector(&self, idx: usize) -> Vector { Vector::load_aligned(&self.blocks[idx].0) }
+
+	#[inline(always)]
+	/// Set the `idx`th group of four values from a [`Vector`]. Values past the end of the buffer are left as zero.
+	/// Panics if the group is out of bounds.
+	pub fn set_vector(&mut self, idx: usize, val: Vector) {
+		let block = &mut self.blocks[idx].0;
+		val.store_aligned(block);
+		let valid = self.len - idx * 4;
+		if valid < 4 {
+			block[valid..].iter_mut().for_each(|val| *val = 0f32);
+		}
+	}
+
+	#[inline(always)]
+	/// Iterate over every group of four values as a [`Vector`], with the last one padded with zeros.
+	pub fn vectors(&self) -> impl Iterator<Item = Vector> + '_ {
+		self.blocks.iter().map(|block| Vector::load_aligned(&block.0))
+	}
+}
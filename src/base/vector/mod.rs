@@ -2,7 +2,7 @@
 
 use std::{
 	fmt::{Debug, Display, Formatter, Result},
-	ops::{AddAssign, DivAssign, Mul, MulAssign, Neg, SubAssign},
+	ops::{AddAssign, Div, DivAssign, Mul, MulAssign, Neg, SubAssign},
 };
 
 #[cfg(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64")))]
@@ -26,6 +26,35 @@ mod scalar;
 )))]
 pub use scalar::*;
 
+#[cfg(all(
+	feature = "simd",
+	target_feature = "avx",
+	any(target_arch = "x86", target_arch = "x86_64")
+))]
+mod avx;
+#[cfg(all(
+	feature = "simd",
+	target_feature = "avx",
+	any(target_arch = "x86", target_arch = "x86_64")
+))]
+pub use avx::*;
+
+#[cfg(not(all(
+	feature = "simd",
+	target_feature = "avx",
+	any(target_arch = "x86", target_arch = "x86_64")
+)))]
+mod pair;
+#[cfg(not(all(
+	feature = "simd",
+	target_feature = "avx",
+	any(target_arch = "x86", target_arch = "x86_64")
+)))]
+pub use pair::*;
+
+mod buffer;
+pub use buffer::AlignedBuffer;
+
 use crate::base::Matrix;
 
 impl AddAssign for Vector {
@@ -57,9 +86,30 @@ impl DivAssign<f32> for Vector {
 	fn div_assign(&mut self, rhs: f32) { *self = *self / rhs; }
 }
 
+impl Debug for IVector {
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+		let [x, y, z, w]: [u32; 4] = (*self).into();
+		write!(f, "[{}, {}, {}, {}]", x, y, z, w)
+	}
+}
+
+impl Eq for IVector {}
+
+impl From<[u32; 4]> for IVector {
+	#[inline(always)]
+	fn from(val: [u32; 4]) -> Self { Self::new(val[0], val[1], val[2], val[3]) }
+}
+
+impl IVector {
+	#[inline(always)]
+	/// Create an [`IVector`] with all four components set to `val`.
+	pub fn splat(val: u32) -> Self { Self::new(val, val, val, val) }
+}
+
 impl From<[f32; 4]> for Vector {
 	#[inline(always)]
-	fn from(val: [f32; 4]) -> Self { Vector::new(val[0], val[1], val[2], val[3]) }
+	fn from(val: [f32; 4]) -> Self { Vector::load(&val) }
 }
 
 impl MulAssign for Vector {
@@ -101,12 +151,20 @@ impl SubAssign for Vector {
 	fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
 }
 
-impl Into<[f32; 4]> for Vector {
+impl From<Vector> for [f32; 4] {
 	#[inline(always)]
-	fn into(self) -> [f32; 4] { [self.x(), self.y(), self.z(), self.w()] }
+	fn from(val: Vector) -> Self {
+		let mut data = [0f32; 4];
+		val.store(&mut data);
+		data
+	}
 }
 
 impl Vector {
+	#[inline(always)]
+	/// Create a [`Vector`] with all four components set to `val`.
+	pub fn splat(val: f32) -> Self { Self::new(val, val, val, val) }
+
 	#[inline(always)]
 	/// Get the square of the four-dimensional length of the [`Vector`].
 	pub fn length_square(self) -> f32 { Self::dot(self, self) }
@@ -130,6 +188,20 @@ impl Vector {
 		temp * rhs.shuffle::<2, 0, 1, 3>() - (temp * rhs).shuffle::<1, 2, 0, 3>()
 	}
 
+	#[inline(always)]
+	/// Get the smallest of x, y, z, and w.
+	pub fn hmin(self) -> f32 {
+		let temp = Self::min(self, self.shuffle::<1, 0, 3, 2>());
+		Self::min(temp, temp.shuffle::<2, 3, 0, 1>()).x()
+	}
+
+	#[inline(always)]
+	/// Get the largest of x, y, z, and w.
+	pub fn hmax(self) -> f32 {
+		let temp = Self::max(self, self.shuffle::<1, 0, 3, 2>());
+		Self::max(temp, temp.shuffle::<2, 3, 0, 1>()).x()
+	}
+
 	#[inline(always)]
 	/// Clamp `val` between `min_val` and `max_val`.
 	pub fn clamp(val: Vector, min_val: Vector, max_val: Vector) -> Vector {
@@ -138,7 +210,85 @@ impl Vector {
 
 	#[inline(always)]
 	/// Linear interpolate from `from` to `to` with a factor `t`.
-	pub fn lerp(from: Vector, to: Vector, t: f32) -> Vector { from + (from - to) * t }
+	pub fn lerp(from: Vector, to: Vector, t: f32) -> Vector { from + (to - from) * t }
+
+	#[inline(always)]
+	/// Gather a [`Vector`] from the values of `src` at `indices`.
+	/// Panics if any index is out of bounds.
+	pub fn gather(src: &[f32], indices: [usize; 4]) -> Self {
+		Self::new(src[indices[0]], src[indices[1]], src[indices[2]], src[indices[3]])
+	}
+
+	#[inline(always)]
+	/// Scatter x, y, z, and w into `dst` at `indices`. If an index is repeated, the later value is kept.
+	/// Panics if any index is out of bounds.
+	pub fn scatter(self, dst: &mut [f32], indices: [usize; 4]) {
+		let data: [f32; 4] = self.into();
+		for (&idx, &val) in indices.iter().zip(data.iter()) {
+			dst[idx] = val;
+		}
+	}
+}
+
+impl Debug for Vector8 {
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result { write!(f, "{:?}", <[f32; 8]>::from(*self)) }
+}
+
+impl Div<f32> for Vector8 {
+	type Output = Self;
+
+	#[inline(always)]
+	fn div(self, rhs: f32) -> Self { self / Self::splat(rhs) }
+}
+
+impl From<[f32; 8]> for Vector8 {
+	#[inline(always)]
+	fn from(val: [f32; 8]) -> Self {
+		Self::from_halves(
+			Vector::new(val[0], val[1], val[2], val[3]),
+			Vector::new(val[4], val[5], val[6], val[7]),
+		)
+	}
+}
+
+impl From<Vector8> for [f32; 8] {
+	#[inline(always)]
+	fn from(val: Vector8) -> Self {
+		let mut data = [0f32; 8];
+		val.low().store(&mut data[..4]);
+		val.high().store(&mut data[4..]);
+		data
+	}
+}
+
+impl Mul<f32> for Vector8 {
+	type Output = Self;
+
+	#[inline(always)]
+	fn mul(self, rhs: f32) -> Self { self * Self::splat(rhs) }
+}
+
+impl Vector8 {
+	#[inline(always)]
+	/// Get the lane at `idx`. Panics if `idx` is larger than 7.
+	pub fn get(self, idx: u8) -> f32 {
+		match idx {
+			0..=3 => self.low().get(idx),
+			4..=7 => self.high().get(idx - 4),
+			_ => panic!("Vector8 lane out of range"),
+		}
+	}
+}
+
+#[inline(always)]
+/// Check that `data` has at least four values and starts on a 16-byte boundary, for aligned loads and stores.
+fn check_aligned(data: &[f32]) {
+	assert!(data.len() >= 4, "Accessed out of slice bounds");
+	assert!(
+		(data.as_ptr() as usize).is_multiple_of(16),
+		"Slice is not aligned to 16 bytes"
+	);
 }
 
 #[cfg(test)]
@@ -255,6 +405,13 @@ mod tests {
 		assert_eq!(vec.abs(), Vector::new(1f32, 2f32, 3f32, 4f32));
 	}
 
+	#[test]
+	fn sqrt() {
+		let vec = Vector::new(1f32, 4f32, 9f32, 0f32);
+
+		assert_eq!(vec.sqrt(), Vector::new(1f32, 2f32, 3f32, 0f32));
+	}
+
 	#[test]
 	fn horizontal_sum() {
 		let vec = Vector::new(-1f32, 2f32, -3f32, 4f32);
@@ -290,6 +447,24 @@ mod tests {
 		assert_eq!(Vector::max(vec1, vec2), Vector::new(4f32, 3f32, 3f32, 4f32));
 	}
 
+	#[test]
+	fn lerp() {
+		let vec1 = Vector::new(1f32, 2f32, 3f32, 4f32);
+		let vec2 = Vector::new(3f32, 2f32, 1f32, 0f32);
+
+		assert_eq!(Vector::lerp(vec1, vec2, 0f32), vec1);
+		assert_eq!(Vector::lerp(vec1, vec2, 0.5f32), Vector::new(2f32, 2f32, 2f32, 2f32));
+		assert_eq!(Vector::lerp(vec1, vec2, 1f32), vec2);
+	}
+
+	#[test]
+	fn horizontal_min_and_max() {
+		let vec = Vector::new(3f32, -2f32, 4f32, 1f32);
+
+		assert_eq!(vec.hmin(), -2f32);
+		assert_eq!(vec.hmax(), 4f32);
+	}
+
 	#[test]
 	fn adj_add_and_sub() {
 		let vec1 = Vector::new(1f32, 2f32, 3f32, 4f32);
@@ -306,4 +481,91 @@ mod tests {
 
 		assert_eq!(Vector::add_sub(vec1, vec2), Vector::new(-3f32, 5f32, 1f32, 5f32));
 	}
+
+	#[test]
+	fn load_and_store() {
+		let data = [1f32, 2f32, 3f32, 4f32, 5f32];
+		assert_eq!(Vector::load(&data), Vector::new(1f32, 2f32, 3f32, 4f32));
+		assert_eq!(Vector::load(&data[1..]), Vector::new(2f32, 3f32, 4f32, 5f32));
+		assert_eq!(Vector::load_xyz(&data[2..], 1f32), Vector::new(3f32, 4f32, 5f32, 1f32));
+		assert_eq!(Vector::load_xyz(&data[2..], 0f32), Vector::new(3f32, 4f32, 5f32, 0f32));
+
+		let mut out = [0f32; 5];
+		Vector::new(1f32, 2f32, 3f32, 4f32).store(&mut out[1..]);
+		assert_eq!(out, [0f32, 1f32, 2f32, 3f32, 4f32]);
+		Vector::new(5f32, 6f32, 7f32, 8f32).store_xyz(&mut out[2..]);
+		assert_eq!(out, [0f32, 1f32, 5f32, 6f32, 7f32]);
+	}
+
+	#[test]
+	fn aligned() {
+		let mut buffer = AlignedBuffer::from_slice(&[1f32, 2f32, 3f32, 4f32, 5f32, 6f32]);
+		assert_eq!(buffer.len(), 6);
+		assert_eq!(Vector::load_aligned(&buffer), Vector::new(1f32, 2f32, 3f32, 4f32));
+		assert_eq!(buffer.vector(1), Vector::new(5f32, 6f32, 0f32, 0f32));
+
+		buffer.set_vector(1, Vector::new(7f32, 8f32, 9f32, 10f32));
+		buffer.push(11f32);
+		assert_eq!(&buffer[..], &[1f32, 2f32, 3f32, 4f32, 7f32, 8f32, 11f32]);
+		Vector::splat(2f32).store_aligned(&mut buffer);
+		let vectors: Vec<_> = buffer.vectors().collect();
+		assert_eq!(vectors, [Vector::splat(2f32), Vector::new(7f32, 8f32, 11f32, 0f32)]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn misaligned() {
+		let buffer = AlignedBuffer::zeroed(8);
+		Vector::load_aligned(&buffer[1..]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn load_out_of_bounds() { Vector::load(&[1f32, 2f32, 3f32]); }
+
+	#[test]
+	fn integers() {
+		let lhs = IVector::new(0b1100, 1, 0xFFFF_FFFF, 7);
+		let rhs = IVector::from([0b1010, 2, 0x8000_0001, 0]);
+		assert_eq!(lhs & rhs, IVector::new(0b1000, 0, 0x8000_0001, 0));
+		assert_eq!(lhs | rhs, IVector::new(0b1110, 3, 0xFFFF_FFFF, 7));
+		assert_eq!(lhs << 4, IVector::new(0b1100_0000, 16, 0xFFFF_FFF0, 112));
+		assert_eq!(lhs >> 2, IVector::new(0b11, 0, 0x3FFF_FFFF, 1));
+		assert_ne!(lhs, IVector::splat(1));
+
+		let truncated = IVector::truncate(Vector::new(0f32, 1.9f32, 1023.5f32, 2e9f32));
+		assert_eq!(<[u32; 4]>::from(truncated), [0, 1, 1023, 2_000_000_000]);
+	}
+
+	#[test]
+	fn eight_lanes() {
+		let values = [1f32, 4f32, 9f32, 16f32, 25f32, 36f32, 49f32, 64f32];
+		let lanes = Vector8::from(values);
+		assert_eq!(<[f32; 8]>::from(lanes), values);
+		assert_eq!(lanes.low(), Vector::new(1f32, 4f32, 9f32, 16f32));
+		assert_eq!(lanes.high(), Vector::new(25f32, 36f32, 49f32, 64f32));
+		assert_eq!(lanes.get(5), 36f32);
+		assert_eq!(
+			<[f32; 8]>::from(lanes.sqrt()),
+			[1f32, 2f32, 3f32, 4f32, 5f32, 6f32, 7f32, 8f32]
+		);
+
+		let twos = Vector8::splat(2f32);
+		assert_eq!(lanes + twos, Vector8::from(values.map(|val| val + 2f32)));
+		assert_eq!(lanes - twos, Vector8::from(values.map(|val| val - 2f32)));
+		assert_eq!(lanes * 2f32, Vector8::from(values.map(|val| val * 2f32)));
+		assert_eq!(lanes / 2f32, Vector8::from(values.map(|val| val / 2f32)));
+		assert_ne!(lanes, Vector8::default());
+	}
+
+	#[test]
+	fn gather_and_scatter() {
+		let data = [0f32, 1f32, 2f32, 3f32, 4f32, 5f32, 6f32];
+		let vec = Vector::gather(&data, [6, 0, 3, 3]);
+		assert_eq!(vec, Vector::new(6f32, 0f32, 3f32, 3f32));
+
+		let mut out = [0f32; 7];
+		Vector::new(1f32, 2f32, 3f32, 4f32).scatter(&mut out, [5, 1, 2, 1]);
+		assert_eq!(out, [0f32, 4f32, 3f32, 0f32, 0f32, 1f32, 0f32]);
+	}
 }
@@ -0,0 +1,65 @@
+//! Implementation of [`Vector8`] as two [`Vector`]s, for targets without AVX.
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use super::Vector;
+
+#[repr(C, align(32))]
+#[derive(Copy, Clone, Default, PartialEq)]
+/// Eight lanes of floats, for structure-of-arrays math.
+pub struct Vector8 {
+	low: Vector,
+	high: Vector,
+}
+
+impl Add for Vector8 {
+	type Output = Self;
+
+	#[inline(always)]
+	fn add(self, rhs: Self) -> Self { Self::from_halves(self.low + rhs.low, self.high + rhs.high) }
+}
+
+impl Div for Vector8 {
+	type Output = Self;
+
+	#[inline(always)]
+	fn div(self, rhs: Self) -> Self { Self::from_halves(self.low / rhs.low, self.high / rhs.high) }
+}
+
+impl Mul for Vector8 {
+	type Output = Self;
+
+	#[inline(always)]
+	fn mul(self, rhs: Self) -> Self { Self::from_halves(self.low * rhs.low, self.high * rhs.high) }
+}
+
+impl Sub for Vector8 {
+	type Output = Self;
+
+	#[inline(always)]
+	fn sub(self, rhs: Self) -> Self { Self::from_halves(self.low - rhs.low, self.high - rhs.high) }
+}
+
+// Nothing here is `const`, to match the AVX backend.
+#[allow(clippy::missing_const_for_fn)]
+impl Vector8 {
+	#[inline(always)]
+	/// Create a [`Vector8`] from the [`Vector`]s of lanes 0 to 3 and 4 to 7.
+	pub fn from_halves(low: Vector, high: Vector) -> Self { Self { low, high } }
+
+	#[inline(always)]
+	/// Get lanes 0 to 3.
+	pub fn low(self) -> Vector { self.low }
+
+	#[inline(always)]
+	/// Get lanes 4 to 7.
+	pub fn high(self) -> Vector { self.high }
+
+	#[inline(always)]
+	/// Create a [`Vector8`] with all eight lanes set to `val`.
+	pub fn splat(val: f32) -> Self { Self::from_halves(Vector::splat(val), Vector::splat(val)) }
+
+	#[inline(always)]
+	/// Get a [`Vector8`] containing the square roots of every lane.
+	pub fn sqrt(self) -> Self { Self::from_halves(self.low.sqrt(), self.high.sqrt()) }
+}
@@ -2,11 +2,13 @@
 
 use core::f32;
 use std::{
-	ops::{Add, Div, Mul, Sub},
+	ops::{Add, BitAnd, BitOr, Div, Mul, Shl, Shr, Sub},
 	slice::from_raw_parts,
 };
 
-#[repr(C)]
+use super::check_aligned;
+
+#[repr(C, align(16))]
 #[derive(Copy, Clone, PartialEq)]
 /// A four-dimensional row vector.
 pub struct Vector {
@@ -185,8 +187,61 @@ impl Vector {
 	/// Get an indexed value from the [`Vector`]. This is slow, don't use it unless you have to.
 	/// Panics if idx is not in the range [0, 3].
 	pub fn get(self, idx: u8) -> f32 {
-		assert!(idx < 4, "Indexed out of Vector bounds");
-		unsafe { from_raw_parts((&self as *const Vector) as *const f32, 4)[idx as usize] }
+		match idx {
+			0 => self.x,
+			1 => self.y,
+			2 => self.z,
+			3 => self.w,
+			_ => panic!("Indexed out of Vector bounds"),
+		}
+	}
+
+	#[inline(always)]
+	/// Load a [`Vector`] from the first four values of `src`.
+	/// Panics if `src` has less than four values.
+	pub fn load(src: &[f32]) -> Self {
+		assert!(src.len() >= 4, "Loaded out of slice bounds");
+		Self::new(src[0], src[1], src[2], src[3])
+	}
+
+	#[inline(always)]
+	/// Load a [`Vector`] from the first four values of `src`, which must start on a 16-byte boundary.
+	/// Panics if `src` has less than four values, or is not aligned.
+	pub fn load_aligned(src: &[f32]) -> Self {
+		check_aligned(src);
+		Self::new(src[0], src[1], src[2], src[3])
+	}
+
+	#[inline(always)]
+	/// Load a [`Vector`] from the first three values of `src`, with the w value set to `w`.
+	/// Panics if `src` has less than three values.
+	pub fn load_xyz(src: &[f32], w: f32) -> Self {
+		assert!(src.len() >= 3, "Loaded out of slice bounds");
+		Self::new(src[0], src[1], src[2], w)
+	}
+
+	#[inline(always)]
+	/// Store the [`Vector`] into the first four values of `dst`.
+	/// Panics if `dst` has less than four values.
+	pub fn store(self, dst: &mut [f32]) {
+		assert!(dst.len() >= 4, "Stored out of slice bounds");
+		dst[..4].copy_from_slice(&[self.x, self.y, self.z, self.w]);
+	}
+
+	#[inline(always)]
+	/// Store the [`Vector`] into the first four values of `dst`, which must start on a 16-byte boundary.
+	/// Panics if `dst` has less than four values, or is not aligned.
+	pub fn store_aligned(self, dst: &mut [f32]) {
+		check_aligned(dst);
+		dst[..4].copy_from_slice(&[self.x, self.y, self.z, self.w]);
+	}
+
+	#[inline(always)]
+	/// Store the x, y, and z values of the [`Vector`] into the first three values of `dst`.
+	/// Panics if `dst` has less than three values.
+	pub fn store_xyz(self, dst: &mut [f32]) {
+		assert!(dst.len() >= 3, "Stored out of slice bounds");
+		dst[..3].copy_from_slice(&[self.x, self.y, self.z]);
 	}
 
 	#[inline(always)]
@@ -200,6 +255,17 @@ impl Vector {
 		}
 	}
 
+	#[inline(always)]
+	/// Get a [`Vector`] containing the square roots of x, y, z, and w.
+	pub fn sqrt(self) -> Self {
+		Self {
+			x: self.x.sqrt(),
+			y: self.y.sqrt(),
+			z: self.z.sqrt(),
+			w: self.w.sqrt(),
+		}
+	}
+
 	#[inline(always)]
 	/// Get the four-dimensional horizontal-sum of a [`Vector`].
 	pub fn hsum(self) -> f32 { self.x + self.y + self.z + self.w }
@@ -265,3 +331,94 @@ impl Vector {
 		}
 	}
 }
+
+#[repr(C, align(16))]
+#[derive(Copy, Clone, PartialEq)]
+/// Four unsigned 32-bit integers, for bit manipulation alongside [`Vector`]s.
+pub struct IVector {
+	x: u32,
+	y: u32,
+	z: u32,
+	w: u32,
+}
+
+impl BitAnd for IVector {
+	type Output = IVector;
+
+	#[inline(always)]
+	fn bitand(self, rhs: Self) -> Self {
+		Self {
+			x: self.x & rhs.x,
+			y: self.y & rhs.y,
+			z: self.z & rhs.z,
+			w: self.w & rhs.w,
+		}
+	}
+}
+
+impl BitOr for IVector {
+	type Output = IVector;
+
+	#[inline(always)]
+	fn bitor(self, rhs: Self) -> Self {
+		Self {
+			x: self.x | rhs.x,
+			y: self.y | rhs.y,
+			z: self.z | rhs.z,
+			w: self.w | rhs.w,
+		}
+	}
+}
+
+impl From<IVector> for [u32; 4] {
+	#[inline(always)]
+	fn from(val: IVector) -> Self { [val.x, val.y, val.z, val.w] }
+}
+
+impl Shl<u32> for IVector {
+	type Output = IVector;
+
+	#[inline(always)]
+	/// Shift every component left by `rhs` bits, which must be less than 32.
+	fn shl(self, rhs: u32) -> Self {
+		Self {
+			x: self.x << rhs,
+			y: self.y << rhs,
+			z: self.z << rhs,
+			w: self.w << rhs,
+		}
+	}
+}
+
+impl Shr<u32> for IVector {
+	type Output = IVector;
+
+	#[inline(always)]
+	/// Shift every component right by `rhs` bits, which must be less than 32.
+	fn shr(self, rhs: u32) -> Self {
+		Self {
+			x: self.x >> rhs,
+			y: self.y >> rhs,
+			z: self.z >> rhs,
+			w: self.w >> rhs,
+		}
+	}
+}
+
+impl IVector {
+	#[inline(always)]
+	/// Create an [`IVector`] from x, y, z, and w values.
+	pub fn new(x: u32, y: u32, z: u32, w: u32) -> Self { Self { x, y, z, w } }
+
+	#[inline(always)]
+	/// Convert every component of a [`Vector`] to an integer, rounding towards zero. The components must be at least
+	/// zero and less than `2^31`.
+	pub fn truncate(vec: Vector) -> Self {
+		Self {
+			x: vec.x as u32,
+			y: vec.y as u32,
+			z: vec.z as u32,
+			w: vec.w as u32,
+		}
+	}
+}
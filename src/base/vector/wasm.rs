@@ -1,10 +1,8 @@
 //! Implementation using SIMD intrinsics for WebAssembly.
 use core::arch::wasm32::*;
-use std::{
-	ops::{Add, Div, Mul, Sub},
-	slice::from_raw_parts,
-};
+use std::ops::{Add, BitAnd, BitOr, Div, Mul, Shl, Shr, Sub};
 
+use super::check_aligned;
 use crate::{is_shuffle_arg, shuffle_mask, Check, True};
 
 #[repr(transparent)]
@@ -136,7 +134,65 @@ impl Vector {
 	/// Panics if idx is not in the range [0, 3].
 	pub fn get(self, idx: u8) -> f32 {
 		assert!(idx < 4, "Indexed out of Vector bounds");
-		unsafe { from_raw_parts((&self as *const Vector) as *const f32, 4)[idx as usize] }
+		let mut data = [0f32; 4];
+		self.store(&mut data);
+		data[idx as usize]
+	}
+
+	#[inline(always)]
+	/// Load a [`Vector`] from the first four values of `src`.
+	/// Panics if `src` has less than four values.
+	pub fn load(src: &[f32]) -> Self {
+		assert!(src.len() >= 4, "Loaded out of slice bounds");
+		Self {
+			data: unsafe { v128_load(src.as_ptr() as *const v128) },
+		}
+	}
+
+	#[inline(always)]
+	/// Load a [`Vector`] from the first four values of `src`, which must start on a 16-byte boundary.
+	/// Panics if `src` has less than four values, or is not aligned.
+	pub fn load_aligned(src: &[f32]) -> Self {
+		check_aligned(src);
+		Self {
+			data: unsafe { v128_load(src.as_ptr() as *const v128) },
+		}
+	}
+
+	#[inline(always)]
+	/// Load a [`Vector`] from the first three values of `src`, with the w value set to `w`.
+	/// Panics if `src` has less than three values.
+	pub fn load_xyz(src: &[f32], w: f32) -> Self {
+		assert!(src.len() >= 3, "Loaded out of slice bounds");
+		let xy = unsafe { v128_load64_zero(src.as_ptr() as *const u64) };
+		Self {
+			data: f32x4_replace_lane::<3>(f32x4_replace_lane::<2>(xy, src[2]), w),
+		}
+	}
+
+	#[inline(always)]
+	/// Store the [`Vector`] into the first four values of `dst`.
+	/// Panics if `dst` has less than four values.
+	pub fn store(self, dst: &mut [f32]) {
+		assert!(dst.len() >= 4, "Stored out of slice bounds");
+		unsafe { v128_store(dst.as_mut_ptr() as *mut v128, self.data) }
+	}
+
+	#[inline(always)]
+	/// Store the [`Vector`] into the first four values of `dst`, which must start on a 16-byte boundary.
+	/// Panics if `dst` has less than four values, or is not aligned.
+	pub fn store_aligned(self, dst: &mut [f32]) {
+		check_aligned(dst);
+		unsafe { v128_store(dst.as_mut_ptr() as *mut v128, self.data) }
+	}
+
+	#[inline(always)]
+	/// Store the x, y, and z values of the [`Vector`] into the first three values of `dst`.
+	/// Panics if `dst` has less than three values.
+	pub fn store_xyz(self, dst: &mut [f32]) {
+		assert!(dst.len() >= 3, "Stored out of slice bounds");
+		unsafe { v128_store64_lane::<0>(self.data, dst.as_mut_ptr() as *mut u64) }
+		dst[2] = self.z();
 	}
 
 	#[inline(always)]
@@ -182,6 +238,14 @@ impl Vector {
 		}
 	}
 
+	#[inline(always)]
+	/// Get a [`Vector`] containing the square roots of x, y, z, and w.
+	pub fn sqrt(self) -> Self {
+		Self {
+			data: f32x4_sqrt(self.data),
+		}
+	}
+
 	#[inline(always)]
 	/// Get the four-dimensional horizontal-sum of a [`Vector`].
 	pub fn hsum(self) -> f32 {
@@ -244,6 +308,95 @@ impl Vector {
 	}
 }
 
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+/// Four unsigned 32-bit integers, for bit manipulation alongside [`Vector`]s.
+pub struct IVector {
+	data: v128,
+}
+
+impl BitAnd for IVector {
+	type Output = Self;
+
+	#[inline(always)]
+	fn bitand(self, rhs: Self) -> Self {
+		Self {
+			data: v128_and(self.data, rhs.data),
+		}
+	}
+}
+
+impl BitOr for IVector {
+	type Output = Self;
+
+	#[inline(always)]
+	fn bitor(self, rhs: Self) -> Self {
+		Self {
+			data: v128_or(self.data, rhs.data),
+		}
+	}
+}
+
+impl From<IVector> for [u32; 4] {
+	#[inline(always)]
+	fn from(val: IVector) -> Self {
+		[
+			u32x4_extract_lane::<0>(val.data),
+			u32x4_extract_lane::<1>(val.data),
+			u32x4_extract_lane::<2>(val.data),
+			u32x4_extract_lane::<3>(val.data),
+		]
+	}
+}
+
+impl PartialEq for IVector {
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool { i32x4_all_true(i32x4_eq(self.data, other.data)) }
+}
+
+impl Shl<u32> for IVector {
+	type Output = Self;
+
+	#[inline(always)]
+	/// Shift every component left by `rhs` bits, which must be less than 32.
+	fn shl(self, rhs: u32) -> Self {
+		Self {
+			data: i32x4_shl(self.data, rhs),
+		}
+	}
+}
+
+impl Shr<u32> for IVector {
+	type Output = Self;
+
+	#[inline(always)]
+	/// Shift every component right by `rhs` bits, which must be less than 32.
+	fn shr(self, rhs: u32) -> Self {
+		Self {
+			data: u32x4_shr(self.data, rhs),
+		}
+	}
+}
+
+impl IVector {
+	#[inline(always)]
+	/// Create an [`IVector`] from x, y, z, and w values.
+	pub fn new(x: u32, y: u32, z: u32, w: u32) -> Self {
+		Self {
+			data: u32x4(x, y, z, w),
+		}
+	}
+
+	#[inline(always)]
+	/// Convert every component of a [`Vector`] to an integer, rounding towards zero. The components must be at least
+	/// zero and less than `2^31`.
+	pub fn truncate(vec: Vector) -> Self {
+		Self {
+			data: i32x4_trunc_sat_f32x4(vec.data),
+		}
+	}
+}
+
 union Bits {
 	uints: [u32; 4],
 	vec: v128,
@@ -4,18 +4,16 @@
 use core::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use core::arch::x86_64::*;
-use std::{
-	ops::{Add, Div, Mul, Sub},
-	slice::from_raw_parts,
-};
+use std::ops::{Add, BitAnd, BitOr, Div, Mul, Shl, Shr, Sub};
 
+use super::check_aligned;
 use crate::{is_shuffle_arg, shuffle_mask, Check, True};
 
 #[repr(transparent)]
 #[derive(Copy, Clone)]
 /// A four-dimensional row vector.
 pub struct Vector {
-	data: __m128,
+	pub(super) data: __m128,
 }
 
 impl Add for Vector {
@@ -177,7 +175,70 @@ impl Vector {
 	/// Panics if idx is not in the range [0, 3].
 	pub fn get(self, idx: u8) -> f32 {
 		assert!(idx < 4, "Indexed out of Vector bounds");
-		unsafe { from_raw_parts((&self as *const Vector) as *const f32, 4)[idx as usize] }
+		let mut data = [0f32; 4];
+		self.store(&mut data);
+		data[idx as usize]
+	}
+
+	#[inline(always)]
+	/// Load a [`Vector`] from the first four values of `src`.
+	/// Panics if `src` has less than four values.
+	pub fn load(src: &[f32]) -> Self {
+		assert!(src.len() >= 4, "Loaded out of slice bounds");
+		Self {
+			data: unsafe { _mm_loadu_ps(src.as_ptr()) },
+		}
+	}
+
+	#[inline(always)]
+	/// Load a [`Vector`] from the first four values of `src`, which must start on a 16-byte boundary.
+	/// Panics if `src` has less than four values, or is not aligned.
+	pub fn load_aligned(src: &[f32]) -> Self {
+		check_aligned(src);
+		Self {
+			data: unsafe { _mm_load_ps(src.as_ptr()) },
+		}
+	}
+
+	#[inline(always)]
+	/// Load a [`Vector`] from the first three values of `src`, with the w value set to `w`.
+	/// Panics if `src` has less than three values.
+	pub fn load_xyz(src: &[f32], w: f32) -> Self {
+		assert!(src.len() >= 3, "Loaded out of slice bounds");
+		unsafe {
+			let xy = _mm_castsi128_ps(_mm_loadu_si64(src.as_ptr() as *const u8)); // xy00
+			let zw = _mm_unpacklo_ps(_mm_load_ss(src.as_ptr().add(2)), _mm_set_ss(w)); // zw00
+			Self {
+				data: _mm_movelh_ps(xy, zw),
+			}
+		}
+	}
+
+	#[inline(always)]
+	/// Store the [`Vector`] into the first four values of `dst`.
+	/// Panics if `dst` has less than four values.
+	pub fn store(self, dst: &mut [f32]) {
+		assert!(dst.len() >= 4, "Stored out of slice bounds");
+		unsafe { _mm_storeu_ps(dst.as_mut_ptr(), self.data) }
+	}
+
+	#[inline(always)]
+	/// Store the [`Vector`] into the first four values of `dst`, which must start on a 16-byte boundary.
+	/// Panics if `dst` has less than four values, or is not aligned.
+	pub fn store_aligned(self, dst: &mut [f32]) {
+		check_aligned(dst);
+		unsafe { _mm_store_ps(dst.as_mut_ptr(), self.data) }
+	}
+
+	#[inline(always)]
+	/// Store the x, y, and z values of the [`Vector`] into the first three values of `dst`.
+	/// Panics if `dst` has less than three values.
+	pub fn store_xyz(self, dst: &mut [f32]) {
+		assert!(dst.len() >= 3, "Stored out of slice bounds");
+		unsafe {
+			_mm_storel_epi64(dst.as_mut_ptr() as *mut __m128i, _mm_castps_si128(self.data));
+			_mm_store_ss(dst.as_mut_ptr().add(2), _mm_movehl_ps(self.data, self.data));
+		}
 	}
 
 	#[inline(always)]
@@ -215,6 +276,16 @@ impl Vector {
 		}
 	}
 
+	#[inline(always)]
+	/// Get a [`Vector`] containing the square roots of x, y, z, and w.
+	pub fn sqrt(self) -> Self {
+		unsafe {
+			Self {
+				data: _mm_sqrt_ps(self.data),
+			}
+		}
+	}
+
 	#[inline(always)]
 	/// Get the four-dimensional horizontal-sum of a [`Vector`].
 	pub fn hsum(self) -> f32 {
@@ -274,6 +345,94 @@ impl Vector {
 	}
 }
 
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+/// Four unsigned 32-bit integers, for bit manipulation alongside [`Vector`]s.
+pub struct IVector {
+	data: __m128i,
+}
+
+impl BitAnd for IVector {
+	type Output = Self;
+
+	#[inline(always)]
+	fn bitand(self, rhs: Self) -> Self {
+		Self {
+			data: unsafe { _mm_and_si128(self.data, rhs.data) },
+		}
+	}
+}
+
+impl BitOr for IVector {
+	type Output = Self;
+
+	#[inline(always)]
+	fn bitor(self, rhs: Self) -> Self {
+		Self {
+			data: unsafe { _mm_or_si128(self.data, rhs.data) },
+		}
+	}
+}
+
+impl From<IVector> for [u32; 4] {
+	#[inline(always)]
+	fn from(val: IVector) -> Self {
+		let mut data = [0u32; 4];
+		unsafe { _mm_storeu_si128(data.as_mut_ptr() as *mut __m128i, val.data) };
+		data
+	}
+}
+
+impl PartialEq for IVector {
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool {
+		unsafe { _mm_movemask_epi8(_mm_cmpeq_epi32(self.data, other.data)) == 0xffff }
+	}
+}
+
+impl Shl<u32> for IVector {
+	type Output = Self;
+
+	#[inline(always)]
+	/// Shift every component left by `rhs` bits, which must be less than 32.
+	fn shl(self, rhs: u32) -> Self {
+		Self {
+			data: unsafe { _mm_sll_epi32(self.data, _mm_cvtsi32_si128(rhs as i32)) },
+		}
+	}
+}
+
+impl Shr<u32> for IVector {
+	type Output = Self;
+
+	#[inline(always)]
+	/// Shift every component right by `rhs` bits, which must be less than 32.
+	fn shr(self, rhs: u32) -> Self {
+		Self {
+			data: unsafe { _mm_srl_epi32(self.data, _mm_cvtsi32_si128(rhs as i32)) },
+		}
+	}
+}
+
+impl IVector {
+	#[inline(always)]
+	/// Create an [`IVector`] from x, y, z, and w values.
+	pub fn new(x: u32, y: u32, z: u32, w: u32) -> Self {
+		Self {
+			data: unsafe { _mm_set_epi32(w as i32, z as i32, y as i32, x as i32) },
+		}
+	}
+
+	#[inline(always)]
+	/// Convert every component of a [`Vector`] to an integer, rounding towards zero. The components must be at least
+	/// zero and less than `2^31`.
+	pub fn truncate(vec: Vector) -> Self {
+		Self {
+			data: unsafe { _mm_cvttps_epi32(vec.data) },
+		}
+	}
+}
+
 union Bits {
 	uints: [u32; 4],
 	vec: __m128,
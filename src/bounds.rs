@@ -0,0 +1,336 @@
+//! Axis-aligned bounding boxes.
+
+use std::{
+	fmt::{Debug, Display},
+	ops::{Mul, MulAssign},
+};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+#[cfg(feature = "rayon")]
+use crate::PAR_CHUNK;
+use crate::{base::Vector, direction::Direction, point::Point, transform::Transform};
+
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+/// An axis-aligned bounding box. It is empty if `min` is greater than `max` along any axis.
+pub struct Bounds3 {
+	/// The corner with the smallest coordinates.
+	pub min: Point,
+	/// The corner with the largest coordinates.
+	pub max: Point,
+}
+
+impl Debug for Bounds3 {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Bounds [{:?}, {:?}]", self.min, self.max)
+	}
+}
+
+impl Default for Bounds3 {
+	#[inline(always)]
+	fn default() -> Self { Self::empty() }
+}
+
+impl Display for Bounds3 {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Bounds [{}, {}]", self.min, self.max)
+	}
+}
+
+impl From<Point> for Bounds3 {
+	#[inline(always)]
+	fn from(val: Point) -> Self { Self { min: val, max: val } }
+}
+
+impl Mul<Transform> for Bounds3 {
+	type Output = Self;
+
+	#[inline(always)]
+	/// Get the tightest [`Bounds3`] around the transformed box, using Arvo's method. Empty [`Bounds3`] stay empty.
+	fn mul(self, rhs: Transform) -> Self::Output {
+		if self.is_empty() {
+			return self;
+		}
+
+		let mut min = rhs.matrix.get_row(3);
+		let mut max = min;
+
+		let mins = [
+			self.min.0.shuffle::<0, 0, 0, 0>(),
+			self.min.0.shuffle::<1, 1, 1, 1>(),
+			self.min.0.shuffle::<2, 2, 2, 2>(),
+		];
+		let maxes = [
+			self.max.0.shuffle::<0, 0, 0, 0>(),
+			self.max.0.shuffle::<1, 1, 1, 1>(),
+			self.max.0.shuffle::<2, 2, 2, 2>(),
+		];
+		for (row, (&lo, &hi)) in mins.iter().zip(maxes.iter()).enumerate() {
+			let row = rhs.matrix.get_row(row as u8);
+			let a = row * lo;
+			let b = row * hi;
+			min += Vector::min(a, b);
+			max += Vector::max(a, b);
+		}
+
+		Self {
+			min: Point(min),
+			max: Point(max),
+		}
+	}
+}
+
+impl MulAssign<Transform> for Bounds3 {
+	#[inline(always)]
+	fn mul_assign(&mut self, rhs: Transform) { *self = *self * rhs }
+}
+
+impl Bounds3 {
+	#[inline(always)]
+	/// Create the smallest [`Bounds3`] containing two [`Point`]s.
+	pub fn new(p1: Point, p2: Point) -> Self {
+		Self {
+			min: Point(Vector::min(p1.0, p2.0)),
+			max: Point(Vector::max(p1.0, p2.0)),
+		}
+	}
+
+	#[inline(always)]
+	/// Get an empty [`Bounds3`], that contains nothing and can be expanded.
+	pub fn empty() -> Self {
+		Self {
+			min: Point::new(f32::INFINITY, f32::INFINITY, f32::INFINITY),
+			max: Point::new(f32::NEG_INFINITY, f32::NEG_INFINITY, f32::NEG_INFINITY),
+		}
+	}
+
+	#[inline(always)]
+	/// Check if the [`Bounds3`] is empty.
+	pub fn is_empty(self) -> bool { Vector::min(self.min.0, self.max.0) != self.min.0 }
+
+	#[inline(always)]
+	/// Get the smallest [`Bounds3`] containing both `lhs` and `rhs`.
+	pub fn union(lhs: Self, rhs: Self) -> Self {
+		Self {
+			min: Point(Vector::min(lhs.min.0, rhs.min.0)),
+			max: Point(Vector::max(lhs.max.0, rhs.max.0)),
+		}
+	}
+
+	#[inline(always)]
+	/// Get the [`Bounds3`] that is contained in both `lhs` and `rhs`. Is empty if they do not overlap.
+	pub fn intersection(lhs: Self, rhs: Self) -> Self {
+		Self {
+			min: Point(Vector::max(lhs.min.0, rhs.min.0)),
+			max: Point(Vector::min(lhs.max.0, rhs.max.0)),
+		}
+	}
+
+	#[inline(always)]
+	/// Get the smallest [`Bounds3`] containing both the [`Bounds3`] and `point`.
+	pub fn expand(self, point: Point) -> Self {
+		Self {
+			min: Point(Vector::min(self.min.0, point.0)),
+			max: Point(Vector::max(self.max.0, point.0)),
+		}
+	}
+
+	/// Get the smallest [`Bounds3`] containing every [`Point`] in `points`, which is empty if there are none.
+	pub fn from_points(points: &[Point]) -> Self { points.iter().fold(Self::empty(), |acc, &point| acc.expand(point)) }
+
+	#[cfg(feature = "rayon")]
+	/// Get the smallest [`Bounds3`] containing every [`Point`] in `points`, in parallel. Gives the same results as
+	/// [`Bounds3::from_points`].
+	pub fn par_from_points(points: &[Point]) -> Self {
+		points
+			.par_chunks(PAR_CHUNK)
+			.map(Self::from_points)
+			.reduce(Self::empty, Self::union)
+	}
+
+	#[inline(always)]
+	/// Check if `lhs` and `rhs` overlap, including touching boundaries.
+	pub fn overlaps(lhs: Self, rhs: Self) -> bool { !Self::intersection(lhs, rhs).is_empty() }
+
+	#[inline(always)]
+	/// Get the center of the [`Bounds3`].
+	pub fn center(self) -> Point { Point((self.min.0 + self.max.0) * 0.5f32) }
+
+	#[inline(always)]
+	/// Get the size of the [`Bounds3`] along each axis.
+	pub fn extent(self) -> Direction { self.max - self.min }
+
+	#[inline(always)]
+	/// Get the surface area of the [`Bounds3`].
+	pub fn surface_area(self) -> f32 {
+		let extent = self.extent();
+		2f32 * Direction::dot(extent, extent.shuffle::<1, 2, 0>())
+	}
+
+	#[inline(always)]
+	/// Get the volume of the [`Bounds3`].
+	pub fn volume(self) -> f32 {
+		let extent = self.extent();
+		extent.x() * extent.y() * extent.z()
+	}
+
+	#[inline(always)]
+	/// Get the axis (0 for x, 1 for y, and 2 for z) along which the [`Bounds3`] is the largest.
+	pub fn maximum_extent(self) -> u8 {
+		let extent = self.extent();
+		if extent.x() > extent.y() && extent.x() > extent.z() {
+			0
+		} else if extent.y() > extent.z() {
+			1
+		} else {
+			2
+		}
+	}
+
+	#[inline(always)]
+	/// Get one of the eight corners of the [`Bounds3`]. Bits 0, 1, and 2 of `idx` select the `max` x, y, and z.
+	/// Panics if idx is not in the range [0, 7].
+	pub fn corner(self, idx: u8) -> Point {
+		assert!(idx < 8, "Indexed out of Bounds3 corners");
+		Point::new(
+			if idx & 1 == 0 { self.min.x() } else { self.max.x() },
+			if idx & 2 == 0 { self.min.y() } else { self.max.y() },
+			if idx & 4 == 0 { self.min.z() } else { self.max.z() },
+		)
+	}
+
+	#[inline(always)]
+	/// Get the position of `point` relative to the [`Bounds3`], where `min` is at 0 and `max` is at 1.
+	/// This is the inverse of [`Bounds3::lerp`]. Axes along which the [`Bounds3`] is flat are not scaled.
+	pub fn offset(self, point: Point) -> Direction {
+		let extent = self.extent();
+		let safe = |x: f32| if x > 0f32 { x } else { 1f32 };
+		Direction((point - self.min).0 / Vector::new(safe(extent.x()), safe(extent.y()), safe(extent.z()), 1f32))
+	}
+
+	#[inline(always)]
+	/// Linear interpolate from `min` to `max`, with a separate factor along each axis.
+	pub fn lerp(self, t: Direction) -> Point { self.min + Direction(self.extent().0 * t.0) }
+}
+
+#[cfg(test)]
+mod tests {
+	use std::f32::consts::FRAC_PI_4;
+
+	use super::*;
+	use crate::{base::nearly_equal, shapes::Contains, Rotation};
+
+	fn unit() -> Bounds3 { Bounds3::new(Point::new(0f32, 0f32, 0f32), Point::new(1f32, 2f32, 3f32)) }
+
+	#[test]
+	fn union_and_intersection() {
+		let other = Bounds3::new(Point::new(0.5f32, -1f32, 1f32), Point::new(4f32, 1f32, 2f32));
+
+		assert_eq!(
+			Bounds3::union(unit(), other),
+			Bounds3::new(Point::new(0f32, -1f32, 0f32), Point::new(4f32, 2f32, 3f32))
+		);
+		assert_eq!(
+			Bounds3::intersection(unit(), other),
+			Bounds3::new(Point::new(0.5f32, 0f32, 1f32), Point::new(1f32, 1f32, 2f32))
+		);
+		assert_eq!(Bounds3::union(Bounds3::empty(), unit()), unit());
+	}
+
+	#[test]
+	fn expand() {
+		let bounds = Bounds3::empty()
+			.expand(Point::new(1f32, 2f32, 3f32))
+			.expand(Point::new(0f32, 0f32, 0f32));
+
+		assert_eq!(bounds, unit());
+		assert!(Bounds3::empty().is_empty());
+		assert!(!bounds.is_empty());
+	}
+
+	#[test]
+	fn contains_and_overlaps() {
+		assert!(unit().contains(Point::new(0.5f32, 2f32, 1f32)));
+		assert!(!unit().contains(Point::new(0.5f32, 2.5f32, 1f32)));
+
+		let touching = Bounds3::new(Point::new(1f32, 0f32, 0f32), Point::new(2f32, 1f32, 1f32));
+		let apart = Bounds3::new(Point::new(1.5f32, 0f32, 0f32), Point::new(2f32, 1f32, 1f32));
+		assert!(Bounds3::overlaps(unit(), touching));
+		assert!(!Bounds3::overlaps(unit(), apart));
+		assert!(!Bounds3::overlaps(unit(), Bounds3::empty()));
+	}
+
+	#[test]
+	fn measures() {
+		let bounds = unit();
+
+		assert_eq!(bounds.center(), Point::new(0.5f32, 1f32, 1.5f32));
+		assert_eq!(bounds.extent(), Direction::new(1f32, 2f32, 3f32));
+		assert_eq!(bounds.surface_area(), 22f32);
+		assert_eq!(bounds.volume(), 6f32);
+		assert_eq!(bounds.maximum_extent(), 2);
+		assert_eq!(bounds.corner(5), Point::new(1f32, 0f32, 3f32));
+	}
+
+	#[test]
+	fn offset_and_lerp() {
+		let bounds = unit();
+		let point = Point::new(0.25f32, 1f32, 3f32);
+
+		assert_eq!(bounds.offset(point), Direction::new(0.25f32, 0.5f32, 1f32));
+		assert_eq!(bounds.lerp(bounds.offset(point)), point);
+	}
+
+	#[test]
+	fn transform() {
+		let bounds = unit() * Transform::translate(Direction::new(1f32, 1f32, 1f32));
+		assert_eq!(
+			bounds,
+			Bounds3::new(Point::new(1f32, 1f32, 1f32), Point::new(2f32, 3f32, 4f32))
+		);
+
+		let cube = Bounds3::new(Point::new(-1f32, -1f32, -1f32), Point::new(1f32, 1f32, 1f32));
+		let rotated = cube * Transform::rotate(Rotation::axis_angle(Direction::new(0f32, 0f32, 1f32), FRAC_PI_4));
+		let expected = 2f32.sqrt();
+		assert!(nearly_equal(rotated.max.x(), expected, 0.0001f32));
+		assert!(nearly_equal(rotated.min.y(), -expected, 0.0001f32));
+		assert!(nearly_equal(rotated.max.z(), 1f32, 0.0001f32));
+	}
+
+	#[test]
+	fn transform_empty() {
+		let rotation = Transform::rotate(Rotation::axis_angle(Direction::new(1f32, 2f32, 3f32), 0.5f32));
+		assert_eq!(Bounds3::empty() * rotation, Bounds3::empty());
+		assert!((Bounds3::empty() * Transform::translate(Direction::new(1f32, 2f32, 3f32))).is_empty());
+	}
+
+	#[test]
+	fn from_points() {
+		assert_eq!(Bounds3::from_points(&[]), Bounds3::empty());
+		let points = [
+			Point::new(1f32, -2f32, 0f32),
+			Point::new(-1f32, 3f32, 0.5f32),
+			Point::new(0f32, 0f32, 4f32),
+		];
+		assert_eq!(
+			Bounds3::from_points(&points),
+			Bounds3::new(Point::new(-1f32, -2f32, 0f32), Point::new(1f32, 3f32, 4f32))
+		);
+	}
+
+	#[cfg(feature = "rayon")]
+	#[test]
+	fn par_from_points() {
+		use crate::random::{self, Pcg32};
+
+		let mut rng = Pcg32::new(1, 0);
+		let region = Bounds3::new(Point::new(-5f32, -5f32, -5f32), Point::new(5f32, 5f32, 5f32));
+		let points: Vec<_> = (0..5003).map(|_| random::point_in_bounds(&mut rng, region)).collect();
+		assert_eq!(Bounds3::par_from_points(&points), Bounds3::from_points(&points));
+		assert_eq!(Bounds3::par_from_points(&[]), Bounds3::empty());
+	}
+}
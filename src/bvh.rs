@@ -0,0 +1,666 @@
+//! Bounding volume hierarchies over arbitrary primitives.
+//!
+//! A [`Bvh`] only knows about the [`Bounds3`] of each primitive, and refers to primitives by their index in the slice
+//! it was built from. Queries take a closure that tests the actual primitive.
+
+use std::{
+	fmt::{Debug, Display},
+	ops::Range,
+};
+
+use crate::{
+	base::Vector,
+	bounds::Bounds3,
+	morton,
+	point::Point,
+	ray::{Ray, RayHit},
+};
+
+/// The maximum number of primitives in a leaf.
+const MAX_LEAF: usize = 4;
+/// The number of bins used to evaluate the surface area heuristic.
+const BINS: usize = 12;
+
+#[derive(Copy, Clone, PartialEq)]
+/// A node of a [`Bvh`].
+///
+/// Nodes are stored in depth-first order: the first child of an interior node is directly after it.
+pub struct BvhNode {
+	/// The bounds of everything under the [`BvhNode`].
+	pub bounds: Bounds3,
+	/// The second child for interior nodes, the first primitive for leaves.
+	offset: u32,
+	/// The number of primitives in a leaf, or zero for interior nodes.
+	count: u32,
+}
+
+impl Debug for BvhNode {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		if self.is_leaf() {
+			write!(f, "Leaf [{:?}, {:?}]", self.bounds, self.primitives())
+		} else {
+			write!(f, "Interior [{:?}, {}]", self.bounds, self.offset)
+		}
+	}
+}
+
+impl Display for BvhNode {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		if self.is_leaf() {
+			write!(f, "Leaf [{}, {:?}]", self.bounds, self.primitives())
+		} else {
+			write!(f, "Interior [{}, {}]", self.bounds, self.offset)
+		}
+	}
+}
+
+impl BvhNode {
+	#[inline(always)]
+	const fn leaf(bounds: Bounds3, first: usize, count: usize) -> Self {
+		Self {
+			bounds,
+			offset: first as u32,
+			count: count as u32,
+		}
+	}
+
+	#[inline(always)]
+	/// Check if the [`BvhNode`] is a leaf.
+	pub const fn is_leaf(&self) -> bool { self.count > 0 }
+
+	#[inline(always)]
+	/// Get the range of [`Bvh::indices`] that hold the primitives of a leaf. Empty for interior nodes.
+	pub const fn primitives(&self) -> Range<usize> { self.offset as usize..(self.offset + self.count) as usize }
+
+	#[inline(always)]
+	/// Get the index of the second child of an interior node. The first child is the node right after it.
+	pub const fn second_child(&self) -> Option<usize> {
+		if self.is_leaf() {
+			None
+		} else {
+			Some(self.offset as usize)
+		}
+	}
+}
+
+#[derive(Clone, Default)]
+/// A bounding volume hierarchy, flattened into an array of [`BvhNode`]s.
+pub struct Bvh {
+	nodes: Vec<BvhNode>,
+	indices: Vec<u32>,
+}
+
+impl Debug for Bvh {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Bvh [{} nodes, {} primitives]", self.nodes.len(), self.indices.len())
+	}
+}
+
+impl Bvh {
+	/// Build a [`Bvh`] over primitives with the given `bounds`, using the binned surface area heuristic.
+	///
+	/// Slower to build than [`Bvh::build_lbvh`], but faster to query.
+	pub fn build(bounds: &[Bounds3]) -> Self {
+		let centroids: Vec<_> = bounds.iter().map(|bounds| bounds.center()).collect();
+		let mut bvh = Self::with_indices(bounds.len());
+		if !bounds.is_empty() {
+			let mut indices = std::mem::take(&mut bvh.indices);
+			bvh.build_sah(bounds, &centroids, &mut indices, 0);
+			bvh.indices = indices;
+		}
+		bvh
+	}
+
+	/// Build a linear [`Bvh`] over primitives with the given `bounds`, by sorting them along a Morton curve.
+	///
+	/// Much faster to build than [`Bvh::build`], but slower to query.
+	pub fn build_lbvh(bounds: &[Bounds3]) -> Self {
+		let mut bvh = Self::with_indices(bounds.len());
+		if bounds.is_empty() {
+			return bvh;
+		}
+
+		let centroid_bounds = bounds
+			.iter()
+			.fold(Bounds3::empty(), |acc, bounds| acc.expand(bounds.center()));
+		let mut codes: Vec<_> = bounds
+			.iter()
+			.enumerate()
+			.map(|(idx, bounds)| (morton::encode_30(bounds.center(), centroid_bounds), idx as u32))
+			.collect();
+		morton::radix_sort(&mut codes, 3 * morton::BITS_30, |&(code, _)| code as u64);
+
+		bvh.indices = codes.iter().map(|&(_, idx)| idx).collect();
+		let codes: Vec<_> = codes.iter().map(|&(code, _)| code).collect();
+		bvh.build_morton(bounds, &codes, 0);
+		bvh
+	}
+
+	#[inline(always)]
+	/// Get the flattened [`BvhNode`]s. The root is the first node, if there is one.
+	pub fn nodes(&self) -> &[BvhNode] { &self.nodes }
+
+	#[inline(always)]
+	/// Get the primitive indices that leaves refer to with [`BvhNode::primitives`].
+	pub fn indices(&self) -> &[u32] { &self.indices }
+
+	/// Find the closest hit along `ray`.
+	///
+	/// `intersect` is called with the [`Ray`] and the index of a primitive. The `t_max` of the [`Ray`] is shortened to
+	/// the closest hit found so far.
+	pub fn closest_hit(
+		&self, mut ray: Ray, mut intersect: impl FnMut(&Ray, usize) -> Option<RayHit>,
+	) -> Option<(usize, RayHit)> {
+		let mut closest = None;
+		self.traverse_ray(&mut ray, |ray, prim| {
+			if let Some(hit) = intersect(ray, prim) {
+				if hit.t < ray.t_max {
+					ray.t_max = hit.t;
+					closest = Some((prim, hit));
+				}
+			}
+			false
+		});
+		closest
+	}
+
+	/// Check if `ray` hits any primitive, stopping at the first hit found.
+	///
+	/// `intersect` is called with the [`Ray`] and the index of a primitive.
+	pub fn any_hit(&self, mut ray: Ray, mut intersect: impl FnMut(&Ray, usize) -> bool) -> bool {
+		let mut hit = false;
+		self.traverse_ray(&mut ray, |ray, prim| {
+			hit = intersect(ray, prim);
+			hit
+		});
+		hit
+	}
+
+	/// Call `callback` with the index of every primitive in a leaf that overlaps `bounds`.
+	///
+	/// This includes every primitive whose bounds overlap `bounds`, but may include a few more that share a leaf with
+	/// them, which `callback` should test itself.
+	pub fn for_each_overlapping(&self, bounds: Bounds3, mut callback: impl FnMut(usize)) {
+		if self.nodes.is_empty() {
+			return;
+		}
+
+		let mut stack = Vec::with_capacity(64);
+		stack.push(0);
+		while let Some(idx) = stack.pop() {
+			let node = &self.nodes[idx];
+			if !Bounds3::overlaps(node.bounds, bounds) {
+				continue;
+			}
+
+			match node.second_child() {
+				Some(second) => {
+					stack.push(second);
+					stack.push(idx + 1);
+				},
+				None => {
+					for &prim in &self.indices[node.primitives()] {
+						callback(prim as usize);
+					}
+				},
+			}
+		}
+	}
+
+	/// Find the primitive nearest to `point`.
+	///
+	/// `distance_square` is called with `point` and the index of a primitive, and returns the square of the distance
+	/// between them. Returns the index of the nearest primitive and its squared distance.
+	pub fn nearest(&self, point: Point, mut distance_square: impl FnMut(Point, usize) -> f32) -> Option<(usize, f32)> {
+		if self.nodes.is_empty() {
+			return None;
+		}
+
+		let mut nearest = None;
+		let mut best = f32::INFINITY;
+		let mut stack = Vec::with_capacity(64);
+		stack.push((0, bounds_distance_square(self.nodes[0].bounds, point)));
+		while let Some((idx, dist)) = stack.pop() {
+			if dist > best {
+				continue;
+			}
+
+			let node = &self.nodes[idx];
+			match node.second_child() {
+				Some(second) => {
+					let first = (idx + 1, bounds_distance_square(self.nodes[idx + 1].bounds, point));
+					let second = (second, bounds_distance_square(self.nodes[second].bounds, point));
+					// Visit the nearer child first.
+					if first.1 <= second.1 {
+						stack.push(second);
+						stack.push(first);
+					} else {
+						stack.push(first);
+						stack.push(second);
+					}
+				},
+				None => {
+					for &prim in &self.indices[node.primitives()] {
+						let dist = distance_square(point, prim as usize);
+						if dist < best {
+							best = dist;
+							nearest = Some((prim as usize, dist));
+						}
+					}
+				},
+			}
+		}
+		nearest
+	}
+
+	/// Update the bounds of every node after the primitives have moved, keeping the structure of the tree.
+	///
+	/// Much faster than rebuilding, but queries get slower as the primitives drift away from their original layout.
+	///
+	/// # Panics
+	/// If `bounds` does not have the same length as the one the [`Bvh`] was built with.
+	pub fn refit(&mut self, bounds: &[Bounds3]) {
+		assert_eq!(
+			bounds.len(),
+			self.indices.len(),
+			"Refitting Bvh with a different number of primitives"
+		);
+
+		// Children are always after their parents.
+		for idx in (0..self.nodes.len()).rev() {
+			let node = self.nodes[idx];
+			self.nodes[idx].bounds = match node.second_child() {
+				Some(second) => Bounds3::union(self.nodes[idx + 1].bounds, self.nodes[second].bounds),
+				None => self.leaf_bounds(bounds, node.primitives()),
+			};
+		}
+	}
+
+	fn with_indices(count: usize) -> Self {
+		assert!(count <= u32::MAX as usize, "Too many primitives for a Bvh");
+		Self {
+			nodes: Vec::with_capacity(count * 2),
+			indices: (0..count as u32).collect(),
+		}
+	}
+
+	fn leaf_bounds(&self, bounds: &[Bounds3], range: Range<usize>) -> Bounds3 {
+		self.indices[range].iter().fold(Bounds3::empty(), |acc, &prim| {
+			Bounds3::union(acc, bounds[prim as usize])
+		})
+	}
+
+	/// Build the subtree over `indices`, which start at `first` in the final index array.
+	fn build_sah(&mut self, bounds: &[Bounds3], centroids: &[Point], indices: &mut [u32], first: usize) -> usize {
+		let count = indices.len();
+		let node_bounds = indices.iter().fold(Bounds3::empty(), |acc, &prim| {
+			Bounds3::union(acc, bounds[prim as usize])
+		});
+		let idx = self.nodes.len();
+		self.nodes.push(BvhNode::leaf(node_bounds, first, count));
+		if count == 1 {
+			return idx;
+		}
+
+		let centroid_bounds = indices
+			.iter()
+			.fold(Bounds3::empty(), |acc, &prim| acc.expand(centroids[prim as usize]));
+		let axis = centroid_bounds.maximum_extent();
+		let min = centroid_bounds.min.0.get(axis);
+		let extent = centroid_bounds.extent().0.get(axis);
+
+		let mid = if extent > 0f32 {
+			let bin_of = |prim: u32| {
+				let offset = (centroids[prim as usize].0.get(axis) - min) / extent;
+				((offset * BINS as f32) as usize).min(BINS - 1)
+			};
+
+			let mut bins = [(Bounds3::empty(), 0usize); BINS];
+			for &prim in indices.iter() {
+				let bin = &mut bins[bin_of(prim)];
+				bin.0 = Bounds3::union(bin.0, bounds[prim as usize]);
+				bin.1 += 1;
+			}
+
+			// Sweep from the right to get the cost of everything after each split, then from the left.
+			let mut right_costs = [0f32; BINS];
+			let mut acc = (Bounds3::empty(), 0usize);
+			for split in (1..BINS).rev() {
+				acc = (Bounds3::union(acc.0, bins[split].0), acc.1 + bins[split].1);
+				right_costs[split] = if acc.1 == 0 {
+					f32::INFINITY
+				} else {
+					acc.0.surface_area() * acc.1 as f32
+				};
+			}
+
+			let mut best = (f32::INFINITY, 0);
+			let mut acc = (Bounds3::empty(), 0usize);
+			for split in 1..BINS {
+				acc = (Bounds3::union(acc.0, bins[split - 1].0), acc.1 + bins[split - 1].1);
+				if acc.1 == 0 {
+					continue;
+				}
+
+				let cost = acc.0.surface_area() * acc.1 as f32 + right_costs[split];
+				if cost < best.0 {
+					best = (cost, split);
+				}
+			}
+
+			let split_cost = 1f32 + best.0 / node_bounds.surface_area();
+			if count <= MAX_LEAF && split_cost >= count as f32 {
+				return idx;
+			}
+
+			match partition(indices, |prim| bin_of(prim) < best.1) {
+				0 => count / 2,
+				mid if mid == count => count / 2,
+				mid => mid,
+			}
+		} else if count <= MAX_LEAF {
+			return idx;
+		} else {
+			count / 2
+		};
+
+		let (left, right) = indices.split_at_mut(mid);
+		self.build_sah(bounds, centroids, left, first);
+		let second = self.build_sah(bounds, centroids, right, first + mid);
+		self.nodes[idx] = BvhNode {
+			bounds: node_bounds,
+			offset: second as u32,
+			count: 0,
+		};
+		idx
+	}
+
+	/// Build the subtree over the sorted `codes`, which start at `first` in the index array.
+	fn build_morton(&mut self, bounds: &[Bounds3], codes: &[u32], first: usize) -> usize {
+		let count = codes.len();
+		let node_bounds = self.leaf_bounds(bounds, first..first + count);
+		let idx = self.nodes.len();
+		self.nodes.push(BvhNode::leaf(node_bounds, first, count));
+		if count <= MAX_LEAF {
+			return idx;
+		}
+
+		let first_code = codes[0];
+		let last_code = codes[count - 1];
+		let mid = if first_code == last_code {
+			count / 2
+		} else {
+			// Split where the highest differing bit changes.
+			let prefix = (first_code ^ last_code).leading_zeros();
+			codes.partition_point(|&code| (first_code ^ code).leading_zeros() > prefix)
+		};
+
+		self.build_morton(bounds, &codes[..mid], first);
+		let second = self.build_morton(bounds, &codes[mid..], first + mid);
+		self.nodes[idx] = BvhNode {
+			bounds: node_bounds,
+			offset: second as u32,
+			count: 0,
+		};
+		idx
+	}
+
+	/// Visit every primitive in a leaf hit by `ray`, nearest node first, until `visit` returns `true`.
+	fn traverse_ray(&self, ray: &mut Ray, mut visit: impl FnMut(&mut Ray, usize) -> bool) {
+		if self.nodes.is_empty() {
+			return;
+		}
+
+		let inv_dir = Vector::new(1f32, 1f32, 1f32, 0f32) / (ray.dir.0 + Vector::new(0f32, 0f32, 0f32, 1f32));
+		if slab(self.nodes[0].bounds, ray, inv_dir).is_none() {
+			return;
+		}
+
+		let mut stack = Vec::with_capacity(64);
+		stack.push(0);
+		while let Some(idx) = stack.pop() {
+			let node = &self.nodes[idx];
+			match node.second_child() {
+				Some(second) => {
+					let first = idx + 1;
+					match (
+						slab(self.nodes[first].bounds, ray, inv_dir),
+						slab(self.nodes[second].bounds, ray, inv_dir),
+					) {
+						(Some(t_first), Some(t_second)) => {
+							// Visit the nearer child first.
+							if t_first <= t_second {
+								stack.push(second);
+								stack.push(first);
+							} else {
+								stack.push(first);
+								stack.push(second);
+							}
+						},
+						(Some(_), None) => stack.push(first),
+						(None, Some(_)) => stack.push(second),
+						(None, None) => {},
+					}
+				},
+				None => {
+					// The node may have been pushed before a closer hit shortened the ray.
+					if slab(node.bounds, ray, inv_dir).is_none() {
+						continue;
+					}
+					for &prim in &self.indices[node.primitives()] {
+						if visit(ray, prim as usize) {
+							return;
+						}
+					}
+				},
+			}
+		}
+	}
+}
+
+#[inline(always)]
+/// Get the distance along `ray` at which it enters `bounds`, or zero if it starts inside.
+fn slab(bounds: Bounds3, ray: &Ray, inv_dir: Vector) -> Option<f32> {
+	let (near, mut far) = ray.slabs(bounds, inv_dir);
+	far.set_w(ray.t_max);
+
+	let t_near = near.hmax();
+	if t_near <= far.hmin() {
+		Some(t_near)
+	} else {
+		None
+	}
+}
+
+#[inline(always)]
+/// Get the square of the distance from `point` to the closest [`Point`] in `bounds`.
+fn bounds_distance_square(bounds: Bounds3, point: Point) -> f32 {
+	(point - Point(Vector::clamp(point.0, bounds.min.0, bounds.max.0))).length_square()
+}
+
+/// Move every element for which `pred` is true to the start of `slice`, and return how many there are.
+fn partition(slice: &mut [u32], pred: impl Fn(u32) -> bool) -> usize {
+	let mut mid = 0;
+	for idx in 0..slice.len() {
+		if pred(slice[idx]) {
+			slice.swap(mid, idx);
+			mid += 1;
+		}
+	}
+	mid
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		direction::Direction,
+		random::{self, Pcg32, Rng},
+		shapes::Sphere,
+	};
+
+	/// The bounds of a square of side `2 * half` in the xy plane, at `z`.
+	fn square(half: f32, z: f32) -> Bounds3 { Bounds3::new(Point::new(-half, -half, z), Point::new(half, half, z)) }
+
+	fn spheres(count: usize, offset: f32) -> Vec<Sphere> {
+		let mut rng = Pcg32::new(count as u64, 0);
+		let region = Bounds3::new(
+			Point::new(offset - 20f32, -20f32, -20f32),
+			Point::new(offset + 20f32, 20f32, 20f32),
+		);
+		(0..count)
+			.map(|_| {
+				Sphere::new(
+					random::point_in_bounds(&mut rng, region),
+					rng.next_range(0.1f32, 1.1f32),
+				)
+			})
+			.collect()
+	}
+
+	fn rays() -> impl Iterator<Item = Ray> {
+		let mut rng = Pcg32::new(0, 1);
+		(0..200).map(move |_| {
+			let origin = random::point_in_bounds(&mut rng, square(30f32, -40f32));
+			let target = random::point_in_bounds(&mut rng, square(15f32, 0f32));
+			Ray::new(origin, (target - origin).normalize())
+		})
+	}
+
+	fn distance_square(sphere: Sphere, point: Point) -> f32 {
+		let dist = ((point - sphere.center()).length() - sphere.radius()).max(0f32);
+		dist * dist
+	}
+
+	/// Check the invariants of the flattened layout, and that every primitive is in exactly one leaf.
+	fn check_structure(bvh: &Bvh, bounds: &[Bounds3]) {
+		let mut seen = vec![false; bounds.len()];
+		for (idx, node) in bvh.nodes().iter().enumerate() {
+			match node.second_child() {
+				Some(second) => {
+					assert!(second > idx + 1);
+					let children = Bounds3::union(bvh.nodes()[idx + 1].bounds, bvh.nodes()[second].bounds);
+					assert_eq!(children, node.bounds);
+				},
+				None => {
+					for &prim in &bvh.indices()[node.primitives()] {
+						assert!(!seen[prim as usize]);
+						seen[prim as usize] = true;
+						assert_eq!(Bounds3::union(node.bounds, bounds[prim as usize]), node.bounds);
+					}
+				},
+			}
+		}
+		assert!(seen.iter().all(|&x| x));
+	}
+
+	fn check_queries(bvh: &Bvh, spheres: &[Sphere]) {
+		for ray in rays() {
+			let brute = spheres
+				.iter()
+				.enumerate()
+				.filter_map(|(idx, sphere)| ray.intersect_sphere(*sphere).map(|hit| (idx, hit)))
+				.min_by(|a, b| a.1.t.partial_cmp(&b.1.t).unwrap());
+			let hit = bvh.closest_hit(ray, |ray, idx| ray.intersect_sphere(spheres[idx]));
+			assert_eq!(hit.map(|(idx, _)| idx), brute.map(|(idx, _)| idx));
+
+			let any = bvh.any_hit(ray, |ray, idx| ray.intersect_sphere(spheres[idx]).is_some());
+			assert_eq!(any, brute.is_some());
+		}
+
+		let mut rng = Pcg32::new(0, 2);
+		for _ in 0..50 {
+			let query = Bounds3::new(
+				random::point_in_bounds(&mut rng, square(20f32, -5f32)),
+				random::point_in_bounds(&mut rng, square(20f32, 5f32)),
+			);
+			let mut found = Vec::new();
+			bvh.for_each_overlapping(query, |idx| {
+				if Bounds3::overlaps(spheres[idx].bounds(), query) {
+					found.push(idx);
+				}
+			});
+			found.sort_unstable();
+			let brute: Vec<_> = (0..spheres.len())
+				.filter(|&idx| Bounds3::overlaps(spheres[idx].bounds(), query))
+				.collect();
+			assert_eq!(found, brute);
+
+			let point = random::point_in_bounds(&mut rng, square(30f32, 0f32));
+			let nearest = bvh.nearest(point, |point, idx| distance_square(spheres[idx], point));
+			let brute = spheres
+				.iter()
+				.map(|&sphere| distance_square(sphere, point))
+				.fold(f32::INFINITY, f32::min);
+			assert_eq!(nearest.map(|(_, dist)| dist), Some(brute));
+		}
+	}
+
+	#[test]
+	fn sah() {
+		let spheres = spheres(300, 0f32);
+		let bounds: Vec<_> = spheres.iter().map(|sphere| sphere.bounds()).collect();
+		let bvh = Bvh::build(&bounds);
+
+		check_structure(&bvh, &bounds);
+		check_queries(&bvh, &spheres);
+	}
+
+	#[test]
+	fn lbvh() {
+		let spheres = spheres(300, 0f32);
+		let bounds: Vec<_> = spheres.iter().map(|sphere| sphere.bounds()).collect();
+		let bvh = Bvh::build_lbvh(&bounds);
+
+		check_structure(&bvh, &bounds);
+		check_queries(&bvh, &spheres);
+	}
+
+	#[test]
+	fn refit() {
+		let bounds: Vec<_> = spheres(300, 0f32).iter().map(|sphere| sphere.bounds()).collect();
+		let mut bvh = Bvh::build(&bounds);
+
+		let moved = spheres(300, 5f32);
+		let bounds: Vec<_> = moved.iter().map(|sphere| sphere.bounds()).collect();
+		bvh.refit(&bounds);
+
+		check_structure(&bvh, &bounds);
+		check_queries(&bvh, &moved);
+	}
+
+	#[test]
+	fn degenerate() {
+		let empty = Bvh::build(&[]);
+		assert!(empty.nodes().is_empty());
+		assert!(
+			!empty.any_hit(Ray::new(Point::default(), Direction::new(1f32, 0f32, 0f32)), |_, _| {
+				true
+			})
+		);
+		assert_eq!(empty.nearest(Point::default(), |_, _| 0f32), None);
+
+		// Identical primitives cannot be split by position.
+		let bounds = vec![Bounds3::new(Point::new(0f32, 0f32, 0f32), Point::new(1f32, 1f32, 1f32)); 37];
+		for bvh in [Bvh::build(&bounds), Bvh::build_lbvh(&bounds)].iter() {
+			check_structure(bvh, &bounds);
+			let mut count = 0;
+			bvh.for_each_overlapping(bounds[0], |_| count += 1);
+			assert_eq!(count, 37);
+		}
+	}
+
+	#[test]
+	fn ray_on_face() {
+		// The ray runs along the x = 0 face of every box, which gives NaN slabs.
+		let bounds = vec![Bounds3::new(Point::new(0f32, 0f32, 0f32), Point::new(1f32, 1f32, 1f32)); 5];
+		let ray = Ray::new(Point::new(0f32, 0.5f32, -5f32), Direction::new(0f32, 0f32, 1f32));
+		for bvh in [Bvh::build(&bounds), Bvh::build_lbvh(&bounds)].iter() {
+			let hit = bvh.closest_hit(ray, |ray, idx| ray.intersect_bounds(bounds[idx]));
+			assert_eq!(hit.map(|(_, hit)| hit.t), Some(5f32));
+		}
+	}
+}
@@ -0,0 +1,102 @@
+//! Conversions to and from `cgmath` types, whose column-vector matrices are the transpose of spaceform's.
+
+use cgmath::{Matrix4, Point3, Quaternion as CgQuaternion, Vector3, Vector4};
+
+use crate::{
+	base::{Matrix, Quaternion, Vector},
+	direction::Direction,
+	point::Point,
+	transform::Transform,
+};
+
+impl From<Vector> for Vector4<f32> {
+	#[inline(always)]
+	fn from(val: Vector) -> Self { <[f32; 4]>::from(val).into() }
+}
+
+impl From<Vector4<f32>> for Vector {
+	#[inline(always)]
+	fn from(val: Vector4<f32>) -> Self { Self::new(val.x, val.y, val.z, val.w) }
+}
+
+impl From<Point> for Point3<f32> {
+	#[inline(always)]
+	fn from(val: Point) -> Self { Self::new(val.x(), val.y(), val.z()) }
+}
+
+impl From<Point3<f32>> for Point {
+	#[inline(always)]
+	fn from(val: Point3<f32>) -> Self { Self::new(val.x, val.y, val.z) }
+}
+
+impl From<Direction> for Vector3<f32> {
+	#[inline(always)]
+	fn from(val: Direction) -> Self { Self::new(val.x(), val.y(), val.z()) }
+}
+
+impl From<Vector3<f32>> for Direction {
+	#[inline(always)]
+	fn from(val: Vector3<f32>) -> Self { Self::new(val.x, val.y, val.z) }
+}
+
+impl From<Quaternion> for CgQuaternion<f32> {
+	#[inline(always)]
+	fn from(val: Quaternion) -> Self { Self::new(val.w(), val.x(), val.y(), val.z()) }
+}
+
+impl From<CgQuaternion<f32>> for Quaternion {
+	#[inline(always)]
+	fn from(val: CgQuaternion<f32>) -> Self { Self::new(val.v.x, val.v.y, val.v.z, val.s) }
+}
+
+impl From<Matrix> for Matrix4<f32> {
+	#[inline(always)]
+	fn from(val: Matrix) -> Self { <[[f32; 4]; 4]>::from(val).into() }
+}
+
+impl From<Matrix4<f32>> for Matrix {
+	#[inline(always)]
+	fn from(val: Matrix4<f32>) -> Self { Self::rows(val.into()) }
+}
+
+impl From<Transform> for Matrix4<f32> {
+	#[inline(always)]
+	fn from(val: Transform) -> Self { val.matrix.into() }
+}
+
+impl From<Matrix4<f32>> for Transform {
+	#[inline(always)]
+	fn from(val: Matrix4<f32>) -> Self { Matrix::from(val).into() }
+}
+
+#[cfg(test)]
+mod tests {
+	use cgmath::{InnerSpace, Rotation as _, Transform as _};
+
+	use super::*;
+	use crate::{fixtures, rotation::Rotation};
+
+	fn close(lhs: Point3<f32>, rhs: Point3<f32>) -> bool { (lhs - rhs).magnitude() < 0.0001f32 }
+
+	#[test]
+	fn convention() {
+		let mat = Matrix4::from(Transform::translate(Direction::new(1f32, 2f32, 3f32)));
+		assert_eq!(mat.w, Vector4::new(1f32, 2f32, 3f32, 1f32));
+
+		let (transform, point) = (fixtures::transform(), Point::new(-2f32, 0.5f32, 4f32));
+		let expected = Point3::from(point * transform);
+		assert!(close(Matrix4::from(transform).transform_point(point.into()), expected));
+
+		let rotation = Rotation::axis_angle(Direction::new(1f32, 2f32, -1f32), 0.6f32);
+		let expected = Point3::from(point * Transform::rotate(rotation));
+		assert!(close(
+			CgQuaternion::from(rotation.0).rotate_point(point.into()),
+			expected
+		));
+	}
+
+	#[test]
+	fn roundtrip() {
+		fixtures::assert_roundtrips!(Vector4<f32>, Point3<f32>, Vector3<f32>, CgQuaternion<f32>, Matrix4<f32>)
+	}
+}
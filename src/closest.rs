@@ -0,0 +1,409 @@
+//! Closest points and distances between primitives.
+//!
+//! Every query returns [`ClosestPoints`], where `point_a` is on the first primitive passed in, and `point_b` on the
+//! second. Primitives that overlap have a distance of zero, and share the same closest [`Point`].
+
+use std::fmt::{Debug, Display};
+
+use crate::{
+	base::Vector,
+	bounds::Bounds3,
+	direction::Direction,
+	point::Point,
+	ray::Ray,
+	shapes::{Obb, Plane},
+};
+
+#[derive(Copy, Clone, PartialEq)]
+/// The closest [`Point`]s between two primitives, and the square of the distance between them.
+pub struct ClosestPoints {
+	/// The closest [`Point`] on the first primitive.
+	pub point_a: Point,
+	/// The closest [`Point`] on the second primitive.
+	pub point_b: Point,
+	/// The square of the distance between `point_a` and `point_b`.
+	pub distance_square: f32,
+}
+
+impl Debug for ClosestPoints {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"ClosestPoints [{:?}, {:?}, {}]",
+			self.point_a, self.point_b, self.distance_square
+		)
+	}
+}
+
+impl Display for ClosestPoints {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"ClosestPoints [{}, {}, {}]",
+			self.point_a, self.point_b, self.distance_square
+		)
+	}
+}
+
+impl ClosestPoints {
+	#[inline(always)]
+	/// Create a new [`ClosestPoints`], calculating the distance between `point_a` and `point_b`.
+	pub fn new(point_a: Point, point_b: Point) -> Self {
+		Self {
+			point_a,
+			point_b,
+			distance_square: (point_b - point_a).length_square(),
+		}
+	}
+
+	#[inline(always)]
+	/// Get the distance between `point_a` and `point_b`.
+	pub fn distance(self) -> f32 { self.distance_square.sqrt() }
+
+	#[inline(always)]
+	/// Swap `point_a` and `point_b`.
+	pub const fn swap(self) -> Self {
+		Self {
+			point_a: self.point_b,
+			point_b: self.point_a,
+			distance_square: self.distance_square,
+		}
+	}
+}
+
+#[inline(always)]
+/// Get the closest [`Point`] on the segment from `start` to `end` to `point`.
+pub fn point_segment(point: Point, start: Point, end: Point) -> ClosestPoints {
+	let dir = end - start;
+	let length_square = dir.length_square();
+	let closest = if length_square == 0f32 {
+		start
+	} else {
+		let t = (Direction::dot(point - start, dir) / length_square).clamp(0f32, 1f32);
+		start + dir * t
+	};
+
+	ClosestPoints::new(point, closest)
+}
+
+/// Get the closest [`Point`] on the triangle `p0`, `p1`, `p2` to `point`.
+pub fn point_triangle(point: Point, p0: Point, p1: Point, p2: Point) -> ClosestPoints {
+	// Real-Time Collision Detection, 5.1.5.
+	let ab = p1 - p0;
+	let ac = p2 - p0;
+	let ap = point - p0;
+	let d1 = Direction::dot(ab, ap);
+	let d2 = Direction::dot(ac, ap);
+	if d1 <= 0f32 && d2 <= 0f32 {
+		return ClosestPoints::new(point, p0);
+	}
+
+	let bp = point - p1;
+	let d3 = Direction::dot(ab, bp);
+	let d4 = Direction::dot(ac, bp);
+	if d3 >= 0f32 && d4 <= d3 {
+		return ClosestPoints::new(point, p1);
+	}
+
+	let vc = d1 * d4 - d3 * d2;
+	if vc <= 0f32 && d1 >= 0f32 && d3 <= 0f32 {
+		return ClosestPoints::new(point, p0 + ab * (d1 / (d1 - d3)));
+	}
+
+	let cp = point - p2;
+	let d5 = Direction::dot(ab, cp);
+	let d6 = Direction::dot(ac, cp);
+	if d6 >= 0f32 && d5 <= d6 {
+		return ClosestPoints::new(point, p2);
+	}
+
+	let vb = d5 * d2 - d1 * d6;
+	if vb <= 0f32 && d2 >= 0f32 && d6 <= 0f32 {
+		return ClosestPoints::new(point, p0 + ac * (d2 / (d2 - d6)));
+	}
+
+	let va = d3 * d6 - d5 * d4;
+	if va <= 0f32 && d4 - d3 >= 0f32 && d5 - d6 >= 0f32 {
+		return ClosestPoints::new(point, p1 + (p2 - p1) * ((d4 - d3) / ((d4 - d3) + (d5 - d6))));
+	}
+
+	let sum = va + vb + vc;
+	if sum <= 0f32 {
+		// The triangle is degenerate, so the closest point is on one of its edges.
+		return nearest(&[
+			point_segment(point, p0, p1),
+			point_segment(point, p0, p2),
+			point_segment(point, p1, p2),
+		]);
+	}
+
+	ClosestPoints::new(point, p0 + ab * (vb / sum) + ac * (vc / sum))
+}
+
+#[allow(clippy::suspicious_operation_groupings)]
+/// Get the closest [`Point`]s between the segments `p1`-`q1` and `p2`-`q2`.
+pub fn segment_segment(p1: Point, q1: Point, p2: Point, q2: Point) -> ClosestPoints {
+	// Real-Time Collision Detection, 5.1.9.
+	let d1 = q1 - p1;
+	let d2 = q2 - p2;
+	let r = p1 - p2;
+	let a = d1.length_square();
+	let e = d2.length_square();
+	let f = Direction::dot(d2, r);
+
+	let (s, t) = if a <= f32::EPSILON && e <= f32::EPSILON {
+		(0f32, 0f32)
+	} else if a <= f32::EPSILON {
+		(0f32, (f / e).clamp(0f32, 1f32))
+	} else {
+		let c = Direction::dot(d1, r);
+		if e <= f32::EPSILON {
+			((-c / a).clamp(0f32, 1f32), 0f32)
+		} else {
+			let b = Direction::dot(d1, d2);
+			let denom = a * e - b * b;
+			let s = if denom != 0f32 {
+				((b * f - c * e) / denom).clamp(0f32, 1f32)
+			} else {
+				0f32
+			};
+
+			let t = (b * s + f) / e;
+			if t < 0f32 {
+				((-c / a).clamp(0f32, 1f32), 0f32)
+			} else if t > 1f32 {
+				(((b - c) / a).clamp(0f32, 1f32), 1f32)
+			} else {
+				(s, t)
+			}
+		}
+	};
+
+	ClosestPoints::new(p1 + d1 * s, p2 + d2 * t)
+}
+
+#[inline(always)]
+/// Get the closest [`Point`] in `bounds` to `point`.
+pub fn point_bounds(point: Point, bounds: Bounds3) -> ClosestPoints {
+	ClosestPoints::new(point, Point(Vector::clamp(point.0, bounds.min.0, bounds.max.0)))
+}
+
+#[inline(always)]
+/// Get the closest [`Point`] in `obb` to `point`.
+pub fn point_obb(point: Point, obb: Obb) -> ClosestPoints { ClosestPoints::new(point, obb.closest_point(point)) }
+
+#[inline(always)]
+/// Get the closest [`Point`] on `plane` to `point`. The [`Plane`] must be normalized.
+pub fn point_plane(point: Point, plane: Plane) -> ClosestPoints {
+	let distance = plane.signed_distance(point);
+	ClosestPoints {
+		point_a: point,
+		point_b: point - Direction(plane.normal().0 * distance),
+		distance_square: distance * distance,
+	}
+}
+
+/// Get the closest [`Point`]s between two triangles.
+pub fn triangle_triangle(lhs: [Point; 3], rhs: [Point; 3]) -> ClosestPoints {
+	// If an edge of one triangle pierces the other, they intersect.
+	let pierce = |edges: [Point; 3], triangle: [Point; 3]| {
+		(0..3).find_map(|idx| {
+			let start = edges[idx];
+			let mut ray = Ray::new(start, edges[(idx + 1) % 3] - start);
+			ray.t_max = 1f32;
+			ray.intersect_triangle(triangle[0], triangle[1], triangle[2])
+				.map(|hit| ray.at(hit.t))
+		})
+	};
+	if let Some(point) = pierce(lhs, rhs).or_else(|| pierce(rhs, lhs)) {
+		return ClosestPoints::new(point, point);
+	}
+
+	// Otherwise, the closest points involve a vertex of one triangle, or an edge of both.
+	let mut candidates = [ClosestPoints::new(lhs[0], rhs[0]); 15];
+	for idx in 0..3 {
+		candidates[idx] = point_triangle(lhs[idx], rhs[0], rhs[1], rhs[2]);
+		candidates[idx + 3] = point_triangle(rhs[idx], lhs[0], lhs[1], lhs[2]).swap();
+		for other in 0..3 {
+			candidates[6 + idx * 3 + other] =
+				segment_segment(lhs[idx], lhs[(idx + 1) % 3], rhs[other], rhs[(other + 1) % 3]);
+		}
+	}
+
+	nearest(&candidates)
+}
+
+#[inline(always)]
+fn nearest(candidates: &[ClosestPoints]) -> ClosestPoints {
+	*candidates
+		.iter()
+		.min_by(|x, y| x.distance_square.total_cmp(&y.distance_square))
+		.unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{base::nearly_equal, normal::Normal, rotation::Rotation};
+
+	fn close(lhs: Point, rhs: Point) -> bool { (lhs - rhs).length() < 0.0001f32 }
+
+	#[test]
+	fn point_segment_and_triangle() {
+		let (start, end) = (Point::new(0f32, 0f32, 0f32), Point::new(2f32, 0f32, 0f32));
+		let closest = point_segment(Point::new(1f32, 1f32, 0f32), start, end);
+		assert_eq!(closest.point_b, Point::new(1f32, 0f32, 0f32));
+		assert_eq!(closest.distance_square, 1f32);
+		assert_eq!(point_segment(Point::new(-1f32, 1f32, 0f32), start, end).point_b, start);
+		assert_eq!(point_segment(Point::new(5f32, 1f32, 0f32), start, start).point_b, start);
+
+		let triangle = [
+			Point::new(0f32, 0f32, 0f32),
+			Point::new(2f32, 0f32, 0f32),
+			Point::new(0f32, 2f32, 0f32),
+		];
+		let query = |point: Point| point_triangle(point, triangle[0], triangle[1], triangle[2]);
+		// Face, vertex, and edge regions.
+		assert!(close(
+			query(Point::new(0.5f32, 0.5f32, 3f32)).point_b,
+			Point::new(0.5f32, 0.5f32, 0f32)
+		));
+		assert_eq!(query(Point::new(-1f32, -1f32, 0f32)).point_b, triangle[0]);
+		assert_eq!(query(Point::new(3f32, -1f32, 0f32)).point_b, triangle[1]);
+		assert_eq!(query(Point::new(-1f32, 3f32, 1f32)).point_b, triangle[2]);
+		assert!(close(
+			query(Point::new(1f32, -1f32, 0f32)).point_b,
+			Point::new(1f32, 0f32, 0f32)
+		));
+		assert!(close(
+			query(Point::new(-1f32, 1f32, 0f32)).point_b,
+			Point::new(0f32, 1f32, 0f32)
+		));
+		assert!(close(
+			query(Point::new(2f32, 2f32, 0f32)).point_b,
+			Point::new(1f32, 1f32, 0f32)
+		));
+		assert!(nearly_equal(
+			query(Point::new(2f32, 2f32, 0f32)).distance_square,
+			2f32,
+			0.0001f32
+		));
+
+		// Degenerate triangle.
+		let closest = point_triangle(Point::new(1f32, 1f32, 0f32), triangle[0], triangle[1], triangle[1]);
+		assert!(close(closest.point_b, Point::new(1f32, 0f32, 0f32)));
+	}
+
+	#[test]
+	fn segment_segment_cases() {
+		// Crossing.
+		let closest = segment_segment(
+			Point::new(-1f32, 0f32, 0f32),
+			Point::new(1f32, 0f32, 0f32),
+			Point::new(0f32, -1f32, 1f32),
+			Point::new(0f32, 1f32, 1f32),
+		);
+		assert!(close(closest.point_a, Point::new(0f32, 0f32, 0f32)));
+		assert!(close(closest.point_b, Point::new(0f32, 0f32, 1f32)));
+		assert!(nearly_equal(closest.distance_square, 1f32, 0.0001f32));
+
+		// Parallel and overlapping.
+		let closest = segment_segment(
+			Point::new(0f32, 0f32, 0f32),
+			Point::new(2f32, 0f32, 0f32),
+			Point::new(1f32, 1f32, 0f32),
+			Point::new(3f32, 1f32, 0f32),
+		);
+		assert!(nearly_equal(closest.distance_square, 1f32, 0.0001f32));
+
+		// Endpoints.
+		let closest = segment_segment(
+			Point::new(0f32, 0f32, 0f32),
+			Point::new(1f32, 0f32, 0f32),
+			Point::new(2f32, 1f32, 0f32),
+			Point::new(3f32, 5f32, 0f32),
+		);
+		assert_eq!(closest.point_a, Point::new(1f32, 0f32, 0f32));
+		assert_eq!(closest.point_b, Point::new(2f32, 1f32, 0f32));
+
+		// Points.
+		let point = Point::new(1f32, 2f32, 3f32);
+		assert_eq!(segment_segment(point, point, point, point).distance_square, 0f32);
+	}
+
+	#[test]
+	fn point_volumes() {
+		let bounds = Bounds3::new(Point::new(-1f32, -1f32, -1f32), Point::new(1f32, 1f32, 1f32));
+		let closest = point_bounds(Point::new(3f32, 0.5f32, -2f32), bounds);
+		assert_eq!(closest.point_b, Point::new(1f32, 0.5f32, -1f32));
+		assert_eq!(closest.distance_square, 5f32);
+		assert_eq!(
+			point_bounds(Point::new(0.5f32, 0f32, 0f32), bounds).distance_square,
+			0f32
+		);
+
+		let obb = Obb::new(
+			Point::new(0f32, 0f32, 0f32),
+			Rotation::axis_angle(Direction::new(0f32, 0f32, 1f32), std::f32::consts::FRAC_PI_4),
+			Direction::new(1f32, 1f32, 1f32),
+		);
+		let closest = point_obb(Point::new(3f32, 0f32, 0f32), obb);
+		assert!(close(closest.point_b, Point::new(2f32.sqrt(), 0f32, 0f32)));
+
+		let plane = Plane::new(Normal::new(0f32, 1f32, 0f32), 2f32);
+		let closest = point_plane(Point::new(3f32, -1f32, 4f32), plane);
+		assert_eq!(closest.point_b, Point::new(3f32, 2f32, 4f32));
+		assert_eq!(closest.distance_square, 9f32);
+	}
+
+	#[test]
+	fn triangles() {
+		let lhs = [
+			Point::new(0f32, 0f32, 0f32),
+			Point::new(2f32, 0f32, 0f32),
+			Point::new(0f32, 2f32, 0f32),
+		];
+
+		// Parallel, above.
+		let rhs = [
+			Point::new(0f32, 0f32, 1f32),
+			Point::new(1f32, 0f32, 1f32),
+			Point::new(0f32, 1f32, 1f32),
+		];
+		assert!(nearly_equal(
+			triangle_triangle(lhs, rhs).distance_square,
+			1f32,
+			0.0001f32
+		));
+
+		// Edge to edge.
+		let rhs = [
+			Point::new(2f32, 2f32, -1f32),
+			Point::new(2f32, 2f32, 1f32),
+			Point::new(3f32, 3f32, 0f32),
+		];
+		let closest = triangle_triangle(lhs, rhs);
+		assert!(close(closest.point_a, Point::new(1f32, 1f32, 0f32)));
+		assert!(close(closest.point_b, Point::new(2f32, 2f32, 0f32)));
+
+		// Piercing.
+		let rhs = [
+			Point::new(0.5f32, 0.5f32, -1f32),
+			Point::new(0.5f32, 0.5f32, 1f32),
+			Point::new(5f32, 5f32, 0f32),
+		];
+		let closest = triangle_triangle(lhs, rhs);
+		assert_eq!(closest.distance_square, 0f32);
+		assert!(close(closest.point_a, closest.point_b));
+
+		// Coplanar and overlapping.
+		let rhs = [
+			Point::new(0.2f32, 0.2f32, 0f32),
+			Point::new(0.5f32, 0.2f32, 0f32),
+			Point::new(0.2f32, 0.5f32, 0f32),
+		];
+		assert!(triangle_triangle(lhs, rhs).distance_square < 0.0001f32);
+	}
+}
@@ -0,0 +1,223 @@
+//! The Expanding Polytope Algorithm.
+
+use super::{
+	gjk::{Simplex, Vertex},
+	Penetration,
+	Support,
+};
+use crate::{base::Vector, direction::Direction, normal::Normal, point::Point};
+
+/// The maximum number of iterations before giving up on convergence.
+const MAX_ITERATIONS: usize = 64;
+/// The improvement in depth below which EPA has converged, relative to the depth.
+const TOLERANCE: f32 = 1e-4;
+/// The distance below which new vertices are considered to not expand the polytope.
+const DEGENERATE_TOLERANCE: f32 = 1e-6;
+
+#[derive(Copy, Clone)]
+/// A triangle of the polytope, with vertices wound anti-clockwise when viewed from outside.
+struct Face {
+	vertices: [usize; 3],
+	normal: Direction,
+	distance: f32,
+}
+
+impl Face {
+	#[inline(always)]
+	fn new(points: &[Vertex], vertices: [usize; 3]) -> Self {
+		let [a, b, c] = [points[vertices[0]].w, points[vertices[1]].w, points[vertices[2]].w];
+		let normal = Direction::cross(b - a, c - a);
+		let length = normal.length();
+		if length <= f32::EPSILON {
+			// A sliver can never be the closest face.
+			return Self {
+				vertices,
+				normal: Direction::default(),
+				distance: f32::INFINITY,
+			};
+		}
+
+		let normal = normal / length;
+		Self {
+			vertices,
+			normal,
+			distance: Direction::dot(normal, a),
+		}
+	}
+}
+
+/// Run EPA on `a` and `b`, starting from the final [`Simplex`] of GJK.
+pub(super) fn epa(a: &impl Support, b: &impl Support, simplex: Simplex) -> Penetration {
+	let mut points: Vec<_> = simplex.vertices[..simplex.len].to_vec();
+	if !expand_to_tetrahedron(a, b, &mut points) {
+		// Both shapes are flat and coplanar, or touch at a single point, so there is no depth.
+		return flat(&points);
+	}
+
+	// Wind the faces so that they face away from the inside of the tetrahedron.
+	let mut faces = Vec::with_capacity(32);
+	let center = Direction(points.iter().fold(Vector::default(), |acc, v| acc + v.w.0) * 0.25f32);
+	for &[i, j, k] in [[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]].iter() {
+		let face = Face::new(&points, [i, j, k]);
+		if Direction::dot(face.normal, points[i].w - center) < 0f32 {
+			faces.push(Face::new(&points, [i, k, j]));
+		} else {
+			faces.push(face);
+		}
+	}
+
+	let mut closest = 0;
+	for _ in 0..MAX_ITERATIONS {
+		closest = closest_face(&faces);
+		let face = faces[closest];
+		let vertex = Vertex::new(a, b, face.normal);
+		let distance = Direction::dot(face.normal, vertex.w);
+		if distance - face.distance <= TOLERANCE * face.distance.max(1f32) {
+			break;
+		}
+
+		// Remove every face that can see the new vertex, keeping the edges on the boundary of the hole.
+		let mut edges: Vec<[usize; 2]> = Vec::new();
+		faces.retain(|face| {
+			let visible = Direction::dot(face.normal, vertex.w - points[face.vertices[0]].w) > 0f32;
+			if visible {
+				let [i, j, k] = face.vertices;
+				for &edge in [[i, j], [j, k], [k, i]].iter() {
+					match edges.iter().position(|&other| other == [edge[1], edge[0]]) {
+						Some(shared) => {
+							edges.swap_remove(shared);
+						},
+						None => edges.push(edge),
+					}
+				}
+			}
+			!visible
+		});
+
+		let new = points.len();
+		points.push(vertex);
+		faces.extend(edges.iter().map(|&[i, j]| Face::new(&points, [i, j, new])));
+		if faces.is_empty() {
+			// Numerical trouble removed every face, so the closest one so far is the best answer.
+			faces.push(face);
+			closest = 0;
+			break;
+		}
+		closest = closest_face(&faces);
+	}
+
+	let face = faces[closest];
+	let [a, b, c] = face.vertices;
+	let (u, v, w) = barycentric(face.normal * face.distance, points[a].w, points[b].w, points[c].w);
+	Penetration {
+		depth: face.distance.max(0f32),
+		normal: Normal(face.normal.0),
+		point_a: Point(points[a].a.0 * u + points[b].a.0 * v + points[c].a.0 * w),
+		point_b: Point(points[a].b.0 * u + points[b].b.0 * v + points[c].b.0 * w),
+	}
+}
+
+#[inline(always)]
+fn closest_face(faces: &[Face]) -> usize {
+	faces
+		.iter()
+		.enumerate()
+		.min_by(|(_, x), (_, y)| x.distance.total_cmp(&y.distance))
+		.map(|(idx, _)| idx)
+		.unwrap()
+}
+
+/// Add vertices to `points` until it is a tetrahedron with volume. Returns `false` if that is not possible.
+fn expand_to_tetrahedron(a: &impl Support, b: &impl Support, points: &mut Vec<Vertex>) -> bool {
+	let axes = [
+		Direction::new(1f32, 0f32, 0f32),
+		Direction::new(0f32, 1f32, 0f32),
+		Direction::new(0f32, 0f32, 1f32),
+	];
+
+	let try_add = |points: &mut Vec<Vertex>, dirs: &[Direction], far: &dyn Fn(&[Vertex], Direction) -> f32| {
+		for &dir in dirs.iter() {
+			for &dir in [dir, -dir].iter() {
+				let vertex = Vertex::new(a, b, dir);
+				if far(points, vertex.w) > DEGENERATE_TOLERANCE {
+					points.push(vertex);
+					return true;
+				}
+			}
+		}
+		false
+	};
+
+	if points.len() == 1 && !try_add(points, &axes, &|points, w| (w - points[0].w).length()) {
+		return false;
+	}
+
+	if points.len() == 2 {
+		let line = points[1].w - points[0].w;
+		let least_aligned = axes
+			.iter()
+			.min_by(|x, y| {
+				Direction::dot(**x, line)
+					.abs()
+					.total_cmp(&Direction::dot(**y, line).abs())
+			})
+			.unwrap();
+		let perp = Direction::cross(line, *least_aligned);
+		let dirs = [perp, Direction::cross(line, perp)];
+		let line_distance = |points: &[Vertex], w: Direction| {
+			let line = points[1].w - points[0].w;
+			Direction::cross(w - points[0].w, line).length() / line.length()
+		};
+		if !try_add(points, &dirs, &line_distance) {
+			return false;
+		}
+	}
+
+	if points.len() == 3 {
+		let normal = Direction::cross(points[1].w - points[0].w, points[2].w - points[0].w).normalize();
+		let plane_distance = |points: &[Vertex], w: Direction| Direction::dot(w - points[0].w, normal).abs();
+		if !try_add(points, &[normal], &plane_distance) {
+			return false;
+		}
+	}
+
+	true
+}
+
+/// Create a zero-depth [`Penetration`] for a polytope without volume.
+fn flat(points: &[Vertex]) -> Penetration {
+	let normal = if points.len() == 3 {
+		Direction::cross(points[1].w - points[0].w, points[2].w - points[0].w).normalize()
+	} else {
+		Direction::new(1f32, 0f32, 0f32)
+	};
+	Penetration {
+		depth: 0f32,
+		normal: Normal(normal.0),
+		point_a: points[0].a,
+		point_b: points[0].b,
+	}
+}
+
+#[inline(always)]
+#[allow(clippy::suspicious_operation_groupings)]
+/// Get the barycentric coordinates of `p` projected onto the triangle `a`, `b`, `c`.
+fn barycentric(p: Direction, a: Direction, b: Direction, c: Direction) -> (f32, f32, f32) {
+	// Real-Time Collision Detection, 3.4.
+	let v0 = b - a;
+	let v1 = c - a;
+	let v2 = p - a;
+	let d00 = Direction::dot(v0, v0);
+	let d01 = Direction::dot(v0, v1);
+	let d11 = Direction::dot(v1, v1);
+	let d20 = Direction::dot(v2, v0);
+	let d21 = Direction::dot(v2, v1);
+	let denom = d00 * d11 - d01 * d01;
+	if denom <= 0f32 {
+		return (1f32, 0f32, 0f32);
+	}
+
+	let v = (d11 * d20 - d01 * d21) / denom;
+	let w = (d00 * d21 - d01 * d20) / denom;
+	(1f32 - v - w, v, w)
+}
@@ -0,0 +1,269 @@
+//! The Gilbert-Johnson-Keerthi distance algorithm.
+
+use super::{Separation, Support};
+use crate::{base::Vector, direction::Direction, point::Point};
+
+/// The maximum number of iterations before giving up on convergence.
+const MAX_ITERATIONS: usize = 64;
+/// The relative improvement in distance below which GJK has converged.
+const RELATIVE_TOLERANCE: f32 = 1e-6;
+/// The squared distance below which shapes are considered to be touching.
+const TOUCHING_TOLERANCE: f32 = 1e-10;
+
+#[derive(Copy, Clone)]
+/// A vertex of the Minkowski difference `a - b`, along with the [`Point`]s of `a` and `b` it came from.
+pub(super) struct Vertex {
+	pub a: Point,
+	pub b: Point,
+	pub w: Direction,
+}
+
+impl Vertex {
+	#[inline(always)]
+	pub fn new(a: &impl Support, b: &impl Support, dir: Direction) -> Self {
+		let a = a.support(dir);
+		let b = b.support(-dir);
+		Self { a, b, w: a - b }
+	}
+}
+
+#[derive(Copy, Clone)]
+/// A simplex of up to four [`Vertex`]s.
+pub(super) struct Simplex {
+	pub vertices: [Vertex; 4],
+	pub len: usize,
+}
+
+impl Simplex {
+	#[inline(always)]
+	const fn push(&mut self, vertex: Vertex) {
+		self.vertices[self.len] = vertex;
+		self.len += 1;
+	}
+
+	#[inline(always)]
+	/// Keep only the vertices at `keep`, along with their barycentric weights.
+	fn reduce(&mut self, keep: &[(usize, f32)]) -> Closest {
+		let old = self.vertices;
+		let mut weights = [0f32; 4];
+		for (idx, &(vertex, weight)) in keep.iter().enumerate() {
+			self.vertices[idx] = old[vertex];
+			weights[idx] = weight;
+		}
+		self.len = keep.len();
+
+		let mut point = Vector::default();
+		for (vertex, &weight) in self.vertices[..self.len].iter().zip(weights.iter()) {
+			point += vertex.w.0 * weight;
+		}
+		Closest {
+			point: Direction(point),
+			weights,
+		}
+	}
+}
+
+/// The result of running GJK.
+pub(super) enum Gjk {
+	/// The shapes are separated.
+	Separated(Separation),
+	/// The shapes overlap, and the final [`Simplex`] contains the origin or touches it.
+	Intersecting(Simplex),
+}
+
+/// The closest point of a [`Simplex`] to the origin.
+struct Closest {
+	point: Direction,
+	weights: [f32; 4],
+}
+
+/// Run GJK on `a` and `b`.
+pub(super) fn gjk(a: &impl Support, b: &impl Support) -> Gjk {
+	let first = Vertex::new(a, b, Direction::new(1f32, 0f32, 0f32));
+	let mut simplex = Simplex {
+		vertices: [first; 4],
+		len: 1,
+	};
+
+	let mut closest = simplex.reduce(&[(0, 1f32)]);
+	for _ in 0..MAX_ITERATIONS {
+		let dist = closest.point.length_square();
+		if dist <= TOUCHING_TOLERANCE {
+			return Gjk::Intersecting(simplex);
+		}
+
+		let vertex = Vertex::new(a, b, -closest.point);
+		let converged = dist - Direction::dot(closest.point, vertex.w) <= RELATIVE_TOLERANCE * dist
+			|| simplex.vertices[..simplex.len].iter().any(|v| v.w == vertex.w);
+		if converged {
+			break;
+		}
+
+		simplex.push(vertex);
+		closest = match closest_to_origin(&mut simplex) {
+			Some(closest) => closest,
+			None => return Gjk::Intersecting(simplex),
+		};
+	}
+
+	let mut point_a = Vector::default();
+	let mut point_b = Vector::default();
+	for idx in 0..simplex.len {
+		point_a += simplex.vertices[idx].a.0 * closest.weights[idx];
+		point_b += simplex.vertices[idx].b.0 * closest.weights[idx];
+	}
+	Gjk::Separated(Separation {
+		distance: closest.point.length(),
+		point_a: Point(point_a),
+		point_b: Point(point_b),
+	})
+}
+
+/// Reduce `simplex` to the smallest sub-simplex containing its closest point to the origin, and return that point.
+/// Returns `None` if the origin is inside the tetrahedron.
+fn closest_to_origin(simplex: &mut Simplex) -> Option<Closest> {
+	let w = |idx: usize| simplex.vertices[idx].w;
+	let keep = match simplex.len {
+		2 => closest_on_segment(w(0), w(1), [0, 1]),
+		3 => closest_on_triangle(w(0), w(1), w(2), [0, 1, 2]),
+		_ => closest_on_tetrahedron(w(0), w(1), w(2), w(3))?,
+	};
+	Some(simplex.reduce(keep.as_slice()))
+}
+
+/// The vertices and weights of a sub-simplex.
+struct Keep {
+	vertices: [(usize, f32); 3],
+	len: usize,
+}
+
+impl Keep {
+	#[inline(always)]
+	fn new(vertices: &[(usize, f32)]) -> Self {
+		let mut keep = [(0, 0f32); 3];
+		keep[..vertices.len()].copy_from_slice(vertices);
+		Self {
+			vertices: keep,
+			len: vertices.len(),
+		}
+	}
+
+	#[inline(always)]
+	fn as_slice(&self) -> &[(usize, f32)] { &self.vertices[..self.len] }
+
+	#[inline(always)]
+	fn distance_square(&self, points: &[Direction; 4]) -> f32 {
+		let mut point = Vector::default();
+		for &(idx, weight) in self.as_slice() {
+			point += points[idx].0 * weight;
+		}
+		point.length_square()
+	}
+}
+
+fn closest_on_segment(a: Direction, b: Direction, idx: [usize; 2]) -> Keep {
+	let ab = b - a;
+	let length = ab.length_square();
+	if length <= 0f32 {
+		return Keep::new(&[(idx[0], 1f32)]);
+	}
+
+	let t = -Direction::dot(a, ab) / length;
+	if t <= 0f32 {
+		Keep::new(&[(idx[0], 1f32)])
+	} else if t >= 1f32 {
+		Keep::new(&[(idx[1], 1f32)])
+	} else {
+		Keep::new(&[(idx[0], 1f32 - t), (idx[1], t)])
+	}
+}
+
+fn closest_on_triangle(a: Direction, b: Direction, c: Direction, idx: [usize; 3]) -> Keep {
+	// Real-Time Collision Detection, 5.1.5.
+	let ab = b - a;
+	let ac = c - a;
+	let d1 = -Direction::dot(ab, a);
+	let d2 = -Direction::dot(ac, a);
+	if d1 <= 0f32 && d2 <= 0f32 {
+		return Keep::new(&[(idx[0], 1f32)]);
+	}
+
+	let d3 = -Direction::dot(ab, b);
+	let d4 = -Direction::dot(ac, b);
+	if d3 >= 0f32 && d4 <= d3 {
+		return Keep::new(&[(idx[1], 1f32)]);
+	}
+
+	let vc = d1 * d4 - d3 * d2;
+	if vc <= 0f32 && d1 >= 0f32 && d3 <= 0f32 {
+		let v = d1 / (d1 - d3);
+		return Keep::new(&[(idx[0], 1f32 - v), (idx[1], v)]);
+	}
+
+	let d5 = -Direction::dot(ab, c);
+	let d6 = -Direction::dot(ac, c);
+	if d6 >= 0f32 && d5 <= d6 {
+		return Keep::new(&[(idx[2], 1f32)]);
+	}
+
+	let vb = d5 * d2 - d1 * d6;
+	if vb <= 0f32 && d2 >= 0f32 && d6 <= 0f32 {
+		let w = d2 / (d2 - d6);
+		return Keep::new(&[(idx[0], 1f32 - w), (idx[2], w)]);
+	}
+
+	let va = d3 * d6 - d5 * d4;
+	if va <= 0f32 && d4 - d3 >= 0f32 && d5 - d6 >= 0f32 {
+		let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+		return Keep::new(&[(idx[1], 1f32 - w), (idx[2], w)]);
+	}
+
+	let sum = va + vb + vc;
+	if sum <= 0f32 {
+		// The triangle is degenerate, so the closest point is on one of its edges.
+		let points = [a, b, c, Direction::default()];
+		let local = [
+			closest_on_segment(a, b, [0, 1]),
+			closest_on_segment(a, c, [0, 2]),
+			closest_on_segment(b, c, [1, 2]),
+		];
+		let best = local
+			.iter()
+			.min_by(|x, y| x.distance_square(&points).total_cmp(&y.distance_square(&points)))
+			.unwrap();
+		let mut keep = Keep::new(best.as_slice());
+		for vertex in keep.vertices[..keep.len].iter_mut() {
+			vertex.0 = idx[vertex.0];
+		}
+		return keep;
+	}
+
+	let v = vb / sum;
+	let w = vc / sum;
+	Keep::new(&[(idx[0], 1f32 - v - w), (idx[1], v), (idx[2], w)])
+}
+
+fn closest_on_tetrahedron(a: Direction, b: Direction, c: Direction, d: Direction) -> Option<Keep> {
+	// Real-Time Collision Detection, 5.1.6.
+	let points = [a, b, c, d];
+	let faces = [[0, 1, 2, 3], [0, 2, 3, 1], [0, 3, 1, 2], [1, 3, 2, 0]];
+
+	let mut best: Option<(f32, Keep)> = None;
+	for face in faces.iter() {
+		let [p0, p1, p2, opposite] = [points[face[0]], points[face[1]], points[face[2]], points[face[3]]];
+		let normal = Direction::cross(p1 - p0, p2 - p0);
+		let origin_side = -Direction::dot(p0, normal);
+		let opposite_side = Direction::dot(opposite - p0, normal);
+
+		// A flat tetrahedron has no inside, so check every face.
+		if origin_side * opposite_side < 0f32 || opposite_side == 0f32 {
+			let keep = closest_on_triangle(p0, p1, p2, [face[0], face[1], face[2]]);
+			let dist = keep.distance_square(&points);
+			if best.as_ref().is_none_or(|(best, _)| dist < *best) {
+				best = Some((dist, keep));
+			}
+		}
+	}
+
+	best.map(|(_, keep)| keep)
+}
@@ -0,0 +1,369 @@
+//! Collision detection between convex shapes, using GJK and EPA.
+//!
+//! Any shape that implements [`Support`] can be tested against any other. [`intersects`] and [`distance`] use GJK,
+//! while [`penetration`] continues with EPA when the shapes overlap.
+
+mod epa;
+mod gjk;
+
+use std::fmt::{Debug, Display};
+
+use self::gjk::Gjk;
+use crate::{
+	base::Vector,
+	bounds::Bounds3,
+	direction::Direction,
+	normal::Normal,
+	point::Point,
+	shapes::{Capsule, Obb, Sphere},
+	transform::Transform,
+};
+
+/// A convex shape that can be used in collision detection.
+pub trait Support {
+	/// Get the farthest [`Point`] of the shape along `dir`. `dir` may NOT be normalized, or even be zero.
+	fn support(&self, dir: Direction) -> Point;
+}
+
+impl<T: Support + ?Sized> Support for &T {
+	#[inline(always)]
+	fn support(&self, dir: Direction) -> Point { (**self).support(dir) }
+}
+
+impl Support for Point {
+	#[inline(always)]
+	fn support(&self, _: Direction) -> Point { *self }
+}
+
+impl Support for Sphere {
+	#[inline(always)]
+	fn support(&self, dir: Direction) -> Point {
+		let length = dir.length();
+		if length > 0f32 {
+			self.center() + dir * (self.radius() / length)
+		} else {
+			self.center() + Direction::new(self.radius(), 0f32, 0f32)
+		}
+	}
+}
+
+impl Support for Bounds3 {
+	#[inline(always)]
+	fn support(&self, dir: Direction) -> Point {
+		Point::new(
+			if dir.x() >= 0f32 { self.max.x() } else { self.min.x() },
+			if dir.y() >= 0f32 { self.max.y() } else { self.min.y() },
+			if dir.z() >= 0f32 { self.max.z() } else { self.min.z() },
+		)
+	}
+}
+
+impl Support for Obb {
+	#[inline(always)]
+	fn support(&self, dir: Direction) -> Point {
+		let local = Direction(self.rotation.0.conjugate().rotate(dir.0));
+		let half = self.half_extents;
+		self.from_local(Direction::new(
+			if local.x() >= 0f32 { half.x() } else { -half.x() },
+			if local.y() >= 0f32 { half.y() } else { -half.y() },
+			if local.z() >= 0f32 { half.z() } else { -half.z() },
+		))
+	}
+}
+
+impl Support for Capsule {
+	#[inline(always)]
+	fn support(&self, dir: Direction) -> Point {
+		let end = if Direction::dot(self.end - self.start, dir) >= 0f32 {
+			self.end
+		} else {
+			self.start
+		};
+		Sphere::new(end, self.radius).support(dir)
+	}
+}
+
+#[derive(Clone, PartialEq)]
+/// The convex hull of a set of [`Point`]s.
+pub struct ConvexHull {
+	points: Vec<Point>,
+}
+
+impl Debug for ConvexHull {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { write!(f, "ConvexHull {:?}", self.points) }
+}
+
+impl Support for ConvexHull {
+	#[inline(always)]
+	fn support(&self, dir: Direction) -> Point {
+		let mut best = (f32::NEG_INFINITY, self.points[0]);
+		for &point in self.points.iter() {
+			let dist = Vector::dot(point.0, dir.0);
+			if dist > best.0 {
+				best = (dist, point);
+			}
+		}
+		best.1
+	}
+}
+
+impl ConvexHull {
+	#[inline(always)]
+	/// Create the [`ConvexHull`] of `points`. The [`Point`]s inside the hull do not have to be removed.
+	///
+	/// # Panics
+	/// If `points` is empty.
+	pub fn new(points: Vec<Point>) -> Self {
+		assert!(!points.is_empty(), "ConvexHull must have at least one point");
+		Self { points }
+	}
+
+	#[inline(always)]
+	/// Get the [`Point`]s the [`ConvexHull`] was created from.
+	pub fn points(&self) -> &[Point] { &self.points }
+}
+
+#[derive(Copy, Clone)]
+/// A shape moved into the world by a [`Transform`], without having to transform the shape itself.
+pub struct Transformed<T> {
+	/// The shape, in its local space.
+	pub shape: T,
+	/// The [`Transform`] from the local space of the shape to the world.
+	pub transform: Transform,
+}
+
+impl<T: Debug> Debug for Transformed<T> {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Transformed [{:?}, {:?}]", self.shape, self.transform)
+	}
+}
+
+impl<T: Support> Support for Transformed<T> {
+	#[inline(always)]
+	fn support(&self, dir: Direction) -> Point {
+		// Directions are dotted with transformed points, so they are taken to local space by the transpose.
+		let local = Direction(dir.0 * self.transform.matrix.transpose() * Vector::new(1f32, 1f32, 1f32, 0f32));
+		self.shape.support(local) * self.transform
+	}
+}
+
+impl<T> Transformed<T> {
+	#[inline(always)]
+	/// Create a new [`Transformed`] shape.
+	pub const fn new(shape: T, transform: Transform) -> Self { Self { shape, transform } }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+/// The result of a [`distance`] query between two separated shapes.
+pub struct Separation {
+	/// The distance between the shapes.
+	pub distance: f32,
+	/// The closest [`Point`] on the first shape to the second.
+	pub point_a: Point,
+	/// The closest [`Point`] on the second shape to the first.
+	pub point_b: Point,
+}
+
+impl Display for Separation {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Separation [{}, {}, {}]", self.distance, self.point_a, self.point_b)
+	}
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+/// The result of a [`penetration`] query between two overlapping shapes.
+pub struct Penetration {
+	/// How far the shapes overlap along `normal`.
+	pub depth: f32,
+	/// The direction to move the second shape by `depth` to separate it from the first.
+	pub normal: Normal,
+	/// The deepest [`Point`] of the first shape inside the second.
+	pub point_a: Point,
+	/// The deepest [`Point`] of the second shape inside the first.
+	pub point_b: Point,
+}
+
+impl Display for Penetration {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"Penetration [{}, {}, {}, {}]",
+			self.depth, self.normal, self.point_a, self.point_b
+		)
+	}
+}
+
+/// Check if two convex shapes overlap, including touching boundaries.
+pub fn intersects(a: &impl Support, b: &impl Support) -> bool { matches!(gjk::gjk(a, b), Gjk::Intersecting(_)) }
+
+/// Get the distance and closest [`Point`]s between two convex shapes, or `None` if they overlap.
+pub fn distance(a: &impl Support, b: &impl Support) -> Option<Separation> {
+	match gjk::gjk(a, b) {
+		Gjk::Separated(separation) => Some(separation),
+		Gjk::Intersecting(_) => None,
+	}
+}
+
+/// Get the depth and direction of the overlap between two convex shapes, or `None` if they are separated.
+pub fn penetration(a: &impl Support, b: &impl Support) -> Option<Penetration> {
+	match gjk::gjk(a, b) {
+		Gjk::Separated(_) => None,
+		Gjk::Intersecting(simplex) => Some(epa::epa(a, b, simplex)),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{base::nearly_equal, rotation::Rotation};
+
+	fn close(lhs: Point, rhs: Point) -> bool { (lhs - rhs).length() < 0.001f32 }
+
+	#[test]
+	fn support() {
+		let dir = Direction::new(1f32, -1f32, 0f32);
+		let sphere = Sphere::new(Point::new(1f32, 0f32, 0f32), 2f32);
+		assert!(close(
+			sphere.support(dir),
+			Point::new(1f32 + 2f32.sqrt(), -(2f32.sqrt()), 0f32)
+		));
+
+		let bounds = Bounds3::new(Point::new(-1f32, -2f32, -3f32), Point::new(1f32, 2f32, 3f32));
+		assert_eq!(bounds.support(dir), Point::new(1f32, -2f32, 3f32));
+
+		let capsule = Capsule::new(Point::new(0f32, 0f32, 0f32), Point::new(0f32, 5f32, 0f32), 1f32);
+		assert!(close(
+			capsule.support(Direction::new(0f32, 1f32, 0f32)),
+			Point::new(0f32, 6f32, 0f32)
+		));
+
+		let hull = ConvexHull::new(vec![
+			Point::new(0f32, 0f32, 0f32),
+			Point::new(1f32, 0f32, 0f32),
+			Point::new(0f32, 1f32, 0f32),
+		]);
+		assert_eq!(hull.support(dir), Point::new(1f32, 0f32, 0f32));
+
+		let obb = Obb::new(
+			Point::new(0f32, 0f32, 0f32),
+			Rotation::axis_angle(Direction::new(0f32, 0f32, 1f32), std::f32::consts::FRAC_PI_4),
+			Direction::new(1f32, 1f32, 1f32),
+		);
+		assert!(close(
+			obb.support(Direction::new(1f32, 0f32, 0f32)),
+			Point::new(2f32.sqrt(), 0f32, 1f32)
+		));
+
+		let transform = Transform::rotate(Rotation::axis_angle(
+			Direction::new(0f32, 0f32, 1f32),
+			std::f32::consts::FRAC_PI_2,
+		)) * Transform::translate(Direction::new(10f32, 0f32, 0f32));
+		let transformed = Transformed::new(bounds, transform);
+		let dir = Direction::new(1f32, 2f32, -3f32);
+		let brute = (0..8)
+			.map(|idx| bounds.corner(idx) * transform)
+			.max_by(|x, y| Vector::dot(x.0, dir.0).total_cmp(&Vector::dot(y.0, dir.0)))
+			.unwrap();
+		assert!(close(transformed.support(dir), brute));
+	}
+
+	#[test]
+	fn sphere_sphere() {
+		let a = Sphere::new(Point::new(0f32, 0f32, 0f32), 1f32);
+		let b = Sphere::new(Point::new(3f32, 4f32, 0f32), 2f32);
+		let separation = distance(&a, &b).unwrap();
+		assert!(nearly_equal(separation.distance, 2f32, 0.001f32));
+		assert!(close(separation.point_a, Point::new(0.6f32, 0.8f32, 0f32)));
+		assert!(close(separation.point_b, Point::new(1.8f32, 2.4f32, 0f32)));
+		assert!(!intersects(&a, &b));
+		assert_eq!(penetration(&a, &b), None);
+
+		let b = Sphere::new(Point::new(1.2f32, 1.6f32, 0f32), 1.5f32);
+		assert!(intersects(&a, &b));
+		assert_eq!(distance(&a, &b), None);
+		let contact = penetration(&a, &b).unwrap();
+		assert!(nearly_equal(contact.depth, 0.5f32, 0.01f32));
+		assert!((contact.normal.0 - Vector::new(0.6f32, 0.8f32, 0f32, 0f32)).length() < 0.01f32);
+	}
+
+	#[test]
+	fn box_box() {
+		let a = Bounds3::new(Point::new(0f32, 0f32, 0f32), Point::new(2f32, 2f32, 2f32));
+		let b = Bounds3::new(Point::new(3f32, 1f32, 1f32), Point::new(4f32, 4f32, 4f32));
+		let separation = distance(&a, &b).unwrap();
+		assert!(nearly_equal(separation.distance, 1f32, 0.0001f32));
+		assert!(nearly_equal(separation.point_a.x(), 2f32, 0.0001f32));
+		assert!(nearly_equal(separation.point_b.x(), 3f32, 0.0001f32));
+
+		let b = Bounds3::new(Point::new(1.7f32, 0.5f32, -1f32), Point::new(4f32, 1.5f32, 1f32));
+		let contact = penetration(&a, &b).unwrap();
+		assert!(nearly_equal(contact.depth, 0.3f32, 0.0001f32));
+		assert_eq!(contact.normal, Normal::new(1f32, 0f32, 0f32));
+
+		// A box rotated 45 degrees around z, with its corner poking into the other one along y.
+		let b = Obb::new(
+			Point::new(1f32, 2.2f32 + 2f32.sqrt(), 1f32),
+			Rotation::axis_angle(Direction::new(0f32, 0f32, 1f32), std::f32::consts::FRAC_PI_4),
+			Direction::new(1f32, 1f32, 1f32),
+		);
+		let separation = distance(&a, &b).unwrap();
+		assert!(nearly_equal(separation.distance, 0.2f32, 0.0001f32));
+		let b = Obb::new(
+			b.center - Direction::new(0f32, 0.6f32, 0f32),
+			b.rotation,
+			b.half_extents,
+		);
+		let contact = penetration(&a, &b).unwrap();
+		assert!(nearly_equal(contact.depth, 0.4f32, 0.0001f32));
+		assert!((contact.normal.0 - Vector::new(0f32, 1f32, 0f32, 0f32)).length() < 0.001f32);
+	}
+
+	#[test]
+	fn degenerate() {
+		// Touching faces.
+		let a = Bounds3::new(Point::new(0f32, 0f32, 0f32), Point::new(1f32, 1f32, 1f32));
+		let b = Bounds3::new(Point::new(1f32, 0f32, 0f32), Point::new(2f32, 1f32, 1f32));
+		assert!(intersects(&a, &b));
+		assert!(penetration(&a, &b).unwrap().depth < 0.0001f32);
+
+		// Identical shapes.
+		let contact = penetration(&a, &a).unwrap();
+		assert!(nearly_equal(contact.depth, 1f32, 0.0001f32));
+
+		// Points and flat shapes.
+		let point = Point::new(0.25f32, 0.25f32, 0f32);
+		let triangle = ConvexHull::new(vec![
+			Point::new(0f32, 0f32, 0f32),
+			Point::new(1f32, 0f32, 0f32),
+			Point::new(0f32, 1f32, 0f32),
+		]);
+		assert!(intersects(&triangle, &point));
+		assert!(penetration(&triangle, &point).unwrap().depth < 0.0001f32);
+		let separation = distance(&triangle, &Point::new(1f32, 1f32, 0f32)).unwrap();
+		assert!(nearly_equal(separation.distance, 0.5f32.sqrt(), 0.0001f32));
+		assert!(close(separation.point_a, Point::new(0.5f32, 0.5f32, 0f32)));
+
+		let segment = ConvexHull::new(vec![Point::new(-1f32, 0f32, 0.5f32), Point::new(1f32, 0f32, 0.5f32)]);
+		let sphere = Sphere::new(Point::new(0f32, 0f32, 0f32), 1f32);
+		let contact = penetration(&sphere, &segment).unwrap();
+		assert!(nearly_equal(contact.depth, 0.5f32, 0.01f32));
+		assert!((contact.normal.0 - Vector::new(0f32, 0f32, 1f32, 0f32)).length() < 0.01f32);
+	}
+
+	#[test]
+	fn transformed() {
+		let a = Transformed::new(
+			Sphere::new(Point::new(0f32, 0f32, 0f32), 1f32),
+			Transform::translate(Direction::new(0f32, 0f32, 5f32)),
+		);
+		let b = Capsule::new(Point::new(-5f32, 0f32, 0f32), Point::new(5f32, 0f32, 0f32), 1f32);
+		let separation = distance(&a, &b).unwrap();
+		assert!(nearly_equal(separation.distance, 3f32, 0.001f32));
+		assert!(close(separation.point_a, Point::new(0f32, 0f32, 4f32)));
+		assert!(close(separation.point_b, Point::new(0f32, 0f32, 1f32)));
+	}
+}
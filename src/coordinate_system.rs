@@ -2,7 +2,9 @@
 
 use crate::Direction;
 
+#[repr(C)]
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A mapping from axis directions to human understanding.
 pub struct CoordinateSystem {
 	/// The direction that points to the right.
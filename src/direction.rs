@@ -5,7 +5,21 @@ use std::{
 	ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
-use crate::{base::Vector, is_shuffle_arg, normal::Normal, shuffle_mask, transform::Transform, Check, True};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+#[cfg(feature = "rayon")]
+use crate::PAR_CHUNK;
+use crate::{
+	base::{DualQuaternion, Vector},
+	is_shuffle_arg,
+	normal::Normal,
+	shuffle_mask,
+	transform::Transform,
+	wide::Vector3x4,
+	Check,
+	True,
+};
 
 #[repr(transparent)]
 #[derive(Copy, Clone, PartialEq)]
@@ -67,6 +81,18 @@ impl Mul<f32> for Direction {
 	fn mul(self, rhs: f32) -> Self::Output { Self(self.0 * rhs) }
 }
 
+impl Mul<DualQuaternion> for Direction {
+	type Output = Self;
+
+	#[inline(always)]
+	fn mul(self, rhs: DualQuaternion) -> Self::Output { Self(rhs.real.rotate(self.0)) }
+}
+
+impl MulAssign<DualQuaternion> for Direction {
+	#[inline(always)]
+	fn mul_assign(&mut self, rhs: DualQuaternion) { *self = *self * rhs }
+}
+
 impl Mul<Transform> for Direction {
 	type Output = Self;
 
@@ -144,6 +170,23 @@ impl Direction {
 	/// Get the normalized [`Direction`].
 	pub fn normalize(self) -> Self { Self(self.0.normalize()) }
 
+	/// Normalize every [`Direction`] in `dirs` in place.
+	///
+	/// Works on four [`Direction`]s at a time, as a [`Vector3x4`].
+	pub fn normalize_all(dirs: &mut [Self]) {
+		for chunk in dirs.chunks_mut(4) {
+			let mut lanes = [chunk[0]; 4];
+			lanes[..chunk.len()].copy_from_slice(chunk);
+			let normalized: [Self; 4] = Vector3x4::from(lanes).normalize().into();
+			chunk.copy_from_slice(&normalized[..chunk.len()]);
+		}
+	}
+
+	#[cfg(feature = "rayon")]
+	/// Normalize every [`Direction`] in `dirs` in place, in parallel. Gives the same results as
+	/// [`Direction::normalize_all`].
+	pub fn par_normalize_all(dirs: &mut [Self]) { dirs.par_chunks_mut(PAR_CHUNK).for_each(Self::normalize_all) }
+
 	#[inline(always)]
 	/// Shuffle the components of a [`Direction`].
 	pub fn shuffle<const X: u32, const Y: u32, const Z: u32>(self) -> Self
@@ -168,4 +211,149 @@ impl Direction {
 	#[inline(always)]
 	/// Linear interpolate from `from` to `to` with a factor `t`.
 	pub fn lerp(from: Direction, to: Direction, t: f32) -> Direction { Direction(Vector::lerp(from.0, to.0, t)) }
+
+	#[inline(always)]
+	/// Flip the [`Direction`] if it lies in the opposite hemisphere of `normal`.
+	pub fn face_forward(self, normal: Normal) -> Self {
+		if Vector::dot(self.0, normal.0) < 0f32 {
+			-self
+		} else {
+			self
+		}
+	}
+
+	#[inline(always)]
+	/// Reflect the [`Direction`] about a normalized `normal`.
+	///
+	/// Both the [`Direction`] and the result point away from the surface, so the result is on the same side of it.
+	pub fn reflect(self, normal: Normal) -> Self { -self + Self(normal.0 * (2f32 * Vector::dot(self.0, normal.0))) }
+
+	#[inline(always)]
+	/// Refract the normalized [`Direction`] through a surface with a normalized `normal`, following Snell's law.
+	///
+	/// `eta` is the ratio of the index of refraction on the side `normal` points away from, to the one on the side it
+	/// points to. Both the [`Direction`] and the result point away from the surface, so the result is on the other
+	/// side of it, and the [`Direction`] may be on either side. Returns `None` on total internal reflection.
+	pub fn refract(self, normal: Normal, eta: f32) -> Option<Self> {
+		let cos_i = Vector::dot(self.0, normal.0);
+		let (normal, eta, cos_i) = if cos_i < 0f32 {
+			(-normal, 1f32 / eta, -cos_i)
+		} else {
+			(normal, eta, cos_i)
+		};
+
+		let sin2_i = (1f32 - cos_i * cos_i).max(0f32);
+		let sin2_t = sin2_i / (eta * eta);
+		if sin2_t >= 1f32 {
+			return None;
+		}
+
+		let cos_t = (1f32 - sin2_t).sqrt();
+		Some(-self / eta + Self(normal.0 * (cos_i / eta - cos_t)))
+	}
+
+	#[inline(always)]
+	/// Get the normalized half-vector between two [`Direction`]s, which must be normalized.
+	/// Returns `None` if they point in opposite directions.
+	pub fn half_vector(lhs: Self, rhs: Self) -> Option<Self> {
+		let half = lhs + rhs;
+		let length = half.length();
+		if length > 0f32 {
+			Some(half / length)
+		} else {
+			None
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::base::nearly_equal;
+
+	fn close(lhs: Direction, rhs: Direction) -> bool { (lhs - rhs).length() < 0.0001f32 }
+
+	#[test]
+	fn reflect() {
+		let normal = Normal::new(0f32, 1f32, 0f32);
+		// Normal incidence.
+		assert_eq!(
+			Direction::new(0f32, 1f32, 0f32).reflect(normal),
+			Direction::new(0f32, 1f32, 0f32)
+		);
+		// Oblique and grazing incidence.
+		assert_eq!(
+			Direction::new(0.6f32, 0.8f32, 0f32).reflect(normal),
+			Direction::new(-0.6f32, 0.8f32, 0f32)
+		);
+		assert_eq!(
+			Direction::new(1f32, 0f32, 0f32).reflect(normal),
+			Direction::new(-1f32, 0f32, 0f32)
+		);
+	}
+
+	#[test]
+	fn refract() {
+		let normal = Normal::new(0f32, 1f32, 0f32);
+		// Normal incidence passes straight through, from either side.
+		let up = Direction::new(0f32, 1f32, 0f32);
+		assert!(close(up.refract(normal, 1.5f32).unwrap(), -up));
+		assert!(close((-up).refract(normal, 1.5f32).unwrap(), up));
+
+		// Snell's law.
+		let dir = Direction::new(0.6f32, 0.8f32, 0f32);
+		let refracted = dir.refract(normal, 1.5f32).unwrap();
+		assert!(nearly_equal(refracted.length(), 1f32, 0.0001f32));
+		assert!(refracted.y() < 0f32);
+		assert!(nearly_equal(-refracted.x() * 1.5f32, dir.x(), 0.0001f32));
+		assert!(close(refracted.refract(normal, 1.5f32).unwrap(), dir));
+
+		// Grazing incidence enters at the critical angle.
+		let grazing = Direction::new(1f32, 0f32, 0f32).refract(normal, 1.5f32).unwrap();
+		assert!(nearly_equal(-grazing.x(), 1f32 / 1.5f32, 0.0001f32));
+
+		// Total internal reflection when leaving the denser side beyond the critical angle.
+		let inside = Direction::new(0.8f32, -0.6f32, 0f32);
+		assert_eq!(inside.refract(normal, 1.5f32), None);
+		assert!(Direction::new(0.6f32, -0.8f32, 0f32).refract(normal, 1.5f32).is_some());
+	}
+
+	#[test]
+	fn face_forward_and_half_vector() {
+		let normal = Normal::new(0f32, 0f32, 1f32);
+		let dir = Direction::new(1f32, 0f32, -1f32);
+		assert_eq!(dir.face_forward(normal), -dir);
+		assert_eq!((-dir).face_forward(normal), -dir);
+
+		let lhs = Direction::new(1f32, 0f32, 0f32);
+		let rhs = Direction::new(0f32, 1f32, 0f32);
+		assert!(close(
+			Direction::half_vector(lhs, rhs).unwrap(),
+			Direction::new(1f32, 1f32, 0f32).normalize()
+		));
+		assert_eq!(Direction::half_vector(lhs, lhs), Some(lhs));
+		assert_eq!(Direction::half_vector(lhs, -lhs), None);
+	}
+
+	#[test]
+	fn normalize_all() {
+		let mut dirs: Vec<_> = (1..8)
+			.map(|i| Direction::new(i as f32, 2f32, -(i as f32) * 0.5f32))
+			.collect();
+		let expected: Vec<_> = dirs.iter().map(|dir| dir.normalize()).collect();
+		Direction::normalize_all(&mut dirs);
+		assert!(dirs.iter().zip(expected.iter()).all(|(&lhs, &rhs)| close(lhs, rhs)));
+	}
+
+	#[cfg(feature = "rayon")]
+	#[test]
+	fn par_normalize_all() {
+		let dirs: Vec<_> = (1..5004)
+			.map(|i| Direction::new(i as f32, (i % 7) as f32, -(i as f32) * 0.5f32))
+			.collect();
+		let (mut serial, mut parallel) = (dirs.clone(), dirs);
+		Direction::normalize_all(&mut serial);
+		Direction::par_normalize_all(&mut parallel);
+		assert_eq!(serial, parallel);
+	}
 }
@@ -0,0 +1,34 @@
+//! Values shared by the tests of several modules.
+
+use crate::{direction::Direction, rotation::Rotation, transform::Transform};
+
+/// A [`Transform`] with a translation, a non-uniform scale, and a rotation, so that no two elements of its matrix are
+/// equal.
+pub fn transform() -> Transform {
+	Transform::translate(Direction::new(1f32, -2f32, 3f32))
+		* Transform::scale(Direction::new(2f32, 0.5f32, 1.5f32))
+		* Transform::rotate(Rotation::axis_angle(Direction::new(1f32, 1f32, 0f32), 0.7f32))
+}
+
+#[cfg(any(feature = "cgmath", feature = "glam", feature = "mint", feature = "nalgebra"))]
+/// Check that converting to another library's four-component vector, point, direction, quaternion, and matrix types,
+/// and back, gives the same value.
+macro_rules! assert_roundtrips {
+	($vector:ty, $point:ty, $direction:ty, $quaternion:ty, $matrix:ty) => {{
+		let transform = crate::fixtures::transform();
+		assert_eq!(Transform::from(<$matrix>::from(transform)).matrix, transform.matrix);
+		assert_eq!(Matrix::from(<$matrix>::from(transform.inverse)), transform.inverse);
+
+		let vec = Vector::new(1f32, -2f32, 3f32, 4f32);
+		assert_eq!(Vector::from(<$vector>::from(vec)), vec);
+		let point = Point::new(1f32, -2f32, 3f32);
+		assert_eq!(Point::from(<$point>::from(point)), point);
+		let dir = Direction::new(1f32, -2f32, 3f32);
+		assert_eq!(Direction::from(<$direction>::from(dir)), dir);
+		let quat = Quaternion::new(0.5f32, -0.5f32, 0.5f32, 0.25f32);
+		assert_eq!(Quaternion::from(<$quaternion>::from(quat)), quat);
+	}};
+}
+
+#[cfg(any(feature = "cgmath", feature = "glam", feature = "mint", feature = "nalgebra"))]
+pub(crate) use assert_roundtrips;
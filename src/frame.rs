@@ -0,0 +1,207 @@
+//! Orthonormal bases, for shading.
+
+use std::fmt::{Debug, Display};
+
+use crate::{
+	base::{Matrix, Vector},
+	direction::Direction,
+	normal::Normal,
+	rotation::Rotation,
+	transform::Transform,
+};
+
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+/// A right-handed orthonormal basis, usually built around a surface [`Normal`] as the `z` axis.
+pub struct Frame {
+	/// The x axis of the [`Frame`].
+	pub x: Direction,
+	/// The y axis of the [`Frame`].
+	pub y: Direction,
+	/// The z axis of the [`Frame`].
+	pub z: Direction,
+}
+
+impl Debug for Frame {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Frame [{:?}, {:?}, {:?}]", self.x, self.y, self.z)
+	}
+}
+
+impl Default for Frame {
+	#[inline(always)]
+	fn default() -> Self {
+		Self::new(
+			Direction::new(1f32, 0f32, 0f32),
+			Direction::new(0f32, 1f32, 0f32),
+			Direction::new(0f32, 0f32, 1f32),
+		)
+	}
+}
+
+impl Display for Frame {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Frame [{}, {}, {}]", self.x, self.y, self.z)
+	}
+}
+
+impl From<Normal> for Frame {
+	#[inline(always)]
+	fn from(val: Normal) -> Self { Self::from_normal(val) }
+}
+
+impl From<Direction> for Frame {
+	#[inline(always)]
+	fn from(val: Direction) -> Self { Self::from_direction(val) }
+}
+
+impl From<Frame> for Transform {
+	#[inline(always)]
+	fn from(val: Frame) -> Self {
+		let matrix = val.matrix();
+		Self {
+			matrix,
+			inverse: matrix.transpose(),
+		}
+	}
+}
+
+impl From<Frame> for Rotation {
+	#[inline(always)]
+	fn from(val: Frame) -> Self { Self::from_matrix(val.matrix()) }
+}
+
+impl Frame {
+	#[inline(always)]
+	/// Create a [`Frame`] from three axes, which must be normalized, perpendicular, and right-handed.
+	pub const fn new(x: Direction, y: Direction, z: Direction) -> Self { Self { x, y, z } }
+
+	#[inline(always)]
+	/// Build a [`Frame`] with a normalized `normal` as its z axis.
+	pub fn from_normal(normal: Normal) -> Self { Self::from_direction(Direction(normal.0)) }
+
+	#[inline(always)]
+	/// Build a [`Frame`] with a normalized `dir` as its z axis.
+	///
+	/// Uses the branchless method from 'Building an Orthonormal Basis, Revisited' (Duff et al. 2017), which is
+	/// continuous everywhere except across the z = 0 plane.
+	pub fn from_direction(dir: Direction) -> Self {
+		let (x, y, z) = (dir.x(), dir.y(), dir.z());
+		let sign = 1f32.copysign(z);
+		let a = -1f32 / (sign + z);
+		let b = x * y * a;
+
+		Self {
+			x: Direction::new(1f32 + sign * x * x * a, sign * b, -sign * x),
+			y: Direction::new(b, sign + y * y * a, -y),
+			z: dir,
+		}
+	}
+
+	#[inline(always)]
+	/// Express a world-space [`Direction`] in terms of the axes of the [`Frame`].
+	pub fn to_local(self, dir: Direction) -> Direction {
+		Direction::new(
+			Direction::dot(dir, self.x),
+			Direction::dot(dir, self.y),
+			Direction::dot(dir, self.z),
+		)
+	}
+
+	#[inline(always)]
+	/// Get the world-space [`Direction`] from one expressed in terms of the axes of the [`Frame`].
+	pub fn from_local(self, dir: Direction) -> Direction { self.x * dir.x() + self.y * dir.y() + self.z * dir.z() }
+
+	#[inline(always)]
+	/// Get the [`Matrix`] that takes local-space row vectors to world space.
+	fn matrix(self) -> Matrix {
+		Matrix::row_vectors([self.x.0, self.y.0, self.z.0, Vector::new(0f32, 0f32, 0f32, 1f32)])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::base::nearly_equal;
+
+	fn directions() -> Vec<Direction> {
+		let mut dirs = vec![
+			Direction::new(0f32, 0f32, 1f32),
+			Direction::new(0f32, 0f32, -1f32),
+			Direction::new(1f32, 0f32, 0f32),
+			Direction::new(0f32, -1f32, 0f32),
+			Direction::new(0.0001f32, 0f32, -1f32).normalize(),
+			Direction::new(0f32, 0.0001f32, 1f32).normalize(),
+		];
+		for idx in 0..50 {
+			let theta = idx as f32 * 0.37f32;
+			let phi = idx as f32 * 1.13f32;
+			dirs.push(Direction::new(
+				theta.sin() * phi.cos(),
+				theta.sin() * phi.sin(),
+				theta.cos(),
+			));
+		}
+		dirs
+	}
+
+	#[test]
+	fn orthonormal() {
+		for dir in directions() {
+			let frame = Frame::from_direction(dir);
+			assert_eq!(frame.z, dir);
+			assert!(nearly_equal(frame.x.length(), 1f32, 0.0001f32), "{}", dir);
+			assert!(nearly_equal(frame.y.length(), 1f32, 0.0001f32), "{}", dir);
+			assert!(
+				nearly_equal(Direction::dot(frame.x, frame.y), 0f32, 0.0001f32),
+				"{}",
+				dir
+			);
+			assert!(
+				nearly_equal(Direction::dot(frame.x, frame.z), 0f32, 0.0001f32),
+				"{}",
+				dir
+			);
+			assert!(
+				nearly_equal(Direction::dot(frame.y, frame.z), 0f32, 0.0001f32),
+				"{}",
+				dir
+			);
+			assert!(
+				(Direction::cross(frame.x, frame.y) - frame.z).length() < 0.0001f32,
+				"{}",
+				dir
+			);
+		}
+	}
+
+	#[test]
+	fn local() {
+		let local = Direction::new(0.3f32, -0.5f32, 0.8f32);
+		for dir in directions() {
+			let frame = Frame::from(Normal(dir.0));
+			let world = frame.from_local(local);
+			assert!((frame.to_local(world) - local).length() < 0.0001f32);
+			assert!(nearly_equal(Direction::dot(world, dir), 0.8f32, 0.0001f32));
+			assert!((frame.to_local(dir) - Direction::new(0f32, 0f32, 1f32)).length() < 0.0001f32);
+		}
+	}
+
+	#[test]
+	fn conversions() {
+		let local = Direction::new(0.3f32, -0.5f32, 0.8f32);
+		for dir in directions() {
+			let frame = Frame::from_direction(dir);
+			let world = frame.from_local(local);
+
+			let transform = Transform::from(frame);
+			assert!((local * transform - world).length() < 0.0001f32);
+			assert!((world * transform.inverse() - local).length() < 0.0001f32);
+
+			let rotation = Rotation::from(frame);
+			assert!((Direction(rotation.0.rotate(local.0)) - world).length() < 0.0001f32);
+		}
+	}
+}
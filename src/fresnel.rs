@@ -0,0 +1,176 @@
+//! Fresnel reflectance of smooth surfaces, for unpolarized light.
+//!
+//! `cos_i` is the cosine of the angle between the incident [`Direction`] and the surface [`Normal`], and `eta` is the
+//! relative index of refraction, like in [`Direction::refract`].
+//!
+//! [`Direction`]: crate::Direction
+//! [`Direction::refract`]: crate::Direction::refract
+//! [`Normal`]: crate::Normal
+
+use std::ops::{Add, Div, Mul, Sub};
+
+/// Get the fraction of light reflected by a dielectric, like glass or water.
+///
+/// A negative `cos_i` means the light arrives from the other side of the surface. Returns 1 on total internal
+/// reflection.
+pub fn dielectric(cos_i: f32, eta: f32) -> f32 {
+	let cos_i = cos_i.clamp(-1f32, 1f32);
+	let (cos_i, eta) = if cos_i < 0f32 {
+		(-cos_i, 1f32 / eta)
+	} else {
+		(cos_i, eta)
+	};
+
+	let sin2_i = cos_i.mul_add(-cos_i, 1f32);
+	let sin2_t = sin2_i / (eta * eta);
+	if sin2_t >= 1f32 {
+		return 1f32;
+	}
+
+	let cos_t = (1f32 - sin2_t).sqrt();
+	let parallel = eta.mul_add(cos_i, -cos_t) / eta.mul_add(cos_i, cos_t);
+	let perpendicular = eta.mul_add(-cos_t, cos_i) / eta.mul_add(cos_t, cos_i);
+	parallel.mul_add(parallel, perpendicular * perpendicular) / 2f32
+}
+
+/// Get the fraction of light reflected by a conductor, like a metal, with the complex index of refraction
+/// `eta + i * k`. `k` is the absorption coefficient.
+///
+/// The light must arrive from outside the conductor, so `cos_i` is clamped to [0, 1].
+pub fn conductor(cos_i: f32, eta: f32, k: f32) -> f32 {
+	let cos_i = cos_i.clamp(0f32, 1f32);
+	let eta = Complex { re: eta, im: k };
+	let cos = Complex { re: cos_i, im: 0f32 };
+
+	let sin2_i = Complex {
+		re: cos_i.mul_add(-cos_i, 1f32),
+		im: 0f32,
+	};
+	let sin2_t = sin2_i / (eta * eta);
+	let cos_t = (Complex { re: 1f32, im: 0f32 } - sin2_t).sqrt();
+
+	let parallel = (eta * cos - cos_t) / (eta * cos + cos_t);
+	let perpendicular = (cos - eta * cos_t) / (cos + eta * cos_t);
+	(parallel.norm() + perpendicular.norm()) / 2f32
+}
+
+#[derive(Copy, Clone)]
+/// Just enough of complex numbers for [`conductor`].
+struct Complex {
+	re: f32,
+	im: f32,
+}
+
+impl Add for Complex {
+	type Output = Self;
+
+	#[inline(always)]
+	fn add(self, rhs: Self) -> Self::Output {
+		Self {
+			re: self.re + rhs.re,
+			im: self.im + rhs.im,
+		}
+	}
+}
+
+impl Sub for Complex {
+	type Output = Self;
+
+	#[inline(always)]
+	fn sub(self, rhs: Self) -> Self::Output {
+		Self {
+			re: self.re - rhs.re,
+			im: self.im - rhs.im,
+		}
+	}
+}
+
+impl Mul for Complex {
+	type Output = Self;
+
+	#[inline(always)]
+	fn mul(self, rhs: Self) -> Self::Output {
+		Self {
+			re: self.re.mul_add(rhs.re, -self.im * rhs.im),
+			im: self.re.mul_add(rhs.im, self.im * rhs.re),
+		}
+	}
+}
+
+impl Div for Complex {
+	type Output = Self;
+
+	#[inline(always)]
+	fn div(self, rhs: Self) -> Self::Output {
+		let scale = 1f32 / rhs.norm();
+		Self {
+			re: scale * self.re.mul_add(rhs.re, self.im * rhs.im),
+			im: scale * self.im.mul_add(rhs.re, -self.re * rhs.im),
+		}
+	}
+}
+
+impl Complex {
+	#[inline(always)]
+	/// Get the square of the magnitude.
+	fn norm(self) -> f32 { self.re.mul_add(self.re, self.im * self.im) }
+
+	#[inline(always)]
+	/// Get the principal square root.
+	fn sqrt(self) -> Self {
+		let length = self.norm().sqrt();
+		let re = ((length + self.re) / 2f32).max(0f32).sqrt();
+		let im = ((length - self.re) / 2f32).max(0f32).sqrt();
+		Self {
+			re,
+			im: im.copysign(self.im),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::base::nearly_equal;
+
+	#[test]
+	fn dielectric_reflectance() {
+		// Normal incidence, from either side.
+		assert!(nearly_equal(dielectric(1f32, 1.5f32), 0.04f32, 0.0001f32));
+		assert!(nearly_equal(dielectric(-1f32, 1.5f32), 0.04f32, 0.0001f32));
+		// Matched indices do not reflect.
+		assert!(nearly_equal(dielectric(0.5f32, 1f32), 0f32, 0.0001f32));
+		// Grazing incidence reflects everything.
+		assert!(nearly_equal(dielectric(0f32, 1.5f32), 1f32, 0.0001f32));
+		// Total internal reflection.
+		assert_eq!(dielectric(-0.6f32, 1.5f32), 1f32);
+		assert!(dielectric(-0.8f32, 1.5f32) < 1f32);
+		// Brewster's angle only reflects perpendicular polarization.
+		let (sin_i, cos_i) = 1.5f32.atan().sin_cos();
+		let cos_t = (1f32 - sin_i * sin_i / (1.5f32 * 1.5f32)).sqrt();
+		let perpendicular = (cos_i - 1.5f32 * cos_t) / (cos_i + 1.5f32 * cos_t);
+		assert!(nearly_equal(
+			dielectric(cos_i, 1.5f32),
+			perpendicular * perpendicular / 2f32,
+			0.0001f32
+		));
+	}
+
+	#[test]
+	fn conductor_reflectance() {
+		// Normal incidence.
+		let (eta, k) = (0.2f32, 3.6f32);
+		let expected = ((eta - 1f32) * (eta - 1f32) + k * k) / ((eta + 1f32) * (eta + 1f32) + k * k);
+		assert!(nearly_equal(conductor(1f32, eta, k), expected, 0.0001f32));
+		// Grazing incidence reflects everything.
+		assert!(nearly_equal(conductor(0f32, eta, k), 1f32, 0.0001f32));
+		// No absorption is the same as a dielectric.
+		for &cos_i in [1f32, 0.7f32, 0.3f32, 0.05f32].iter() {
+			assert!(nearly_equal(
+				conductor(cos_i, 1.5f32, 0f32),
+				dielectric(cos_i, 1.5f32),
+				0.0001f32
+			));
+		}
+	}
+}
@@ -0,0 +1,95 @@
+//! Conversions to and from `glam` types, whose column-vector matrices are the transpose of spaceform's.
+
+use glam::{Mat4, Quat, Vec3, Vec4};
+
+use crate::{
+	base::{Matrix, Quaternion, Vector},
+	direction::Direction,
+	point::Point,
+	transform::Transform,
+};
+
+impl From<Vector> for Vec4 {
+	#[inline(always)]
+	fn from(val: Vector) -> Self { Self::from_array(val.into()) }
+}
+
+impl From<Vec4> for Vector {
+	#[inline(always)]
+	fn from(val: Vec4) -> Self { Self::from(val.to_array()) }
+}
+
+impl From<Point> for Vec3 {
+	#[inline(always)]
+	fn from(val: Point) -> Self { Self::new(val.x(), val.y(), val.z()) }
+}
+
+impl From<Vec3> for Point {
+	#[inline(always)]
+	fn from(val: Vec3) -> Self { Self::new(val.x, val.y, val.z) }
+}
+
+impl From<Direction> for Vec3 {
+	#[inline(always)]
+	fn from(val: Direction) -> Self { Self::new(val.x(), val.y(), val.z()) }
+}
+
+impl From<Vec3> for Direction {
+	#[inline(always)]
+	fn from(val: Vec3) -> Self { Self::new(val.x, val.y, val.z) }
+}
+
+impl From<Quaternion> for Quat {
+	#[inline(always)]
+	fn from(val: Quaternion) -> Self { Self::from_xyzw(val.x(), val.y(), val.z(), val.w()) }
+}
+
+impl From<Quat> for Quaternion {
+	#[inline(always)]
+	fn from(val: Quat) -> Self { Self::new(val.x, val.y, val.z, val.w) }
+}
+
+impl From<Matrix> for Mat4 {
+	#[inline(always)]
+	fn from(val: Matrix) -> Self { Self::from_cols_array_2d(&val.into()) }
+}
+
+impl From<Mat4> for Matrix {
+	#[inline(always)]
+	fn from(val: Mat4) -> Self { Self::rows(val.to_cols_array_2d()) }
+}
+
+impl From<Transform> for Mat4 {
+	#[inline(always)]
+	fn from(val: Transform) -> Self { val.matrix.into() }
+}
+
+impl From<Mat4> for Transform {
+	#[inline(always)]
+	fn from(val: Mat4) -> Self { Matrix::from(val).into() }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{fixtures, rotation::Rotation};
+
+	#[test]
+	fn convention() {
+		let mat = Mat4::from(Transform::translate(Direction::new(1f32, 2f32, 3f32)));
+		assert_eq!(mat.w_axis, Vec4::new(1f32, 2f32, 3f32, 1f32));
+
+		let (transform, point) = (fixtures::transform(), Point::new(-2f32, 0.5f32, 4f32));
+		let expected = Vec3::from(point * transform);
+		assert!(Mat4::from(transform)
+			.transform_point3(point.into())
+			.abs_diff_eq(expected, 0.0001f32));
+
+		let rotation = Rotation::axis_angle(Direction::new(1f32, 2f32, -1f32), 0.6f32);
+		let expected = Vec3::from(point * Transform::rotate(rotation));
+		assert!((Quat::from(rotation.0) * Vec3::from(point)).abs_diff_eq(expected, 0.0001f32));
+	}
+
+	#[test]
+	fn roundtrip() { fixtures::assert_roundtrips!(Vec4, Vec3, Vec3, Quat, Mat4) }
+}
@@ -0,0 +1,203 @@
+//! Memory layout guarantees, for sending data to the GPU.
+//!
+//! Every backend of [`Vector`] is four `f32`s in x, y, z, w order, with a size of 16 bytes and an alignment of 16
+//! bytes. The types built on it are sequences of [`Vector`]s with no padding between them:
+//! - [`Point`], [`Direction`], [`Normal`], [`Quaternion`], [`Rotation`], [`Sphere`], and [`Plane`] are a single
+//!   [`Vector`]. [`Point`]s have a w of 1, and [`Direction`]s and [`Normal`]s have a w of 0.
+//! - [`Matrix`] is four rows. Since spaceform uses row vectors, a column-major `mat4` in GLSL or `float4x4` in HLSL
+//!   gives the same results when uploaded as-is.
+//! - [`Transform`] is the [`Matrix`] followed by its inverse.
+//! - [`Bounds3`] is the minimum [`Point`] followed by the maximum.
+//! - [`DualQuaternion`] is the real part followed by the dual part.
+//! - [`IVector`] is four `u32`s, laid out like a [`Vector`].
+//! - [`Frame`], [`CoordinateSystem`], [`Obb`], [`Frustum`], [`Vector3x4`], and [`Point3x4`] are their fields in order.
+//!
+//! - [`Vector8`] is eight `f32`s with an alignment of 32 bytes, and [`Vector3x8`] and [`Point3x8`] are three of them.
+//!
+//! This matches `vec4` and `mat4` in both the std140 and std430 layouts. These layouts are checked at compile time.
+//!
+//! With the `bytemuck` feature, these types implement `Pod` and `Zeroable`, so slices of them can be cast to bytes.
+//! Types that end in an `f32`, like [`Ray`](crate::Ray), [`Capsule`](crate::shapes::Capsule), and
+//! [`ClosestPoints`](crate::closest::ClosestPoints), have padding, so they only implement `Zeroable`.
+//!
+//! [`EulerAngles`] is pitch, yaw, and roll followed by its [`RotationOrder`] as a `u32`, with no padding. Not every
+//! `u32` is a valid [`RotationOrder`], so neither is `Pod`. They implement `Zeroable` and `NoUninit` instead, so they
+//! can be cast to bytes, and [`RotationOrder`] implements `CheckedBitPattern` to be cast back with validation.
+
+use std::mem::{align_of, size_of};
+
+use crate::{
+	base::{DualQuaternion, IVector, Matrix, Quaternion, Vector, Vector8},
+	bounds::Bounds3,
+	coordinate_system::CoordinateSystem,
+	direction::Direction,
+	frame::Frame,
+	normal::Normal,
+	point::Point,
+	rotation::{EulerAngles, Rotation, RotationOrder},
+	shapes::{Frustum, Obb, Plane, Sphere},
+	transform::Transform,
+	wide::{Point3x4, Point3x8, Vector3x4, Vector3x8},
+};
+
+/// Check at compile time that each type is made of the given number of [`Vector`]s, and is aligned to 16 bytes.
+macro_rules! assert_layout {
+	($($ty:ty => $vectors:expr),* $(,)?) => {
+		$(
+			const _: () = assert!(size_of::<$ty>() == $vectors * 16 && align_of::<$ty>() == 16);
+		)*
+	};
+}
+
+assert_layout! {
+	Vector => 1,
+	IVector => 1,
+	Point => 1,
+	Direction => 1,
+	Normal => 1,
+	Quaternion => 1,
+	Rotation => 1,
+	Sphere => 1,
+	Plane => 1,
+	Bounds3 => 2,
+	DualQuaternion => 2,
+	Frame => 3,
+	CoordinateSystem => 3,
+	Obb => 3,
+	Vector3x4 => 3,
+	Point3x4 => 3,
+	Matrix => 4,
+	Transform => 8,
+	Frustum => 14,
+}
+
+const _: () = assert!(size_of::<EulerAngles>() == 16 && align_of::<EulerAngles>() == 4);
+const _: () = assert!(size_of::<RotationOrder>() == 4);
+
+// The eight-wide types are aligned for AVX, whether or not it is enabled.
+const _: () = assert!(size_of::<Vector8>() == 32 && align_of::<Vector8>() == 32);
+const _: () = assert!(size_of::<Vector3x8>() == 96 && align_of::<Vector3x8>() == 32);
+const _: () = assert!(size_of::<Point3x8>() == 96 && align_of::<Point3x8>() == 32);
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impls {
+	use bytemuck::{CheckedBitPattern, NoUninit, Pod, Zeroable};
+
+	use super::*;
+	use crate::{closest::ClosestPoints, ray::Ray, shapes::Capsule};
+
+	macro_rules! impl_pod {
+		($($ty:ty),* $(,)?) => {
+			$(
+				// SAFETY: `assert_layout` checks that the type is made of `Vector`s with no padding, and any bit
+				// pattern is a valid `f32` or `u32`.
+				unsafe impl Zeroable for $ty {}
+				unsafe impl Pod for $ty {}
+			)*
+		};
+	}
+
+	macro_rules! impl_zeroable {
+		($($ty:ty),* $(,)?) => {
+			$(
+				// SAFETY: The type is made of `Vector`s and `f32`s, which are valid when zeroed.
+				unsafe impl Zeroable for $ty {}
+			)*
+		};
+	}
+
+	impl_pod! {
+		Vector,
+		IVector,
+		Point,
+		Direction,
+		Normal,
+		Quaternion,
+		Rotation,
+		Sphere,
+		Plane,
+		Bounds3,
+		DualQuaternion,
+		Frame,
+		CoordinateSystem,
+		Obb,
+		Vector3x4,
+		Point3x4,
+		Vector8,
+		Vector3x8,
+		Point3x8,
+		Matrix,
+		Transform,
+		Frustum,
+	}
+
+	impl_zeroable! {
+		Ray,
+		Capsule,
+		ClosestPoints,
+		// A discriminant of zero is `RotationOrder::PYR`.
+		RotationOrder,
+		EulerAngles,
+	}
+
+	// SAFETY: `RotationOrder` is `repr(u32)`, and `EulerAngles` is `repr(C)` with three `f32`s and a `RotationOrder`,
+	// so neither has padding.
+	unsafe impl NoUninit for RotationOrder {}
+	unsafe impl NoUninit for EulerAngles {}
+
+	// SAFETY: `RotationOrder` is `repr(u32)` with the discriminants 0 to 5.
+	unsafe impl CheckedBitPattern for RotationOrder {
+		type Bits = u32;
+
+		#[inline(always)]
+		fn is_valid_bit_pattern(bits: &u32) -> bool { *bits <= Self::YRP as u32 }
+	}
+}
+
+#[cfg(all(test, feature = "bytemuck"))]
+mod tests {
+	use bytemuck::{bytes_of, cast_slice, checked, Zeroable};
+
+	use super::*;
+	use crate::ray::Ray;
+
+	#[test]
+	fn cast() {
+		let points = [Point::new(1f32, 2f32, 3f32), Point::new(4f32, 5f32, 6f32)];
+		let floats: &[f32] = cast_slice(&points);
+		assert_eq!(floats, &[1f32, 2f32, 3f32, 1f32, 4f32, 5f32, 6f32, 1f32]);
+		let back: &[Point] = cast_slice(floats);
+		assert_eq!(back, &points);
+
+		let matrix = Matrix::rows([
+			[1f32, 2f32, 3f32, 4f32],
+			[5f32, 6f32, 7f32, 8f32],
+			[9f32, 10f32, 11f32, 12f32],
+			[13f32, 14f32, 15f32, 16f32],
+		]);
+		let floats: &[f32] = cast_slice(bytes_of(&matrix));
+		assert_eq!(floats, &(1..=16).map(|x| x as f32).collect::<Vec<_>>()[..]);
+	}
+
+	#[test]
+	fn euler_angles() {
+		let angles = EulerAngles {
+			pitch: 1f32,
+			yaw: 2f32,
+			roll: 3f32,
+			order: RotationOrder::RYP,
+		};
+		let words: &[u32] = cast_slice(bytes_of(&angles));
+		assert_eq!(words, &[1f32.to_bits(), 2f32.to_bits(), 3f32.to_bits(), 3]);
+		assert_eq!(EulerAngles::zeroed().order, RotationOrder::PYR);
+
+		assert_eq!(checked::try_cast::<u32, RotationOrder>(5), Ok(RotationOrder::YRP));
+		assert!(checked::try_cast::<u32, RotationOrder>(6).is_err());
+	}
+
+	#[test]
+	fn zeroed() {
+		assert_eq!(Vector::zeroed(), Vector::default());
+		assert_eq!(Ray::zeroed().t_max, 0f32);
+	}
+}
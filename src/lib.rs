@@ -9,19 +9,57 @@
 
 //! spaceform is a SIMD-accelerated library for 3D graphics.
 
+pub mod approx;
 pub mod base;
+pub mod bounds;
+pub mod bvh;
+pub mod closest;
+pub mod collision;
 pub mod coordinate_system;
 pub mod direction;
+pub mod frame;
+pub mod fresnel;
+pub mod layout;
+pub mod morton;
 pub mod normal;
 pub mod point;
+pub mod random;
+pub mod ray;
 pub mod rotation;
+pub mod sampling;
+pub mod sequence;
+pub mod shapes;
 pub mod transform;
+pub mod wide;
 
+#[cfg(feature = "cgmath")]
+mod cgmath_impls;
+#[cfg(test)]
+mod fixtures;
+#[cfg(feature = "glam")]
+mod glam_impls;
+#[cfg(feature = "mint")]
+mod mint_impls;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impls;
+#[cfg(feature = "serde")]
+mod serde_impls;
+
+pub use bounds::Bounds3;
+pub use bvh::Bvh;
 pub use direction::Direction;
+pub use frame::Frame;
 pub use normal::Normal;
 pub use point::Point;
-pub use rotation::{EulerAngles, Rotation, RotationOrder};
+pub use ray::{Ray, RayHit};
+pub use rotation::{EulerAngles, Rotation, RotationAccumulator, RotationOrder};
 pub use transform::Transform;
+pub use wide::{Point3x4, Point3x8, Vector3x4, Vector3x8};
+
+#[cfg(feature = "rayon")]
+/// The number of elements each parallel task works on. It is a multiple of the four lanes of a [`base::Vector`], so
+/// tasks split on the same boundaries as the serial SIMD kernels and give identical results.
+pub(crate) const PAR_CHUNK: usize = 1024;
 
 /// Check if an argument is valid to pass into `shuffle`.
 pub const fn is_shuffle_arg(x: u32, y: u32, z: u32, w: u32) -> bool { x < 4 && y < 4 && z < 4 && w < 4 }
@@ -0,0 +1,152 @@
+//! Conversions to and from `mint` types, whose matrices hold the transpose of a [`Matrix`] for column vectors.
+
+use mint::{ColumnMatrix4, Point3, Quaternion as MintQuaternion, RowMatrix4, Vector3, Vector4};
+
+use crate::{
+	base::{Matrix, Quaternion, Vector},
+	direction::Direction,
+	point::Point,
+	transform::Transform,
+};
+
+impl From<Vector> for Vector4<f32> {
+	#[inline(always)]
+	fn from(val: Vector) -> Self { <[f32; 4]>::from(val).into() }
+}
+
+impl From<Vector4<f32>> for Vector {
+	#[inline(always)]
+	fn from(val: Vector4<f32>) -> Self { Self::new(val.x, val.y, val.z, val.w) }
+}
+
+impl From<Point> for Point3<f32> {
+	#[inline(always)]
+	fn from(val: Point) -> Self {
+		Self {
+			x: val.x(),
+			y: val.y(),
+			z: val.z(),
+		}
+	}
+}
+
+impl From<Point3<f32>> for Point {
+	#[inline(always)]
+	fn from(val: Point3<f32>) -> Self { Self::new(val.x, val.y, val.z) }
+}
+
+impl From<Direction> for Vector3<f32> {
+	#[inline(always)]
+	fn from(val: Direction) -> Self {
+		Self {
+			x: val.x(),
+			y: val.y(),
+			z: val.z(),
+		}
+	}
+}
+
+impl From<Vector3<f32>> for Direction {
+	#[inline(always)]
+	fn from(val: Vector3<f32>) -> Self { Self::new(val.x, val.y, val.z) }
+}
+
+impl From<Quaternion> for MintQuaternion<f32> {
+	#[inline(always)]
+	fn from(val: Quaternion) -> Self {
+		Self {
+			v: Direction(val.0).into(),
+			s: val.w(),
+		}
+	}
+}
+
+impl From<MintQuaternion<f32>> for Quaternion {
+	#[inline(always)]
+	fn from(val: MintQuaternion<f32>) -> Self { Self::new(val.v.x, val.v.y, val.v.z, val.s) }
+}
+
+impl From<Matrix> for ColumnMatrix4<f32> {
+	#[inline(always)]
+	fn from(val: Matrix) -> Self { <[[f32; 4]; 4]>::from(val).into() }
+}
+
+impl From<ColumnMatrix4<f32>> for Matrix {
+	#[inline(always)]
+	fn from(val: ColumnMatrix4<f32>) -> Self { Self::rows(val.into()) }
+}
+
+impl From<Matrix> for RowMatrix4<f32> {
+	#[inline(always)]
+	fn from(val: Matrix) -> Self { <[[f32; 4]; 4]>::from(val.transpose()).into() }
+}
+
+impl From<RowMatrix4<f32>> for Matrix {
+	#[inline(always)]
+	fn from(val: RowMatrix4<f32>) -> Self { Self::rows(val.into()).transpose() }
+}
+
+impl From<Transform> for ColumnMatrix4<f32> {
+	#[inline(always)]
+	fn from(val: Transform) -> Self { val.matrix.into() }
+}
+
+impl From<ColumnMatrix4<f32>> for Transform {
+	#[inline(always)]
+	fn from(val: ColumnMatrix4<f32>) -> Self { Matrix::from(val).into() }
+}
+
+impl From<Transform> for RowMatrix4<f32> {
+	#[inline(always)]
+	fn from(val: Transform) -> Self { val.matrix.into() }
+}
+
+impl From<RowMatrix4<f32>> for Transform {
+	#[inline(always)]
+	fn from(val: RowMatrix4<f32>) -> Self { Matrix::from(val).into() }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::fixtures;
+
+	#[test]
+	fn convention() {
+		// The translation is in the last column.
+		let translate = Transform::translate(Direction::new(1f32, 2f32, 3f32));
+		let columns = ColumnMatrix4::from(translate);
+		assert_eq!(columns.w, Vector4::from([1f32, 2f32, 3f32, 1f32]));
+		let rows = RowMatrix4::from(translate);
+		assert_eq!((rows.x.w, rows.y.w, rows.z.w, rows.w.w), (1f32, 2f32, 3f32, 1f32));
+
+		// Multiplying the rows by a column vector is the same as transforming a point.
+		let (transform, point) = (fixtures::transform(), Point::new(-2f32, 0.5f32, 4f32));
+		let rows = RowMatrix4::from(transform);
+		let column = Vector::new(point.x(), point.y(), point.z(), 1f32);
+		let expected = point * transform;
+		for (row, expected) in [rows.x, rows.y, rows.z]
+			.iter()
+			.zip([expected.x(), expected.y(), expected.z()].iter())
+		{
+			assert!((Vector::dot(Vector::from(*row), column) - expected).abs() < 0.0001f32);
+		}
+	}
+
+	#[test]
+	fn roundtrip() {
+		fixtures::assert_roundtrips!(
+			Vector4<f32>,
+			Point3<f32>,
+			Vector3<f32>,
+			MintQuaternion<f32>,
+			ColumnMatrix4<f32>
+		);
+
+		// mint also has row-major matrices, and stores the real part of quaternions separately.
+		let transform = fixtures::transform();
+		assert_eq!(Transform::from(RowMatrix4::from(transform)).matrix, transform.matrix);
+		let mint = MintQuaternion::from(Quaternion::new(0.5f32, -0.5f32, 0.5f32, 0.25f32));
+		assert_eq!((mint.v.x, mint.s), (0.5f32, 0.25f32));
+	}
+}
@@ -0,0 +1,421 @@
+//! Morton codes and Hilbert curve indices, for ordering [`Point`]s spatially.
+//!
+//! [`Point`]s are first quantized onto a grid covering a [`Bounds3`], and the cell coordinates are then mapped onto a
+//! space-filling curve. Nearby points get nearby codes, so sorting by code gives a cache-friendly order, and the
+//! prefixes of Morton codes are octree keys.
+
+use crate::{
+	base::{IVector, Matrix, Vector},
+	bounds::Bounds3,
+	direction::Direction,
+	point::Point,
+};
+
+/// The number of bits per axis of 30-bit codes.
+pub const BITS_30: u32 = 10;
+/// The number of bits per axis of 63-bit codes.
+pub const BITS_63: u32 = 21;
+
+#[inline(always)]
+/// Get the cell of the `2^bits` grid along each axis of `bounds` containing `point`. Points outside `bounds` are
+/// clamped to the closest cell.
+pub fn quantize(point: Point, bounds: Bounds3, bits: u32) -> (u32, u32, u32) {
+	let levels = (1u32 << bits) as f32;
+	let cell = Vector::clamp(
+		bounds.offset(point).0 * levels,
+		Vector::default(),
+		Vector::splat(levels - 1f32),
+	);
+	(cell.x() as u32, cell.y() as u32, cell.z() as u32)
+}
+
+#[inline(always)]
+/// Get the center of a cell of the `2^bits` grid along each axis of `bounds`. This is the inverse of [`quantize`].
+pub fn dequantize(cell: (u32, u32, u32), bounds: Bounds3, bits: u32) -> Point {
+	let levels = (1u32 << bits) as f32;
+	let cell = Vector::new(cell.0 as f32, cell.1 as f32, cell.2 as f32, 0f32);
+	bounds.lerp(Direction((cell + Vector::splat(0.5f32)) / levels))
+}
+
+#[inline(always)]
+/// Insert two zero bits between each of the lower 10 bits of `x`.
+const fn expand_30(x: u32) -> u32 {
+	let x = x & 0x3FF;
+	let x = (x | (x << 16)) & 0x030000FF;
+	let x = (x | (x << 8)) & 0x0300F00F;
+	let x = (x | (x << 4)) & 0x030C30C3;
+	(x | (x << 2)) & 0x09249249
+}
+
+#[inline(always)]
+/// [`expand_30`] on each component of `x`.
+fn expand_30x4(x: IVector) -> IVector {
+	let x = x & IVector::splat(0x3FF);
+	let x = (x | (x << 16)) & IVector::splat(0x030000FF);
+	let x = (x | (x << 8)) & IVector::splat(0x0300F00F);
+	let x = (x | (x << 4)) & IVector::splat(0x030C30C3);
+	(x | (x << 2)) & IVector::splat(0x09249249)
+}
+
+#[inline(always)]
+/// Take every third bit of `x`, undoing [`expand_30`].
+const fn compact_30(x: u32) -> u32 {
+	let x = x & 0x09249249;
+	let x = (x | (x >> 2)) & 0x030C30C3;
+	let x = (x | (x >> 4)) & 0x0300F00F;
+	let x = (x | (x >> 8)) & 0x030000FF;
+	(x | (x >> 16)) & 0x3FF
+}
+
+#[inline(always)]
+/// Insert two zero bits between each of the lower 21 bits of `x`.
+const fn expand_63(x: u32) -> u64 {
+	let x = x as u64 & 0x1FFFFF;
+	let x = (x | (x << 32)) & 0x001F00000000FFFF;
+	let x = (x | (x << 16)) & 0x001F0000FF0000FF;
+	let x = (x | (x << 8)) & 0x100F00F00F00F00F;
+	let x = (x | (x << 4)) & 0x10C30C30C30C30C3;
+	(x | (x << 2)) & 0x1249249249249249
+}
+
+#[inline(always)]
+/// Take every third bit of `x`, undoing [`expand_63`].
+const fn compact_63(x: u64) -> u32 {
+	let x = x & 0x1249249249249249;
+	let x = (x | (x >> 2)) & 0x10C30C30C30C30C3;
+	let x = (x | (x >> 4)) & 0x100F00F00F00F00F;
+	let x = (x | (x >> 8)) & 0x001F0000FF0000FF;
+	let x = (x | (x >> 16)) & 0x001F00000000FFFF;
+	((x | (x >> 32)) & 0x1FFFFF) as u32
+}
+
+#[inline(always)]
+/// Interleave the lower 10 bits of each coordinate into a 30-bit Morton code, with `x` in the highest bit of every
+/// triple.
+pub const fn interleave_30(x: u32, y: u32, z: u32) -> u32 { (expand_30(x) << 2) | (expand_30(y) << 1) | expand_30(z) }
+
+#[inline(always)]
+/// Get the coordinates back from a 30-bit Morton code.
+pub const fn deinterleave_30(code: u32) -> (u32, u32, u32) {
+	(compact_30(code >> 2), compact_30(code >> 1), compact_30(code))
+}
+
+#[inline(always)]
+/// Interleave the lower 21 bits of each coordinate into a 63-bit Morton code, with `x` in the highest bit of every
+/// triple.
+pub const fn interleave_63(x: u32, y: u32, z: u32) -> u64 { (expand_63(x) << 2) | (expand_63(y) << 1) | expand_63(z) }
+
+#[inline(always)]
+/// Get the coordinates back from a 63-bit Morton code.
+pub const fn deinterleave_63(code: u64) -> (u32, u32, u32) {
+	(compact_63(code >> 2), compact_63(code >> 1), compact_63(code))
+}
+
+#[inline(always)]
+/// Get the 30-bit Morton code of `point` within `bounds`.
+pub fn encode_30(point: Point, bounds: Bounds3) -> u32 {
+	let (x, y, z) = quantize(point, bounds, BITS_30);
+	interleave_30(x, y, z)
+}
+
+#[inline(always)]
+/// Get the center of the cell of `bounds` with the 30-bit Morton code `code`.
+pub fn decode_30(code: u32, bounds: Bounds3) -> Point { dequantize(deinterleave_30(code), bounds, BITS_30) }
+
+#[inline(always)]
+/// Get the 63-bit Morton code of `point` within `bounds`.
+pub fn encode_63(point: Point, bounds: Bounds3) -> u64 {
+	let (x, y, z) = quantize(point, bounds, BITS_63);
+	interleave_63(x, y, z)
+}
+
+#[inline(always)]
+/// Get the center of the cell of `bounds` with the 63-bit Morton code `code`.
+pub fn decode_63(code: u64, bounds: Bounds3) -> Point { dequantize(deinterleave_63(code), bounds, BITS_63) }
+
+/// Get the 30-bit Morton codes of four [`Point`]s within `bounds` at once.
+///
+/// The [`Point`]s are transposed so each coordinate is quantized for all four in one [`Vector`], and the bits are
+/// then interleaved on the four lanes of an [`IVector`]. Gives the same results as [`encode_30`].
+pub fn encode_30x4(points: [Point; 4], bounds: Bounds3) -> [u32; 4] {
+	let levels = (1u32 << BITS_30) as f32;
+	let soa = Matrix::row_vectors([points[0].0, points[1].0, points[2].0, points[3].0]).transpose();
+	let extent = bounds.extent();
+	let lanes = |axis: u8| {
+		// Match the rounding of `Bounds3::offset`, which divides rather than multiplying by the reciprocal.
+		let size = extent.0.get(axis);
+		let size = if size > 0f32 { size } else { 1f32 };
+		let offset = (soa.get_row(axis) - Vector::splat(bounds.min.0.get(axis))) / size;
+		let cell = Vector::clamp(offset * levels, Vector::default(), Vector::splat(levels - 1f32));
+		expand_30x4(IVector::truncate(cell))
+	};
+
+	((lanes(0) << 2) | (lanes(1) << 1) | lanes(2)).into()
+}
+
+/// Get the index of a cell along the three-dimensional Hilbert curve over a `2^bits` grid, where `bits` is at most
+/// 21.
+///
+/// Unlike Morton codes, consecutive indices are always adjacent cells, so the curve has no long jumps.
+pub fn hilbert_index(x: u32, y: u32, z: u32, bits: u32) -> u64 {
+	// 'Programming the Hilbert curve' (Skilling 2004).
+	let mask = (1u32 << bits) - 1;
+	let mut axes = [x & mask, y & mask, z & mask];
+	let top = 1u32 << (bits - 1);
+
+	let mut q = top;
+	while q > 1 {
+		let p = q - 1;
+		for idx in 0..3 {
+			if axes[idx] & q != 0 {
+				axes[0] ^= p;
+			} else {
+				let t = (axes[0] ^ axes[idx]) & p;
+				axes[0] ^= t;
+				axes[idx] ^= t;
+			}
+		}
+		q >>= 1;
+	}
+
+	axes[1] ^= axes[0];
+	axes[2] ^= axes[1];
+	let mut t = 0;
+	let mut q = top;
+	while q > 1 {
+		if axes[2] & q != 0 {
+			t ^= q - 1;
+		}
+		q >>= 1;
+	}
+	for axis in axes.iter_mut() {
+		*axis ^= t;
+	}
+
+	interleave_63(axes[0], axes[1], axes[2])
+}
+
+/// Get the cell at `index` along the three-dimensional Hilbert curve over a `2^bits` grid. This is the inverse of
+/// [`hilbert_index`].
+pub fn hilbert_axes(index: u64, bits: u32) -> (u32, u32, u32) {
+	let mut axes: [u32; 3] = deinterleave_63(index).into();
+
+	let t = axes[2] >> 1;
+	axes[2] ^= axes[1];
+	axes[1] ^= axes[0];
+	axes[0] ^= t;
+
+	let mut q = 2;
+	while q != 2 << (bits - 1) {
+		let p = q - 1;
+		for idx in (0..3).rev() {
+			if axes[idx] & q != 0 {
+				axes[0] ^= p;
+			} else {
+				let t = (axes[0] ^ axes[idx]) & p;
+				axes[0] ^= t;
+				axes[idx] ^= t;
+			}
+		}
+		q <<= 1;
+	}
+
+	axes.into()
+}
+
+#[inline(always)]
+/// Get the 63-bit Hilbert curve index of `point` within `bounds`.
+pub fn encode_hilbert(point: Point, bounds: Bounds3) -> u64 {
+	let (x, y, z) = quantize(point, bounds, BITS_63);
+	hilbert_index(x, y, z, BITS_63)
+}
+
+#[inline(always)]
+/// Get the center of the cell of `bounds` with the 63-bit Hilbert curve index `index`.
+pub fn decode_hilbert(index: u64, bounds: Bounds3) -> Point {
+	dequantize(hilbert_axes(index, BITS_63), bounds, BITS_63)
+}
+
+/// Stably sort `items` by the lower `bits` bits of `key`, with a least significant digit radix sort on bytes.
+pub fn radix_sort<T: Copy>(items: &mut [T], bits: u32, key: impl Fn(&T) -> u64) {
+	let mut scratch = items.to_vec();
+	let mut from_scratch = false;
+	for shift in (0..bits).step_by(8) {
+		let (from, to): (&[T], &mut [T]) = if from_scratch {
+			(&scratch, items)
+		} else {
+			(items, &mut scratch)
+		};
+
+		let mut offsets = [0usize; 256];
+		for item in from.iter() {
+			offsets[(key(item) >> shift) as usize & 0xFF] += 1;
+		}
+		let mut total = 0;
+		for offset in offsets.iter_mut() {
+			total += *offset;
+			*offset = total - *offset;
+		}
+		for item in from.iter() {
+			let digit = (key(item) >> shift) as usize & 0xFF;
+			to[offsets[digit]] = *item;
+			offsets[digit] += 1;
+		}
+		from_scratch = !from_scratch;
+	}
+
+	if from_scratch {
+		items.copy_from_slice(&scratch);
+	}
+}
+
+/// Get the indices of `points` sorted along the Morton curve over their bounds, which keeps nearby [`Point`]s close
+/// together.
+pub fn spatial_order(points: &[Point]) -> Vec<u32> {
+	let bounds = Bounds3::from_points(points);
+	let mut keys: Vec<_> = points
+		.chunks(4)
+		.enumerate()
+		.flat_map(|(chunk_idx, chunk)| {
+			let codes = match *chunk {
+				[p0, p1, p2, p3] => encode_30x4([p0, p1, p2, p3], bounds),
+				_ => {
+					let mut codes = [0u32; 4];
+					for (code, &point) in codes.iter_mut().zip(chunk.iter()) {
+						*code = encode_30(point, bounds);
+					}
+					codes
+				},
+			};
+			let first = chunk_idx as u32 * 4;
+			(0..chunk.len()).map(move |lane| (codes[lane], first + lane as u32))
+		})
+		.collect();
+
+	radix_sort(&mut keys, 3 * BITS_30, |&(code, _)| code as u64);
+	keys.into_iter().map(|(_, idx)| idx).collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::random::{self, Pcg32, Rng};
+
+	fn bounds() -> Bounds3 { Bounds3::new(Point::new(-2f32, 0f32, 1f32), Point::new(6f32, 1f32, 3f32)) }
+
+	#[test]
+	fn interleave() {
+		assert_eq!(interleave_30(1, 0, 0), 0b100);
+		assert_eq!(interleave_30(0, 1, 1), 0b011);
+		assert_eq!(interleave_30(0x3FF, 0x3FF, 0x3FF), (1 << 30) - 1);
+		assert_eq!(interleave_63(0x1FFFFF, 0x1FFFFF, 0x1FFFFF), (1 << 63) - 1);
+		assert_eq!(interleave_63(1 << 20, 0, 0), 1 << 62);
+
+		let mut rng = Pcg32::new(1, 0);
+		for _ in 0..1000 {
+			let (x, y, z) = (rng.next_u32(), rng.next_u32(), rng.next_u32());
+			let small = (x & 0x3FF, y & 0x3FF, z & 0x3FF);
+			assert_eq!(deinterleave_30(interleave_30(x, y, z)), small);
+			let large = (x & 0x1FFFFF, y & 0x1FFFFF, z & 0x1FFFFF);
+			assert_eq!(deinterleave_63(interleave_63(x, y, z)), large);
+			// The 30-bit code is a prefix of the 63-bit one.
+			assert_eq!(
+				interleave_63(large.0, large.1, large.2) >> 33,
+				interleave_30(large.0 >> 11, large.1 >> 11, large.2 >> 11) as u64
+			);
+		}
+	}
+
+	#[test]
+	fn encode_decode() {
+		let bounds = bounds();
+		let mut rng = Pcg32::new(2, 0);
+		for _ in 0..1000 {
+			let point = random::point_in_bounds(&mut rng, bounds);
+			let cell = bounds.extent().0 / 1024f32;
+			let error = (decode_30(encode_30(point, bounds), bounds) - point).0.abs();
+			assert!(Vector::max(error - cell * 0.5001f32, Vector::default()) == Vector::default());
+			let error = (decode_63(encode_63(point, bounds), bounds) - point).length();
+			assert!(error < 0.00001f32);
+			let error = (decode_hilbert(encode_hilbert(point, bounds), bounds) - point).length();
+			assert!(error < 0.00001f32);
+		}
+
+		// Points outside the bounds are clamped.
+		assert_eq!(encode_30(Point::new(-10f32, -10f32, -10f32), bounds), 0);
+		assert_eq!(encode_30(Point::new(10f32, 10f32, 10f32), bounds), (1 << 30) - 1);
+	}
+
+	#[test]
+	fn lanes() {
+		let bounds = bounds();
+		let mut rng = Pcg32::new(3, 0);
+		// Cover points outside the bounds too, which are clamped.
+		let outside = Bounds3::new(Point::new(-3f32, -1f32, 0f32), Point::new(7f32, 2f32, 4f32));
+		for _ in 0..250 {
+			let points = [(); 4].map(|_| random::point_in_bounds(&mut rng, outside));
+			assert_eq!(
+				encode_30x4(points, bounds),
+				points.map(|point| encode_30(point, bounds))
+			);
+		}
+
+		let flat = Bounds3::new(Point::new(-2f32, 0f32, 1f32), Point::new(6f32, 0f32, 3f32));
+		let points = [(); 4].map(|_| random::point_in_bounds(&mut rng, flat));
+		assert_eq!(encode_30x4(points, flat), points.map(|point| encode_30(point, flat)));
+	}
+
+	#[test]
+	fn hilbert_adjacency() {
+		let bits = 3;
+		let mut seen = vec![false; 1 << (3 * bits)];
+		let mut previous = hilbert_axes(0, bits);
+		assert_eq!(previous, (0, 0, 0));
+		for index in 0..1u64 << (3 * bits) {
+			let (x, y, z) = hilbert_axes(index, bits);
+			assert_eq!(hilbert_index(x, y, z, bits), index);
+			assert!(!std::mem::replace(&mut seen[interleave_30(x, y, z) as usize], true));
+
+			let step = x.abs_diff(previous.0) + y.abs_diff(previous.1) + z.abs_diff(previous.2);
+			assert_eq!(step, if index == 0 { 0 } else { 1 });
+			previous = (x, y, z);
+		}
+
+		let mut rng = Pcg32::new(4, 0);
+		for _ in 0..1000 {
+			let (x, y, z) = (
+				rng.next_u32() & 0x1FFFFF,
+				rng.next_u32() & 0x1FFFFF,
+				rng.next_u32() & 0x1FFFFF,
+			);
+			assert_eq!(hilbert_axes(hilbert_index(x, y, z, BITS_63), BITS_63), (x, y, z));
+		}
+	}
+
+	#[test]
+	fn sorting() {
+		let mut rng = Pcg32::new(5, 0);
+		let mut items: Vec<_> = (0..1000).map(|idx| (rng.next_u64() >> 1, idx)).collect();
+		let mut expected = items.clone();
+		expected.sort_by_key(|&(key, _)| key);
+		radix_sort(&mut items, 63, |&(key, _)| key);
+		assert_eq!(items, expected);
+
+		// The sort is stable.
+		let mut items: Vec<_> = (0..1000u32).map(|idx| (idx % 7, idx)).collect();
+		radix_sort(&mut items, 3, |&(key, _)| key as u64);
+		assert!(items.windows(2).all(|pair| pair[0] < pair[1]));
+
+		let points: Vec<_> = (0..103).map(|_| random::point_in_bounds(&mut rng, bounds())).collect();
+		let order = spatial_order(&points);
+		let point_bounds = points.iter().fold(Bounds3::empty(), |acc, &point| acc.expand(point));
+		let codes: Vec<_> = order
+			.iter()
+			.map(|&idx| encode_30(points[idx as usize], point_bounds))
+			.collect();
+		assert!(codes.windows(2).all(|pair| pair[0] <= pair[1]));
+		let mut sorted = order.clone();
+		sorted.sort_unstable();
+		assert_eq!(sorted, (0..103).collect::<Vec<_>>());
+	}
+}
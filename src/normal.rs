@@ -67,7 +67,12 @@ impl Mul<Transform> for Normal {
 	type Output = Self;
 
 	#[inline(always)]
-	fn mul(self, rhs: Transform) -> Self::Output { Self(self.0 * rhs.inverse.transpose()) }
+	fn mul(self, rhs: Transform) -> Self::Output {
+		// The transposed translation of the inverse would otherwise leak into w, which lengths and dot products read.
+		let mut normal = self.0 * rhs.inverse.transpose();
+		normal.set_w(0f32);
+		Self(normal)
+	}
 }
 
 impl MulAssign<f32> for Normal {
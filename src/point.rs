@@ -5,7 +5,15 @@ use std::{
 	ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
 };
 
-use crate::{base::Vector, direction::Direction, is_shuffle_arg, shuffle_mask, transform::Transform, Check, True};
+use crate::{
+	base::{DualQuaternion, Vector},
+	direction::Direction,
+	is_shuffle_arg,
+	shuffle_mask,
+	transform::Transform,
+	Check,
+	True,
+};
 
 #[repr(transparent)]
 #[derive(Copy, Clone, PartialEq)]
@@ -43,6 +51,19 @@ impl Display for Point {
 	}
 }
 
+impl Mul<DualQuaternion> for Point {
+	type Output = Self;
+
+	#[inline(always)]
+	#[allow(clippy::suspicious_arithmetic_impl)]
+	fn mul(self, rhs: DualQuaternion) -> Self::Output { Self(rhs.real.rotate(self.0) + rhs.translation().0) }
+}
+
+impl MulAssign<DualQuaternion> for Point {
+	#[inline(always)]
+	fn mul_assign(&mut self, rhs: DualQuaternion) { *self = *self * rhs }
+}
+
 impl Mul<Transform> for Point {
 	type Output = Self;
 
@@ -0,0 +1,265 @@
+//! Random geometry, for tests and procedural placement.
+//!
+//! Everything is generic over the small [`Rng`] trait, with [`Pcg32`] as a built-in implementation. With the `rand`
+//! feature, the same distributions are available through `rand`'s `Distribution` trait.
+
+use std::f32::consts::PI;
+
+use crate::{
+	base::{Quaternion, Vector},
+	bounds::Bounds3,
+	direction::Direction,
+	point::Point,
+	rotation::Rotation,
+	sampling,
+	shapes::Sphere,
+};
+
+/// A source of uniformly distributed random bits.
+pub trait Rng {
+	/// Get the next 32 random bits.
+	fn next_u32(&mut self) -> u32;
+
+	#[inline(always)]
+	/// Get the next 64 random bits.
+	fn next_u64(&mut self) -> u64 { (self.next_u32() as u64) << 32 | self.next_u32() as u64 }
+
+	#[inline(always)]
+	/// Get a uniformly distributed `f32` in [0, 1).
+	fn next_f32(&mut self) -> f32 { (self.next_u32() >> 8) as f32 * (1f32 / (1u32 << 24) as f32) }
+
+	#[inline(always)]
+	/// Get a uniformly distributed `f32` in [`min`, `max`).
+	fn next_range(&mut self, min: f32, max: f32) -> f32 { min + (max - min) * self.next_f32() }
+}
+
+impl<R: Rng + ?Sized> Rng for &mut R {
+	#[inline(always)]
+	fn next_u32(&mut self) -> u32 { (**self).next_u32() }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// The PCG-XSH-RR generator from 'PCG: A Family of Simple Fast Space-Efficient Statistically Good Algorithms for
+/// Random Number Generation' (O'Neill 2014), with 64 bits of state and 32 bits of output.
+pub struct Pcg32 {
+	state: u64,
+	increment: u64,
+}
+
+impl Default for Pcg32 {
+	#[inline(always)]
+	fn default() -> Self { Self::new(0x853c49e6748fea9b, 0xda3e39cb94b95bdb) }
+}
+
+impl Pcg32 {
+	/// The multiplier of the underlying linear congruential generator.
+	const MULTIPLIER: u64 = 6364136223846793005;
+
+	#[inline(always)]
+	/// Create a [`Pcg32`] with a `seed`. Generators with different `stream`s produce independent sequences, even
+	/// with the same `seed`.
+	pub const fn new(seed: u64, stream: u64) -> Self {
+		let mut rng = Self {
+			state: 0,
+			increment: (stream << 1) | 1,
+		};
+		rng.step();
+		rng.state = rng.state.wrapping_add(seed);
+		rng.step();
+		rng
+	}
+
+	#[inline(always)]
+	const fn step(&mut self) -> u64 {
+		let old = self.state;
+		self.state = old.wrapping_mul(Self::MULTIPLIER).wrapping_add(self.increment);
+		old
+	}
+}
+
+impl Rng for Pcg32 {
+	#[inline(always)]
+	fn next_u32(&mut self) -> u32 {
+		let old = self.step();
+		let shifted = (((old >> 18) ^ old) >> 27) as u32;
+		shifted.rotate_right((old >> 59) as u32)
+	}
+}
+
+#[inline(always)]
+/// Get a [`Rotation`] uniformly distributed over all rotations, using Shoemake's method from Graphics Gems III.
+pub fn rotation(rng: &mut impl Rng) -> Rotation {
+	let u = rng.next_f32();
+	let (low, high) = ((1f32 - u).sqrt(), u.sqrt());
+	let (sin1, cos1) = (2f32 * PI * rng.next_f32()).sin_cos();
+	let (sin2, cos2) = (2f32 * PI * rng.next_f32()).sin_cos();
+	Rotation(Quaternion::new(low * sin1, low * cos1, high * sin2, high * cos2))
+}
+
+#[inline(always)]
+/// Get a normalized [`Direction`] uniformly distributed on the unit sphere.
+pub fn direction(rng: &mut impl Rng) -> Direction { sampling::uniform_sphere((rng.next_f32(), rng.next_f32())) }
+
+#[inline(always)]
+/// Get a [`Point`] uniformly distributed inside `bounds`.
+pub fn point_in_bounds(rng: &mut impl Rng, bounds: Bounds3) -> Point {
+	bounds.lerp(Direction::new(rng.next_f32(), rng.next_f32(), rng.next_f32()))
+}
+
+#[inline(always)]
+/// Get a [`Point`] uniformly distributed inside `sphere`.
+pub fn point_in_sphere(rng: &mut impl Rng, sphere: Sphere) -> Point {
+	let dir = direction(rng);
+	sphere.center() + dir * (sphere.radius() * rng.next_f32().cbrt())
+}
+
+#[inline(always)]
+/// Get a [`Vector`] with every lane uniformly distributed in [0, 1).
+pub fn vector(rng: &mut impl Rng) -> Vector {
+	Vector::new(rng.next_f32(), rng.next_f32(), rng.next_f32(), rng.next_f32())
+}
+
+#[cfg(feature = "rand")]
+mod rand_impls {
+	use rand::{
+		distributions::{Distribution, Standard},
+		Error,
+		RngCore,
+	};
+
+	use super::*;
+
+	/// Use a `rand` generator as an [`Rng`].
+	struct Adapter<'a, R: ?Sized>(&'a mut R);
+
+	impl<R: rand::Rng + ?Sized> Rng for Adapter<'_, R> {
+		#[inline(always)]
+		fn next_u32(&mut self) -> u32 { self.0.next_u32() }
+	}
+
+	impl RngCore for Pcg32 {
+		#[inline(always)]
+		fn next_u32(&mut self) -> u32 { Rng::next_u32(self) }
+
+		#[inline(always)]
+		fn next_u64(&mut self) -> u64 { Rng::next_u64(self) }
+
+		#[inline(always)]
+		fn fill_bytes(&mut self, dest: &mut [u8]) {
+			for chunk in dest.chunks_mut(4) {
+				let bytes = Rng::next_u32(self).to_le_bytes();
+				chunk.copy_from_slice(&bytes[..chunk.len()]);
+			}
+		}
+
+		#[inline(always)]
+		fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+			self.fill_bytes(dest);
+			Ok(())
+		}
+	}
+
+	impl Distribution<Rotation> for Standard {
+		#[inline(always)]
+		fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Rotation { rotation(&mut Adapter(rng)) }
+	}
+
+	impl Distribution<Direction> for Standard {
+		#[inline(always)]
+		fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Direction { direction(&mut Adapter(rng)) }
+	}
+
+	impl Distribution<Vector> for Standard {
+		#[inline(always)]
+		fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Vector { vector(&mut Adapter(rng)) }
+	}
+
+	impl Distribution<Point> for Bounds3 {
+		#[inline(always)]
+		fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Point { point_in_bounds(&mut Adapter(rng), *self) }
+	}
+
+	impl Distribution<Point> for Sphere {
+		#[inline(always)]
+		fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Point { point_in_sphere(&mut Adapter(rng), *self) }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{base::nearly_equal, shapes::Contains};
+
+	#[test]
+	fn pcg32_reference() {
+		// The output of the reference implementation's demo.
+		let mut rng = Pcg32::new(42, 54);
+		let expected = [0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e];
+		for &value in expected.iter() {
+			assert_eq!(rng.next_u32(), value);
+		}
+	}
+
+	#[test]
+	fn ranges() {
+		let mut rng = Pcg32::default();
+		for _ in 0..1000 {
+			let value = rng.next_range(-2f32, 3f32);
+			assert!((-2f32..3f32).contains(&value));
+		}
+		assert_ne!(Pcg32::new(1, 0).next_u32(), Pcg32::new(1, 1).next_u32());
+	}
+
+	#[test]
+	fn uniform_rotations() {
+		// A uniform rotation takes a fixed axis to a uniformly distributed direction, so the mean is near zero.
+		let mut rng = Pcg32::new(7, 0);
+		let axis = Direction::new(0f32, 0f32, 1f32);
+		let mut mean = Vector::default();
+		let count = 10000;
+		for _ in 0..count {
+			let rotation = rotation(&mut rng);
+			assert!(nearly_equal(rotation.0 .0.length(), 1f32, 0.0001f32));
+			let rotated = rotation.0.rotate(axis.0);
+			assert!(nearly_equal(rotated.length(), 1f32, 0.0001f32));
+			mean += rotated;
+		}
+		assert!((mean / count as f32).length() < 0.03f32);
+	}
+
+	#[test]
+	fn points() {
+		let mut rng = Pcg32::new(3, 0);
+		let bounds = Bounds3::new(Point::new(-1f32, 2f32, 0f32), Point::new(3f32, 4f32, 1f32));
+		let sphere = Sphere::new(Point::new(1f32, -1f32, 2f32), 2f32);
+		let mut inner = 0;
+		for _ in 0..1000 {
+			assert!(bounds.contains(point_in_bounds(&mut rng, bounds)));
+			let point = point_in_sphere(&mut rng, sphere);
+			assert!(sphere.contains(point));
+			if (point - sphere.center()).length() < sphere.radius() / 2f32 {
+				inner += 1;
+			}
+			assert!(nearly_equal(direction(&mut rng).length(), 1f32, 0.0001f32));
+		}
+		// An eighth of the volume is within half the radius.
+		assert!((90..160).contains(&inner));
+	}
+
+	#[cfg(feature = "rand")]
+	#[test]
+	fn distributions() {
+		use rand::Rng as _;
+
+		let mut rng = Pcg32::new(5, 0);
+		let bounds = Bounds3::new(Point::new(-1f32, 2f32, 0f32), Point::new(3f32, 4f32, 1f32));
+		for _ in 0..100 {
+			let _: Rotation = rng.gen();
+			let dir: Direction = rng.gen();
+			assert!(nearly_equal(dir.length(), 1f32, 0.0001f32));
+			assert!(bounds.contains(rng.sample(bounds)));
+			let vector: Vector = rng.gen();
+			assert!(Vector::min(vector, Vector::default()) == Vector::default());
+		}
+	}
+}
@@ -0,0 +1,493 @@
+//! Rays and ray-primitive intersection.
+
+use std::{
+	fmt::{Debug, Display},
+	ops::{Mul, MulAssign},
+};
+
+use crate::{
+	base::Vector,
+	bounds::Bounds3,
+	direction::Direction,
+	normal::Normal,
+	point::Point,
+	shapes::{Plane, Sphere},
+	transform::Transform,
+};
+
+#[derive(Copy, Clone, PartialEq)]
+/// A half-line starting at `origin`, going along `dir`, that ends at `t_max`.
+pub struct Ray {
+	/// The origin of the [`Ray`].
+	pub origin: Point,
+	/// The direction of the [`Ray`]. May NOT be normalized, in which case distances are in multiples of its length.
+	pub dir: Direction,
+	/// The maximum distance along the [`Ray`] that is considered, in multiples of `dir`.
+	pub t_max: f32,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+/// The result of a successful ray-primitive intersection.
+pub struct RayHit {
+	/// The distance along the [`Ray`] of the hit, in multiples of `dir`.
+	pub t: f32,
+	/// The barycentric coordinates `(u, v)` of the hit on a triangle, where the hit point is
+	/// `p0 * (1 - u - v) + p1 * u + p2 * v`. Zero for other primitives.
+	pub barycentric: (f32, f32),
+	/// The normalized geometric normal of the primitive at the hit point.
+	pub normal: Normal,
+}
+
+impl Debug for Ray {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Ray [{:?}, {:?}, {}]", self.origin, self.dir, self.t_max)
+	}
+}
+
+impl Display for Ray {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Ray [{}, {}, {}]", self.origin, self.dir, self.t_max)
+	}
+}
+
+impl Mul<Transform> for Ray {
+	type Output = Self;
+
+	#[inline(always)]
+	fn mul(self, rhs: Transform) -> Self::Output {
+		Self {
+			origin: self.origin * rhs,
+			dir: self.dir * rhs,
+			t_max: self.t_max,
+		}
+	}
+}
+
+impl MulAssign<Transform> for Ray {
+	#[inline(always)]
+	fn mul_assign(&mut self, rhs: Transform) { *self = *self * rhs }
+}
+
+impl Ray {
+	#[inline(always)]
+	/// Create a new [`Ray`] that extends to infinity.
+	pub const fn new(origin: Point, dir: Direction) -> Self {
+		Self {
+			origin,
+			dir,
+			t_max: f32::INFINITY,
+		}
+	}
+
+	#[inline(always)]
+	/// Get the [`Point`] at distance `t` along the [`Ray`].
+	pub fn at(&self, t: f32) -> Point { self.origin + self.dir * t }
+
+	#[inline(always)]
+	/// Check if `t` is in the range (0, `t_max`].
+	fn in_range(&self, t: f32) -> bool { t > 0f32 && t <= self.t_max }
+
+	#[inline(always)]
+	/// Intersect the [`Ray`] with a sphere. Hits from the inside of the sphere are reported as well.
+	#[allow(clippy::suspicious_operation_groupings)]
+	pub fn intersect_sphere(&self, sphere: Sphere) -> Option<RayHit> {
+		let center = sphere.center();
+		let radius = sphere.radius();
+		let offset = self.origin - center;
+		let dir_square = self.dir.length_square();
+		let half_b = Direction::dot(offset, self.dir);
+		let c = radius.mul_add(-radius, offset.length_square());
+
+		let discriminant = half_b.mul_add(half_b, -dir_square * c);
+		if discriminant < 0f32 {
+			return None;
+		}
+
+		// Avoid catastrophic cancellation.
+		let q = -(half_b + discriminant.sqrt().copysign(half_b));
+		let (t0, t1) = (q / dir_square, c / q);
+		let (t0, t1) = if t0 > t1 { (t1, t0) } else { (t0, t1) };
+
+		let t = if self.in_range(t0) {
+			t0
+		} else if self.in_range(t1) {
+			t1
+		} else {
+			return None;
+		};
+
+		Some(RayHit {
+			t,
+			barycentric: (0f32, 0f32),
+			normal: Normal(((self.at(t) - center) / radius).0),
+		})
+	}
+
+	#[inline(always)]
+	/// Intersect the [`Ray`] with a normalized [`Plane`].
+	pub fn intersect_plane(&self, plane: Plane) -> Option<RayHit> {
+		let normal = plane.normal();
+		let denom = Vector::dot(normal.0, self.dir.0);
+		if denom == 0f32 {
+			return None;
+		}
+
+		let t = -plane.signed_distance(self.origin) / denom;
+		if self.in_range(t) {
+			Some(RayHit {
+				t,
+				barycentric: (0f32, 0f32),
+				normal,
+			})
+		} else {
+			None
+		}
+	}
+
+	#[inline(always)]
+	/// Intersect the [`Ray`] with a double-sided triangle, using the Möller–Trumbore algorithm.
+	/// This is fast, but rays that pass exactly through an edge shared by two triangles may miss both.
+	pub fn intersect_triangle(&self, p0: Point, p1: Point, p2: Point) -> Option<RayHit> {
+		let edge1 = p1 - p0;
+		let edge2 = p2 - p0;
+
+		let pvec = Direction::cross(self.dir, edge2);
+		let det = Direction::dot(edge1, pvec);
+		// `det` scales with the lengths of the direction and both edges, so small triangles need a smaller threshold.
+		let scale = self.dir.length_square() * edge1.length_square() * edge2.length_square();
+		if det * det <= f32::EPSILON * f32::EPSILON * scale {
+			return None;
+		}
+		let inv_det = 1f32 / det;
+
+		let tvec = self.origin - p0;
+		let u = Direction::dot(tvec, pvec) * inv_det;
+		if !(0f32..=1f32).contains(&u) {
+			return None;
+		}
+
+		let qvec = Direction::cross(tvec, edge1);
+		let v = Direction::dot(self.dir, qvec) * inv_det;
+		if v < 0f32 || u + v > 1f32 {
+			return None;
+		}
+
+		let t = Direction::dot(edge2, qvec) * inv_det;
+		if self.in_range(t) {
+			Some(RayHit {
+				t,
+				barycentric: (u, v),
+				normal: Normal(Direction::cross(edge1, edge2).normalize().0),
+			})
+		} else {
+			None
+		}
+	}
+
+	#[inline(always)]
+	/// Intersect the [`Ray`] with a double-sided triangle, using Woop et al.'s watertight algorithm.
+	/// Slower than [`Ray::intersect_triangle`], but rays never slip through edges shared by two triangles.
+	// Fusing the edge functions would round `a * b - c * d` differently from `-(c * d - a * b)`, so the two triangles
+	// sharing an edge could disagree on its sign.
+	#[allow(clippy::suboptimal_flops)]
+	pub fn intersect_triangle_watertight(&self, p0: Point, p1: Point, p2: Point) -> Option<RayHit> {
+		let dir: [f32; 4] = self.dir.0.into();
+
+		// Permute the axes so that the largest component of the direction is z.
+		let abs = self.dir.0.abs();
+		let kz = if abs.x() > abs.y() {
+			if abs.x() > abs.z() {
+				0
+			} else {
+				2
+			}
+		} else if abs.y() > abs.z() {
+			1
+		} else {
+			2
+		};
+		let kx = (kz + 1) % 3;
+		let ky = (kx + 1) % 3;
+		let (kx, ky) = if dir[kz] < 0f32 { (ky, kx) } else { (kx, ky) };
+
+		// Shear so that the ray points along +z.
+		let shear_x = dir[kx] / dir[kz];
+		let shear_y = dir[ky] / dir[kz];
+		let shear_z = 1f32 / dir[kz];
+
+		let a: [f32; 4] = (p0 - self.origin).0.into();
+		let b: [f32; 4] = (p1 - self.origin).0.into();
+		let c: [f32; 4] = (p2 - self.origin).0.into();
+
+		let (ax, ay) = (a[kx] - shear_x * a[kz], a[ky] - shear_y * a[kz]);
+		let (bx, by) = (b[kx] - shear_x * b[kz], b[ky] - shear_y * b[kz]);
+		let (cx, cy) = (c[kx] - shear_x * c[kz], c[ky] - shear_y * c[kz]);
+
+		let mut u = cx * by - cy * bx;
+		let mut v = ax * cy - ay * cx;
+		let mut w = bx * ay - by * ax;
+
+		// Fall back to double precision on edges.
+		if u == 0f32 || v == 0f32 || w == 0f32 {
+			u = (cx as f64 * by as f64 - cy as f64 * bx as f64) as f32;
+			v = (ax as f64 * cy as f64 - ay as f64 * cx as f64) as f32;
+			w = (bx as f64 * ay as f64 - by as f64 * ax as f64) as f32;
+		}
+
+		if (u < 0f32 || v < 0f32 || w < 0f32) && (u > 0f32 || v > 0f32 || w > 0f32) {
+			return None;
+		}
+
+		let det = u + v + w;
+		if det == 0f32 {
+			return None;
+		}
+
+		let t = (u * a[kz] + v * b[kz] + w * c[kz]) * shear_z / det;
+		if self.in_range(t) {
+			Some(RayHit {
+				t,
+				barycentric: (v / det, w / det),
+				normal: Normal(Direction::cross(p1 - p0, p2 - p0).normalize().0),
+			})
+		} else {
+			None
+		}
+	}
+
+	#[inline(always)]
+	/// Get the distances along the [`Ray`] at which it enters and leaves each slab of `bounds`. `inv_dir` is the
+	/// reciprocal of the direction with a w of 0. The entries have a w of 0, and the exits a w of infinity.
+	pub(crate) fn slabs(&self, bounds: Bounds3, inv_dir: Vector) -> (Vector, Vector) {
+		let mut t0: [f32; 4] = ((bounds.min.0 - self.origin.0) * inv_dir).into();
+		let mut t1: [f32; 4] = ((bounds.max.0 - self.origin.0) * inv_dir).into();
+
+		// A zero direction component with the origin on one of that axis' planes gives `0 * inf`, which is NaN. The
+		// ray stays on the plane, so that slab doesn't limit it. This can't be left to `min` and `max`, since
+		// backends handle NaNs differently.
+		for (lo, hi) in t0.iter_mut().zip(t1.iter_mut()) {
+			if lo.is_nan() || hi.is_nan() {
+				*lo = f32::NEG_INFINITY;
+				*hi = f32::INFINITY;
+			}
+		}
+		let (t0, t1) = (Vector::from(t0), Vector::from(t1));
+
+		let mut near = Vector::min(t0, t1);
+		near.set_w(0f32);
+		let mut far = Vector::max(t0, t1);
+		far.set_w(f32::INFINITY);
+		(near, far)
+	}
+
+	#[inline(always)]
+	/// Intersect the [`Ray`] with a [`Bounds3`], using the slab test.
+	/// If the [`Ray`] starts inside the [`Bounds3`], the exit is reported.
+	pub fn intersect_bounds(&self, bounds: Bounds3) -> Option<RayHit> {
+		let inv_dir = Vector::new(1f32, 1f32, 1f32, 0f32) / (self.dir.0 + Vector::new(0f32, 0f32, 0f32, 1f32));
+		let (near, far) = self.slabs(bounds, inv_dir);
+
+		let t_near = near.hmax();
+		let t_far = far.hmin();
+		if t_near > t_far {
+			return None;
+		}
+
+		let (t, slabs, sign) = if t_near > 0f32 {
+			(t_near, near, -1f32)
+		} else {
+			(t_far, far, 1f32)
+		};
+		if !self.in_range(t) {
+			return None;
+		}
+
+		let axis = if slabs.x() == t {
+			Vector::new(self.dir.x().signum(), 0f32, 0f32, 0f32)
+		} else if slabs.y() == t {
+			Vector::new(0f32, self.dir.y().signum(), 0f32, 0f32)
+		} else {
+			Vector::new(0f32, 0f32, self.dir.z().signum(), 0f32)
+		};
+
+		Some(RayHit {
+			t,
+			barycentric: (0f32, 0f32),
+			normal: Normal(axis * sign),
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::base::nearly_equal;
+
+	fn assert_hit(hit: Option<RayHit>, t: f32, normal: Normal) {
+		let hit = hit.expect("Ray missed");
+		assert!(nearly_equal(hit.t, t, 0.0001f32), "{} != {}", hit.t, t);
+		assert!(
+			nearly_equal(Normal::dot(hit.normal, normal), 1f32, 0.0001f32),
+			"{:?} != {:?}",
+			hit.normal,
+			normal
+		);
+	}
+
+	#[test]
+	fn at() {
+		let ray = Ray::new(Point::new(1f32, 2f32, 3f32), Direction::new(0f32, 0f32, 2f32));
+
+		assert_eq!(ray.at(1.5f32), Point::new(1f32, 2f32, 6f32));
+	}
+
+	#[test]
+	fn transform() {
+		let ray = Ray::new(Point::new(0f32, 0f32, 0f32), Direction::new(1f32, 0f32, 0f32))
+			* Transform::translate(Direction::new(0f32, 5f32, 0f32));
+
+		assert_eq!(ray.origin, Point::new(0f32, 5f32, 0f32));
+		assert_eq!(ray.dir, Direction::new(1f32, 0f32, 0f32));
+	}
+
+	#[test]
+	fn sphere() {
+		let ray = Ray::new(Point::new(0f32, 0f32, -5f32), Direction::new(0f32, 0f32, 1f32));
+		let center = Point::new(0f32, 0f32, 0f32);
+		let sphere = Sphere::new(center, 1f32);
+
+		assert_hit(ray.intersect_sphere(sphere), 4f32, Normal::new(0f32, 0f32, -1f32));
+
+		let inside = Ray::new(center, Direction::new(0f32, 0f32, 1f32));
+		assert_hit(inside.intersect_sphere(sphere), 1f32, Normal::new(0f32, 0f32, 1f32));
+
+		let short = Ray { t_max: 3f32, ..ray };
+		assert_eq!(short.intersect_sphere(sphere), None);
+		assert_eq!(
+			ray.intersect_sphere(Sphere::new(Point::new(5f32, 0f32, 0f32), 1f32)),
+			None
+		);
+	}
+
+	#[test]
+	fn plane() {
+		let ray = Ray::new(Point::new(0f32, 5f32, 0f32), Direction::new(0f32, -1f32, 0f32));
+		let normal = Normal::new(0f32, 1f32, 0f32);
+
+		assert_hit(ray.intersect_plane(Plane::new(normal, 1f32)), 4f32, normal);
+		assert_eq!(ray.intersect_plane(Plane::new(normal, 6f32)), None);
+		assert_eq!(
+			Ray::new(Point::default(), Direction::new(1f32, 0f32, 0f32)).intersect_plane(Plane::new(normal, 1f32)),
+			None
+		);
+	}
+
+	#[test]
+	fn triangle() {
+		let p0 = Point::new(0f32, 0f32, 0f32);
+		let p1 = Point::new(1f32, 0f32, 0f32);
+		let p2 = Point::new(0f32, 1f32, 0f32);
+		let ray = Ray::new(Point::new(0.25f32, 0.5f32, 1f32), Direction::new(0f32, 0f32, -1f32));
+		let normal = Normal::new(0f32, 0f32, 1f32);
+
+		for hit in [
+			ray.intersect_triangle(p0, p1, p2),
+			ray.intersect_triangle_watertight(p0, p1, p2),
+		]
+		.iter()
+		{
+			assert_hit(*hit, 1f32, normal);
+			let (u, v) = hit.unwrap().barycentric;
+			assert!(nearly_equal(u, 0.25f32, 0.0001f32) && nearly_equal(v, 0.5f32, 0.0001f32));
+		}
+
+		let miss = Ray::new(Point::new(1f32, 1f32, 1f32), Direction::new(0f32, 0f32, -1f32));
+		assert_eq!(miss.intersect_triangle(p0, p1, p2), None);
+		assert_eq!(miss.intersect_triangle_watertight(p0, p1, p2), None);
+	}
+
+	#[test]
+	fn small_triangle() {
+		let p0 = Point::new(0f32, 0f32, 0f32);
+		let p1 = Point::new(1e-4f32, 0f32, 0f32);
+		let p2 = Point::new(0f32, 1e-4f32, 0f32);
+		let ray = Ray::new(Point::new(2.5e-5f32, 5e-5f32, 1f32), Direction::new(0f32, 0f32, -1f32));
+		assert_hit(ray.intersect_triangle(p0, p1, p2), 1f32, Normal::new(0f32, 0f32, 1f32));
+
+		let short = Ray::new(
+			Point::new(2.5e-5f32, 5e-5f32, 1e-4f32),
+			Direction::new(0f32, 0f32, -1e-4f32),
+		);
+		assert_hit(
+			short.intersect_triangle(p0, p1, p2),
+			1f32,
+			Normal::new(0f32, 0f32, 1f32),
+		);
+
+		let parallel = Ray::new(Point::new(0f32, 0f32, 0f32), Direction::new(1f32, 1f32, 0f32));
+		assert_eq!(parallel.intersect_triangle(p0, p1, p2), None);
+	}
+
+	#[test]
+	fn watertight_shared_edge() {
+		let p0 = Point::new(0f32, 0f32, 0f32);
+		let p1 = Point::new(1f32, 0f32, 0f32);
+		let p2 = Point::new(0f32, 1f32, 0f32);
+		let p3 = Point::new(1f32, 1f32, 0f32);
+
+		for i in 1..10 {
+			let x = i as f32 / 10f32;
+			let dir = Direction::new(0.1f32, 0.3f32, -1f32);
+			let ray = Ray::new(Point::new(x, 1f32 - x, 0f32) - dir, dir);
+
+			assert!(
+				ray.intersect_triangle_watertight(p0, p1, p2).is_some()
+					|| ray.intersect_triangle_watertight(p1, p3, p2).is_some()
+			);
+		}
+	}
+
+	#[test]
+	fn aabb() {
+		let bounds = Bounds3::new(Point::new(-1f32, -1f32, -1f32), Point::new(1f32, 1f32, 1f32));
+
+		let ray = Ray::new(Point::new(-5f32, 0f32, 0f32), Direction::new(1f32, 0f32, 0f32));
+		assert_hit(ray.intersect_bounds(bounds), 4f32, Normal::new(-1f32, 0f32, 0f32));
+
+		let inside = Ray::new(Point::default(), Direction::new(0f32, 0f32, -2f32));
+		assert_hit(inside.intersect_bounds(bounds), 0.5f32, Normal::new(0f32, 0f32, -1f32));
+
+		let miss = Ray::new(Point::new(-5f32, 3f32, 0f32), Direction::new(1f32, 0f32, 0f32));
+		assert_eq!(miss.intersect_bounds(bounds), None);
+
+		let behind = Ray::new(Point::new(5f32, 0f32, 0f32), Direction::new(1f32, 0f32, 0f32));
+		assert_eq!(behind.intersect_bounds(bounds), None);
+	}
+
+	#[test]
+	fn aabb_on_face() {
+		let bounds = Bounds3::new(Point::new(-1f32, -1f32, -1f32), Point::new(1f32, 1f32, 1f32));
+
+		// The origin is on the x = -1 and y = 1 planes, and the direction has zero x and y.
+		for &zero in [0f32, -0f32].iter() {
+			let ray = Ray::new(Point::new(-1f32, 1f32, -5f32), Direction::new(zero, zero, 1f32));
+			assert_hit(ray.intersect_bounds(bounds), 4f32, Normal::new(0f32, 0f32, -1f32));
+		}
+
+		let outside = Ray::new(Point::new(-1f32, 1.5f32, -5f32), Direction::new(0f32, 0f32, 1f32));
+		assert_eq!(outside.intersect_bounds(bounds), None);
+	}
+
+	#[test]
+	fn aabb_exit_at_t_max() {
+		let bounds = Bounds3::new(Point::new(-1f32, -1f32, -1f32), Point::new(1f32, 1f32, 1f32));
+		let mut ray = Ray::new(Point::default(), Direction::new(0f32, 0f32, 1f32));
+		ray.t_max = 1f32;
+		assert_hit(ray.intersect_bounds(bounds), 1f32, Normal::new(0f32, 0f32, 1f32));
+
+		ray.t_max = 0.5f32;
+		assert_eq!(ray.intersect_bounds(bounds), None);
+	}
+}
@@ -1,7 +1,16 @@
 //! Rotations.
 
-use crate::{base::Quaternion, coordinate_system::CoordinateSystem};
+use std::fmt::{Debug, Display, Formatter, Result};
 
+use crate::{
+	base::{Matrix, Quaternion},
+	coordinate_system::CoordinateSystem,
+	Direction,
+};
+
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// The order to apply euler rotations in.
 pub enum RotationOrder {
 	/// Pitch, yaw, and then roll.
@@ -18,6 +27,9 @@ pub enum RotationOrder {
 	YRP,
 }
 
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A rotation described by euler angles in radians. Positive angles convey an anti-clockwise rotation.
 pub struct EulerAngles {
 	/// Rotation along the `right` vector while looking along the vector, from the origin.
@@ -35,7 +47,77 @@ pub struct EulerAngles {
 /// A rotation in 3D space.
 pub struct Rotation(pub(crate) Quaternion);
 
+impl Debug for Rotation {
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result { write!(f, "Rot {}", self.0) }
+}
+
+impl Default for Rotation {
+	#[inline(always)]
+	fn default() -> Self { Self::identity() }
+}
+
+impl Display for Rotation {
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result { write!(f, "Rot {}", self.0) }
+}
+
 impl Rotation {
+	#[inline(always)]
+	/// Get the identity [`Rotation`] that leaves everything unchanged.
+	pub fn identity() -> Self { Self(Quaternion::default()) }
+
+	#[inline(always)]
+	/// Create a [`Rotation`] of `angle` radians anti-clockwise around `axis`.
+	pub fn axis_angle(axis: Direction, angle: f32) -> Self {
+		let half = angle / 2f32;
+		let mut quat = Quaternion(axis.normalize().0 * half.sin());
+		quat.set_w(half.cos());
+
+		Self(quat)
+	}
+
+	#[inline(always)]
+	/// Create a [`Rotation`] from the upper 3x3 of a [`Matrix`], which must be a pure rotation.
+	pub fn from_matrix(matrix: Matrix) -> Self {
+		let m = |row: u8, column: u8| matrix.get_row(row).get(column);
+
+		let trace = m(0, 0) + m(1, 1) + m(2, 2);
+		Self(if trace > 0f32 {
+			let s = (trace + 1f32).sqrt() * 2f32;
+			Quaternion::new(
+				(m(1, 2) - m(2, 1)) / s,
+				(m(2, 0) - m(0, 2)) / s,
+				(m(0, 1) - m(1, 0)) / s,
+				s / 4f32,
+			)
+		} else if m(0, 0) > m(1, 1) && m(0, 0) > m(2, 2) {
+			let s = (1f32 + m(0, 0) - m(1, 1) - m(2, 2)).sqrt() * 2f32;
+			Quaternion::new(
+				s / 4f32,
+				(m(1, 0) + m(0, 1)) / s,
+				(m(2, 0) + m(0, 2)) / s,
+				(m(1, 2) - m(2, 1)) / s,
+			)
+		} else if m(1, 1) > m(2, 2) {
+			let s = (1f32 + m(1, 1) - m(0, 0) - m(2, 2)).sqrt() * 2f32;
+			Quaternion::new(
+				(m(0, 1) + m(1, 0)) / s,
+				s / 4f32,
+				(m(2, 1) + m(1, 2)) / s,
+				(m(2, 0) - m(0, 2)) / s,
+			)
+		} else {
+			let s = (1f32 + m(2, 2) - m(0, 0) - m(1, 1)).sqrt() * 2f32;
+			Quaternion::new(
+				(m(0, 2) + m(2, 0)) / s,
+				(m(1, 2) + m(2, 1)) / s,
+				s / 4f32,
+				(m(0, 1) - m(1, 0)) / s,
+			)
+		})
+	}
+
 	#[inline(always)]
 	/// Create a [`Rotation`] from [`EulerAngles`].  
 	/// `system` is the [`CoordinateSystem`] to use to decipher what `angles` mean.
@@ -69,4 +151,208 @@ impl Rotation {
 			YRP => yaw * roll * pitch,
 		})
 	}
+
+	#[inline(always)]
+	/// Get the weighted average of many [`Rotation`]s.  
+	/// Uses Markley et al.'s method, which finds the eigenvector with the largest eigenvalue of the weighted sum of
+	/// the quaternions' outer products. As `q` and `-q` have the same outer product, the result does not depend on
+	/// the signs of the quaternions.  
+	/// Returns the identity if `weighted` is empty, or all the weights are zero.
+	pub fn weighted_average(weighted: &[(Self, f32)]) -> Self {
+		let mut sum = [[0f32; 4]; 4];
+		for &(rotation, weight) in weighted {
+			let quat: [f32; 4] = rotation.0 .0.into();
+			for (row, &lhs) in sum.iter_mut().zip(quat.iter()) {
+				for (elem, &rhs) in row.iter_mut().zip(quat.iter()) {
+					*elem += weight * lhs * rhs;
+				}
+			}
+		}
+
+		match max_eigenvector(sum) {
+			Some(vec) if vec[3] < 0f32 => Self(Quaternion::new(-vec[0], -vec[1], -vec[2], -vec[3])),
+			Some(vec) => Self(Quaternion::new(vec[0], vec[1], vec[2], vec[3])),
+			None => Self::identity(),
+		}
+	}
+}
+
+#[derive(Copy, Clone, Debug)]
+/// A fast, approximate weighted average of [`Rotation`]s, for real-time blending.
+///
+/// Every [`Rotation`] is flipped into the hemisphere of the first one added, summed, and then normalized. This is
+/// accurate when the [`Rotation`]s are close to each other, use [`Rotation::weighted_average`] when they are not.
+pub struct RotationAccumulator {
+	sum: Quaternion,
+	pivot: Option<Quaternion>,
+}
+
+impl Default for RotationAccumulator {
+	#[inline(always)]
+	fn default() -> Self { Self::new() }
+}
+
+impl RotationAccumulator {
+	#[inline(always)]
+	/// Create an empty [`RotationAccumulator`].
+	pub fn new() -> Self {
+		Self {
+			sum: Quaternion::new(0f32, 0f32, 0f32, 0f32),
+			pivot: None,
+		}
+	}
+
+	#[inline(always)]
+	/// Add a [`Rotation`] with a `weight`.
+	pub fn add(&mut self, rotation: Rotation, weight: f32) {
+		let pivot = *self.pivot.get_or_insert(rotation.0);
+		if Quaternion::dot(pivot, rotation.0) < 0f32 {
+			self.sum -= rotation.0 * weight;
+		} else {
+			self.sum += rotation.0 * weight;
+		}
+	}
+
+	#[inline(always)]
+	/// Get the average of the added [`Rotation`]s.  
+	/// Returns the identity if nothing has been added, or all the weights are zero.
+	pub fn rotation(&self) -> Rotation {
+		if Quaternion::dot(self.sum, self.sum) == 0f32 {
+			Rotation::identity()
+		} else {
+			Rotation(self.sum.normalize())
+		}
+	}
+}
+
+/// Find the eigenvector with the largest eigenvalue of a symmetric 4x4 matrix, using cyclic Jacobi rotations.
+/// Returns `None` if the largest eigenvalue is not positive.
+#[allow(clippy::needless_range_loop)]
+fn max_eigenvector(mut mat: [[f32; 4]; 4]) -> Option<[f32; 4]> {
+	let mut vecs = [
+		[1f32, 0f32, 0f32, 0f32],
+		[0f32, 1f32, 0f32, 0f32],
+		[0f32, 0f32, 1f32, 0f32],
+		[0f32, 0f32, 0f32, 1f32],
+	];
+
+	for _ in 0..16 {
+		let mut off = 0f32;
+		for p in 0..3 {
+			for q in (p + 1)..4 {
+				off += mat[p][q] * mat[p][q];
+			}
+		}
+		if off < 1e-18f32 {
+			break;
+		}
+
+		for p in 0..3 {
+			for q in (p + 1)..4 {
+				if mat[p][q] == 0f32 {
+					continue;
+				}
+
+				let theta = (mat[q][q] - mat[p][p]) / (2f32 * mat[p][q]);
+				let t = theta.signum() / (theta.abs() + (theta * theta + 1f32).sqrt());
+				let c = 1f32 / (t * t + 1f32).sqrt();
+				let s = t * c;
+
+				for row in mat.iter_mut().chain(vecs.iter_mut()) {
+					let (kp, kq) = (row[p], row[q]);
+					row[p] = c * kp - s * kq;
+					row[q] = s * kp + c * kq;
+				}
+				for k in 0..4 {
+					let (pk, qk) = (mat[p][k], mat[q][k]);
+					mat[p][k] = c * pk - s * qk;
+					mat[q][k] = s * pk + c * qk;
+				}
+			}
+		}
+	}
+
+	let max = (0..4).fold(0, |max, i| if mat[i][i] > mat[max][max] { i } else { max });
+	if mat[max][max] > 0f32 {
+		let vec = [vecs[0][max], vecs[1][max], vecs[2][max], vecs[3][max]];
+		let length = vec.iter().map(|x| x * x).sum::<f32>().sqrt();
+		Some([vec[0] / length, vec[1] / length, vec[2] / length, vec[3] / length])
+	} else {
+		None
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::f32::consts::FRAC_PI_2;
+
+	use super::*;
+	use crate::{approx::Tolerance, assert_approx_eq};
+
+	fn flip(rotation: Rotation) -> Rotation { Rotation(rotation.0 * -1f32) }
+
+	fn rotations() -> [(Rotation, f32); 3] {
+		[
+			(Rotation::axis_angle(Direction::new(0f32, 0f32, 1f32), 0.3f32), 2f32),
+			(Rotation::axis_angle(Direction::new(1f32, 1f32, 0f32), 0.5f32), 1f32),
+			(Rotation::axis_angle(Direction::new(0f32, 1f32, 1f32), -0.2f32), 0.5f32),
+		]
+	}
+
+	#[test]
+	fn weighted_average() {
+		let z = Direction::new(0f32, 0f32, 1f32);
+		let halfway =
+			Rotation::weighted_average(&[(Rotation::identity(), 1f32), (Rotation::axis_angle(z, FRAC_PI_2), 1f32)]);
+		assert_approx_eq!(halfway, Rotation::axis_angle(z, FRAC_PI_2 / 2f32));
+
+		let first = Rotation::axis_angle(z, 0.3f32);
+		assert_approx_eq!(
+			Rotation::weighted_average(&[(first, 1f32), (Rotation::axis_angle(z, 2f32), 0f32)]),
+			first,
+		);
+		assert_eq!(Rotation::weighted_average(&[]), Rotation::identity());
+	}
+
+	#[test]
+	fn weighted_average_sign_invariant() {
+		let rotations = rotations();
+		let mut flipped = rotations;
+		flipped[1].0 = flip(flipped[1].0);
+		flipped[2].0 = flip(flipped[2].0);
+
+		assert_approx_eq!(
+			Rotation::weighted_average(&rotations),
+			Rotation::weighted_average(&flipped)
+		);
+	}
+
+	#[test]
+	fn accumulator() {
+		let z = Direction::new(0f32, 0f32, 1f32);
+		let mut acc = RotationAccumulator::new();
+		acc.add(Rotation::identity(), 1f32);
+		acc.add(Rotation::axis_angle(z, FRAC_PI_2), 1f32);
+		assert_approx_eq!(acc.rotation(), Rotation::axis_angle(z, FRAC_PI_2 / 2f32));
+
+		assert_eq!(RotationAccumulator::new().rotation(), Rotation::identity());
+	}
+
+	#[test]
+	fn accumulator_sign_invariant() {
+		let mut acc = RotationAccumulator::new();
+		let mut flipped = RotationAccumulator::new();
+		for &(rotation, weight) in rotations().iter() {
+			acc.add(rotation, weight);
+			flipped.add(flip(rotation), weight);
+		}
+
+		assert_approx_eq!(acc.rotation(), flipped.rotation());
+		// The accumulator approximates the eigenvector solve, so it is only close.
+		assert_approx_eq!(
+			acc.rotation(),
+			Rotation::weighted_average(&rotations()),
+			Tolerance::Absolute(0.001f32)
+		);
+	}
 }
@@ -0,0 +1,408 @@
+//! Warping uniform samples in `[0, 1)^2` to other distributions, for Monte Carlo integration.
+//!
+//! Every warp has a function to get its probability density, and one to invert it, getting back the sample that
+//! would have been warped to a given value. Directions are in a local space where the z axis is the surface normal,
+//! which can be moved to world space with a [`Frame`].
+//!
+//! [`Frame`]: crate::Frame
+
+use std::f32::consts::{FRAC_1_PI, FRAC_PI_2, FRAC_PI_4, PI};
+
+use crate::{direction::Direction, point::Point};
+
+/// `2 * PI`.
+const TAU: f32 = 2f32 * PI;
+
+#[inline(always)]
+/// Get the [`Direction`] from spherical coordinates.
+fn spherical(cos_theta: f32, phi: f32) -> Direction {
+	let sin_theta = cos_theta.mul_add(-cos_theta, 1f32).max(0f32).sqrt();
+	let (sin_phi, cos_phi) = phi.sin_cos();
+	Direction::new(sin_theta * cos_phi, sin_theta * sin_phi, cos_theta)
+}
+
+#[inline(always)]
+/// Get the sample in [0, 1) that maps to the azimuth of `dir`.
+fn invert_phi(dir: Direction) -> f32 {
+	let phi = dir.y().atan2(dir.x());
+	let phi = if phi < 0f32 { phi + TAU } else { phi };
+	(phi / TAU).min(1f32 - f32::EPSILON)
+}
+
+#[inline(always)]
+/// Get a uniformly distributed [`Direction`] on the unit sphere.
+pub fn uniform_sphere(u: (f32, f32)) -> Direction { spherical(2f32.mul_add(-u.0, 1f32), TAU * u.1) }
+
+#[inline(always)]
+/// Get the probability density of [`uniform_sphere`], with respect to solid angle.
+pub const fn uniform_sphere_pdf() -> f32 { 1f32 / (4f32 * PI) }
+
+#[inline(always)]
+/// Get the sample that [`uniform_sphere`] maps to a normalized `dir`.
+pub fn invert_uniform_sphere(dir: Direction) -> (f32, f32) { ((1f32 - dir.z()) / 2f32, invert_phi(dir)) }
+
+#[inline(always)]
+/// Get a uniformly distributed [`Direction`] on the unit hemisphere around the z axis.
+pub fn uniform_hemisphere(u: (f32, f32)) -> Direction { spherical(u.0, TAU * u.1) }
+
+#[inline(always)]
+/// Get the probability density of [`uniform_hemisphere`], with respect to solid angle.
+pub const fn uniform_hemisphere_pdf() -> f32 { 1f32 / TAU }
+
+#[inline(always)]
+/// Get the sample that [`uniform_hemisphere`] maps to a normalized `dir`.
+pub fn invert_uniform_hemisphere(dir: Direction) -> (f32, f32) { (dir.z(), invert_phi(dir)) }
+
+/// Get a uniformly distributed [`Point`] on the unit disk in the xy plane, using Shirley and Chiu's concentric
+/// mapping, which keeps strata compact.
+pub fn concentric_disk(u: (f32, f32)) -> Point {
+	let x = 2f32.mul_add(u.0, -1f32);
+	let y = 2f32.mul_add(u.1, -1f32);
+	if x == 0f32 && y == 0f32 {
+		return Point::new(0f32, 0f32, 0f32);
+	}
+
+	let (r, theta) = if x.abs() > y.abs() {
+		(x, FRAC_PI_4 * (y / x))
+	} else {
+		(y, FRAC_PI_4.mul_add(-(x / y), FRAC_PI_2))
+	};
+	let (sin, cos) = theta.sin_cos();
+	Point::new(r * cos, r * sin, 0f32)
+}
+
+#[inline(always)]
+/// Get the probability density of [`concentric_disk`], with respect to area.
+pub const fn concentric_disk_pdf() -> f32 { FRAC_1_PI }
+
+/// Get the sample that [`concentric_disk`] maps to `point`, which must be on the unit disk in the xy plane.
+pub fn invert_concentric_disk(point: Point) -> (f32, f32) {
+	let r = point.y().mul_add(point.y(), point.x() * point.x()).sqrt();
+	let phi = point.y().atan2(point.x());
+	// Move the angle into [-PI / 4, 7 * PI / 4), so each quadrant of the square is a contiguous range.
+	let phi = if phi < -FRAC_PI_4 { phi + TAU } else { phi };
+
+	let (x, y) = if phi < FRAC_PI_4 {
+		(r, r * phi / FRAC_PI_4)
+	} else if phi < 3f32 * FRAC_PI_4 {
+		(r * (FRAC_PI_2 - phi) / FRAC_PI_4, r)
+	} else if phi < 5f32 * FRAC_PI_4 {
+		(-r, -r * (phi - PI) / FRAC_PI_4)
+	} else {
+		(-r * (FRAC_PI_2 - (phi - PI)) / FRAC_PI_4, -r)
+	};
+	((x + 1f32) / 2f32, (y + 1f32) / 2f32)
+}
+
+#[inline(always)]
+/// Get a cosine-weighted [`Direction`] on the unit hemisphere around the z axis, using Malley's method.
+pub fn cosine_hemisphere(u: (f32, f32)) -> Direction {
+	let disk = concentric_disk(u);
+	let z = disk
+		.y()
+		.mul_add(-disk.y(), disk.x().mul_add(-disk.x(), 1f32))
+		.max(0f32)
+		.sqrt();
+	Direction::new(disk.x(), disk.y(), z)
+}
+
+#[inline(always)]
+/// Get the probability density of [`cosine_hemisphere`] producing a [`Direction`] with z equal to `cos_theta`, with
+/// respect to solid angle.
+pub fn cosine_hemisphere_pdf(cos_theta: f32) -> f32 { cos_theta.max(0f32) * FRAC_1_PI }
+
+#[inline(always)]
+/// Get the sample that [`cosine_hemisphere`] maps to a normalized `dir`.
+pub fn invert_cosine_hemisphere(dir: Direction) -> (f32, f32) {
+	invert_concentric_disk(Point::new(dir.x(), dir.y(), 0f32))
+}
+
+#[inline(always)]
+/// Get a uniformly distributed [`Direction`] in the cone around the z axis, containing the [`Direction`]s with z at
+/// least `cos_max`.
+pub fn uniform_cone(u: (f32, f32), cos_max: f32) -> Direction { spherical(u.0.mul_add(cos_max, 1f32 - u.0), TAU * u.1) }
+
+#[inline(always)]
+/// Get the probability density of [`uniform_cone`], with respect to solid angle.
+pub fn uniform_cone_pdf(cos_max: f32) -> f32 { 1f32 / (TAU * (1f32 - cos_max)) }
+
+#[inline(always)]
+/// Get the sample that [`uniform_cone`] maps to a normalized `dir`.
+pub fn invert_uniform_cone(dir: Direction, cos_max: f32) -> (f32, f32) {
+	((1f32 - dir.z()) / (1f32 - cos_max), invert_phi(dir))
+}
+
+#[inline(always)]
+/// Get uniformly distributed barycentric coordinates `(u, v)` on a triangle, where the point is
+/// `p0 * (1 - u - v) + p1 * u + p2 * v`, like in [`RayHit`](crate::RayHit).
+pub fn uniform_triangle(u: (f32, f32)) -> (f32, f32) {
+	let root = u.0.sqrt();
+	(1f32 - root, u.1 * root)
+}
+
+#[inline(always)]
+/// Get the probability density of [`uniform_triangle`] on the triangle `p0`, `p1`, `p2`, with respect to area.
+pub fn uniform_triangle_pdf(p0: Point, p1: Point, p2: Point) -> f32 {
+	2f32 / Direction::cross(p1 - p0, p2 - p0).length()
+}
+
+#[inline(always)]
+/// Get the sample that [`uniform_triangle`] maps to the barycentric coordinates `(u, v)`.
+pub fn invert_uniform_triangle(barycentric: (f32, f32)) -> (f32, f32) {
+	let root = 1f32 - barycentric.0;
+	let v = if root > 0f32 { barycentric.1 / root } else { 0f32 };
+	(root * root, v)
+}
+
+#[inline(always)]
+/// Get a microfacet normal distributed according to the GGX (Trowbridge-Reitz) distribution with roughness `alpha`,
+/// weighted by its cosine with the z axis.
+pub fn ggx(u: (f32, f32), alpha: f32) -> Direction {
+	let tan2_theta = alpha * alpha * u.0 / (1f32 - u.0);
+	spherical(1f32 / (1f32 + tan2_theta).sqrt(), TAU * u.1)
+}
+
+#[inline(always)]
+/// Get the probability density of [`ggx`], with respect to solid angle.
+pub fn ggx_pdf(dir: Direction, alpha: f32) -> f32 {
+	let cos2_theta = dir.z() * dir.z();
+	if dir.z() <= 0f32 {
+		return 0f32;
+	}
+
+	let alpha2 = alpha * alpha;
+	let tan2_theta = (1f32 - cos2_theta) / cos2_theta;
+	let denom = alpha2 + tan2_theta;
+	alpha2 / (PI * cos2_theta * dir.z() * denom * denom)
+}
+
+#[inline(always)]
+/// Get the sample that [`ggx`] maps to a normalized `dir`.
+pub fn invert_ggx(dir: Direction, alpha: f32) -> (f32, f32) {
+	let cos2_theta = dir.z() * dir.z();
+	let tan2_theta = (1f32 - cos2_theta) / cos2_theta;
+	(tan2_theta / alpha.mul_add(alpha, tan2_theta), invert_phi(dir))
+}
+
+#[inline(always)]
+/// Get a microfacet normal distributed according to the Beckmann distribution with roughness `alpha`, weighted by
+/// its cosine with the z axis.
+pub fn beckmann(u: (f32, f32), alpha: f32) -> Direction {
+	let tan2_theta = -alpha * alpha * (1f32 - u.0).ln();
+	spherical(1f32 / (1f32 + tan2_theta).sqrt(), TAU * u.1)
+}
+
+#[inline(always)]
+/// Get the probability density of [`beckmann`], with respect to solid angle.
+pub fn beckmann_pdf(dir: Direction, alpha: f32) -> f32 {
+	let cos2_theta = dir.z() * dir.z();
+	if dir.z() <= 0f32 {
+		return 0f32;
+	}
+
+	let alpha2 = alpha * alpha;
+	let tan2_theta = (1f32 - cos2_theta) / cos2_theta;
+	(-tan2_theta / alpha2).exp() / (PI * alpha2 * cos2_theta * dir.z())
+}
+
+#[inline(always)]
+/// Get the sample that [`beckmann`] maps to a normalized `dir`.
+pub fn invert_beckmann(dir: Direction, alpha: f32) -> (f32, f32) {
+	let cos2_theta = dir.z() * dir.z();
+	let tan2_theta = (1f32 - cos2_theta) / cos2_theta;
+	(1f32 - (-tan2_theta / (alpha * alpha)).exp(), invert_phi(dir))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::random::{Pcg32, Rng};
+
+	const SAMPLES: usize = 100_000;
+	const Z_BINS: usize = 20;
+	const PHI_BINS: usize = 20;
+
+	/// A deterministic sample sequence.
+	fn samples() -> impl Iterator<Item = (f32, f32)> {
+		let mut rng = Pcg32::default();
+		(0..SAMPLES).map(move |_| (rng.next_f32(), rng.next_f32()))
+	}
+
+	/// Check that `observed` counts fit the `expected` ones, with a significance level of 0.1%.
+	fn chi_square(observed: &[f64], expected: &[f64]) {
+		// Pool bins that are too small for the test to be valid.
+		let (mut stat, mut dof) = (0f64, 0f64);
+		let (mut pool_observed, mut pool_expected) = (0f64, 0f64);
+		for (&observed, &expected) in observed.iter().zip(expected.iter()) {
+			if expected < 5f64 {
+				pool_observed += observed;
+				pool_expected += expected;
+			} else {
+				stat += (observed - expected) * (observed - expected) / expected;
+				dof += 1f64;
+			}
+		}
+		if pool_expected > 0f64 {
+			stat += (pool_observed - pool_expected) * (pool_observed - pool_expected) / pool_expected;
+			dof += 1f64;
+		} else {
+			assert_eq!(pool_observed, 0f64);
+		}
+		dof -= 1f64;
+
+		// The Wilson-Hilferty approximation of the critical value.
+		let z = 3.09f64;
+		let critical = dof * (1f64 - 2f64 / (9f64 * dof) + z * (2f64 / (9f64 * dof)).sqrt()).powi(3);
+		assert!(
+			stat < critical,
+			"chi-square {} exceeds {} with {} dof",
+			stat,
+			critical,
+			dof
+		);
+	}
+
+	/// Test a warp to [`Direction`]s against its `pdf`, by binning in z and the azimuth.
+	fn test_directions(warp: impl Fn((f32, f32)) -> Direction, pdf: impl Fn(Direction) -> f32) {
+		let bin = |z: f32, phi: f32| {
+			let z = (((z + 1f32) / 2f32 * Z_BINS as f32) as usize).min(Z_BINS - 1);
+			let phi = ((phi / TAU * PHI_BINS as f32) as usize).min(PHI_BINS - 1);
+			z * PHI_BINS + phi
+		};
+
+		let mut observed = vec![0f64; Z_BINS * PHI_BINS];
+		for u in samples() {
+			let dir = warp(u);
+			assert!((dir.length() - 1f32).abs() < 0.001f32);
+			observed[bin(dir.z(), invert_phi(dir) * TAU)] += 1f64;
+		}
+
+		// Integrate the density over each bin, where the solid angle is dz * dphi.
+		let mut expected = vec![0f64; Z_BINS * PHI_BINS];
+		let steps = 16;
+		let dz = 2f64 / (Z_BINS * steps) as f64;
+		let dphi = TAU as f64 / (PHI_BINS * steps) as f64;
+		for z_step in 0..Z_BINS * steps {
+			let z = -1f64 + (z_step as f64 + 0.5f64) * dz;
+			for phi_step in 0..PHI_BINS * steps {
+				let phi = (phi_step as f64 + 0.5f64) * dphi;
+				let density = pdf(spherical(z as f32, phi as f32)) as f64;
+				expected[bin(z as f32, phi as f32)] += density * dz * dphi * SAMPLES as f64;
+			}
+		}
+
+		chi_square(&observed, &expected);
+	}
+
+	fn test_inverse(warp: impl Fn((f32, f32)) -> Direction, invert: impl Fn(Direction) -> (f32, f32)) {
+		for u in samples().take(1000) {
+			let inverse = invert(warp(u));
+			assert!((inverse.0 - u.0).abs() < 0.001f32, "{:?} {:?}", u, inverse);
+			assert!((inverse.1 - u.1).abs() < 0.001f32, "{:?} {:?}", u, inverse);
+		}
+	}
+
+	#[test]
+	fn sphere() {
+		test_directions(uniform_sphere, |_| uniform_sphere_pdf());
+		test_inverse(uniform_sphere, invert_uniform_sphere);
+	}
+
+	#[test]
+	fn hemisphere() {
+		let pdf = |dir: Direction| {
+			if dir.z() >= 0f32 {
+				uniform_hemisphere_pdf()
+			} else {
+				0f32
+			}
+		};
+		test_directions(uniform_hemisphere, pdf);
+		test_inverse(uniform_hemisphere, invert_uniform_hemisphere);
+	}
+
+	#[test]
+	fn cosine() {
+		test_directions(cosine_hemisphere, |dir| cosine_hemisphere_pdf(dir.z()));
+		test_inverse(cosine_hemisphere, invert_cosine_hemisphere);
+	}
+
+	#[test]
+	fn cone() {
+		let pdf = |dir: Direction| {
+			if dir.z() >= 0.5f32 {
+				uniform_cone_pdf(0.5f32)
+			} else {
+				0f32
+			}
+		};
+		test_directions(|u| uniform_cone(u, 0.5f32), pdf);
+		test_inverse(|u| uniform_cone(u, 0.5f32), |dir| invert_uniform_cone(dir, 0.5f32));
+	}
+
+	#[test]
+	fn microfacets() {
+		for &alpha in [0.3f32, 0.8f32].iter() {
+			test_directions(|u| ggx(u, alpha), |dir| ggx_pdf(dir, alpha));
+			test_inverse(|u| ggx(u, alpha), |dir| invert_ggx(dir, alpha));
+			test_directions(|u| beckmann(u, alpha), |dir| beckmann_pdf(dir, alpha));
+			test_inverse(|u| beckmann(u, alpha), |dir| invert_beckmann(dir, alpha));
+		}
+	}
+
+	#[test]
+	fn disk() {
+		// Bin in r^2 and the azimuth, which are uniform.
+		let mut observed = vec![0f64; Z_BINS * PHI_BINS];
+		for u in samples() {
+			let point = concentric_disk(u);
+			let r2 = point.x() * point.x() + point.y() * point.y();
+			assert!(r2 <= 1.0001f32);
+			let phi = invert_phi(Direction::new(point.x(), point.y(), 0f32));
+			let r2 = ((r2 * Z_BINS as f32) as usize).min(Z_BINS - 1);
+			observed[r2 * PHI_BINS + (phi * PHI_BINS as f32) as usize] += 1f64;
+
+			let inverse = invert_concentric_disk(point);
+			assert!((inverse.0 - u.0).abs() < 0.001f32, "{:?} {:?}", u, inverse);
+			assert!((inverse.1 - u.1).abs() < 0.001f32, "{:?} {:?}", u, inverse);
+		}
+
+		let expected = vec![SAMPLES as f64 / (Z_BINS * PHI_BINS) as f64; Z_BINS * PHI_BINS];
+		chi_square(&observed, &expected);
+		assert_eq!(concentric_disk_pdf() * PI, 1f32);
+	}
+
+	#[test]
+	fn triangle() {
+		// Bin in u and v / (1 - u): u has a density of 2 * (1 - u), and v / (1 - u) is uniform.
+		let mut observed = vec![0f64; Z_BINS * PHI_BINS];
+		let mut expected = vec![0f64; Z_BINS * PHI_BINS];
+		for u in samples() {
+			let (b1, b2) = uniform_triangle(u);
+			assert!(b1 >= 0f32 && b2 >= 0f32 && b1 + b2 <= 1.0001f32);
+			let t = if b1 < 1f32 { b2 / (1f32 - b1) } else { 0f32 };
+			let b1 = ((b1 * Z_BINS as f32) as usize).min(Z_BINS - 1);
+			let t = ((t * PHI_BINS as f32) as usize).min(PHI_BINS - 1);
+			observed[b1 * PHI_BINS + t] += 1f64;
+
+			let inverse = invert_uniform_triangle(uniform_triangle(u));
+			assert!((inverse.0 - u.0).abs() < 0.001f32, "{:?} {:?}", u, inverse);
+			assert!((inverse.1 - u.1).abs() < 0.001f32, "{:?} {:?}", u, inverse);
+		}
+		for b1 in 0..Z_BINS {
+			let (low, high) = (b1 as f64 / Z_BINS as f64, (b1 + 1) as f64 / Z_BINS as f64);
+			let fraction = (1f64 - low).powi(2) - (1f64 - high).powi(2);
+			for t in 0..PHI_BINS {
+				expected[b1 * PHI_BINS + t] = fraction / PHI_BINS as f64 * SAMPLES as f64;
+			}
+		}
+		chi_square(&observed, &expected);
+
+		let pdf = uniform_triangle_pdf(
+			Point::new(0f32, 0f32, 0f32),
+			Point::new(2f32, 0f32, 0f32),
+			Point::new(0f32, 2f32, 0f32),
+		);
+		assert_eq!(pdf, 0.5f32);
+	}
+}
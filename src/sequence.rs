@@ -0,0 +1,433 @@
+//! Deterministic low-discrepancy sequences, for filling sample buffers.
+//!
+//! Every generator implements [`Sequence`], which produces samples in `[0, 1)^2`, or [`Point`]s in the unit cube or
+//! a [`Bounds3`]. The two-dimensional samples can be fed straight into the warps in [`sampling`](crate::sampling).
+
+use crate::{bounds::Bounds3, direction::Direction, point::Point};
+
+/// The largest `f32` below 1.
+const ONE_MINUS_EPSILON: f32 = 1f32 - f32::EPSILON / 2f32;
+
+/// A generator of samples, indexed from 0.
+pub trait Sequence {
+	/// Get the sample at `index`, in `[0, 1)^2`.
+	fn sample(&self, index: u32) -> (f32, f32);
+
+	/// Get the sample at `index` as a [`Point`] in the unit cube.
+	fn sample_point(&self, index: u32) -> Point;
+
+	/// Fill `out` with the samples starting from `start`.
+	fn fill(&self, start: u32, out: &mut [(f32, f32)]) {
+		for (idx, sample) in out.iter_mut().enumerate() {
+			*sample = self.sample(start.wrapping_add(idx as u32));
+		}
+	}
+
+	/// Fill `out` with the [`Point`]s starting from `start`, mapped into `bounds`.
+	fn fill_points(&self, start: u32, bounds: Bounds3, out: &mut [Point]) {
+		for (idx, point) in out.iter_mut().enumerate() {
+			let sample = self.sample_point(start.wrapping_add(idx as u32));
+			*point = bounds.lerp(Direction::new(sample.x(), sample.y(), sample.z()));
+		}
+	}
+}
+
+/// The first 32 primes, used as the bases of the [`Halton`] sequence.
+pub const PRIMES: [u32; 32] = [
+	2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97, 101, 103, 107, 109,
+	113, 127, 131,
+];
+
+/// Mirror the digits of `index` in `base` around the radix point.
+pub fn radical_inverse(base: u32, mut index: u64) -> f32 {
+	let base = base as u64;
+	let inverse_base = 1f64 / base as f64;
+	let mut value = 0f64;
+	let mut scale = inverse_base;
+	while index > 0 {
+		let next = index / base;
+		value += (index - next * base) as f64 * scale;
+		scale *= inverse_base;
+		index = next;
+	}
+	(value as f32).min(ONE_MINUS_EPSILON)
+}
+
+#[inline(always)]
+/// Mirror the bits of `index` around the radix point, which is the radical inverse in base 2.
+pub fn van_der_corput(index: u32) -> f32 { to_f32(index.reverse_bits()) }
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+/// The Halton sequence, which uses the radical inverse in a different prime base for each dimension.
+pub struct Halton {
+	/// The first dimension to use, which is the index into [`PRIMES`].
+	pub dimension: usize,
+}
+
+impl Halton {
+	#[inline(always)]
+	/// Create a [`Halton`] sequence starting at `dimension`. Panics if it leaves fewer than three dimensions.
+	pub const fn new(dimension: usize) -> Self {
+		assert!(dimension + 3 <= PRIMES.len());
+		Self { dimension }
+	}
+
+	#[inline(always)]
+	fn get(self, index: u32, dimension: usize) -> f32 {
+		radical_inverse(PRIMES[self.dimension + dimension], index as u64)
+	}
+}
+
+impl Sequence for Halton {
+	#[inline(always)]
+	fn sample(&self, index: u32) -> (f32, f32) { (self.get(index, 0), self.get(index, 1)) }
+
+	#[inline(always)]
+	fn sample_point(&self, index: u32) -> Point {
+		Point::new(self.get(index, 0), self.get(index, 1), self.get(index, 2))
+	}
+}
+
+/// The number of dimensions of the [`Sobol`] sequence.
+pub const SOBOL_DIMENSIONS: usize = 16;
+
+/// The degree, coefficients, and initial direction numbers of the primitive polynomials for dimensions after the
+/// first, from 'Constructing Sobol Sequences with Better Two-Dimensional Projections' (Joe and Kuo 2008).
+const JOE_KUO: [(usize, u32, [u32; 6]); SOBOL_DIMENSIONS - 1] = [
+	(1, 0, [1, 0, 0, 0, 0, 0]),
+	(2, 1, [1, 3, 0, 0, 0, 0]),
+	(3, 1, [1, 3, 1, 0, 0, 0]),
+	(3, 2, [1, 1, 1, 0, 0, 0]),
+	(4, 1, [1, 1, 3, 3, 0, 0]),
+	(4, 4, [1, 3, 5, 13, 0, 0]),
+	(5, 2, [1, 1, 5, 5, 17, 0]),
+	(5, 4, [1, 1, 5, 5, 5, 0]),
+	(5, 7, [1, 1, 7, 11, 19, 0]),
+	(5, 11, [1, 1, 5, 1, 1, 0]),
+	(5, 13, [1, 1, 1, 3, 11, 0]),
+	(5, 14, [1, 3, 5, 5, 31, 0]),
+	(6, 1, [1, 3, 3, 9, 7, 49]),
+	(6, 13, [1, 1, 1, 15, 21, 21]),
+	(6, 16, [1, 3, 1, 13, 27, 49]),
+];
+
+/// The generator matrices of each dimension, as 32 direction numbers each.
+const SOBOL_MATRICES: [[u32; 32]; SOBOL_DIMENSIONS] = sobol_matrices();
+
+const fn sobol_matrices() -> [[u32; 32]; SOBOL_DIMENSIONS] {
+	let mut matrices = [[0u32; 32]; SOBOL_DIMENSIONS];
+	let mut bit = 0;
+	while bit < 32 {
+		matrices[0][bit] = 1 << (31 - bit);
+		bit += 1;
+	}
+
+	let mut dimension = 1;
+	while dimension < SOBOL_DIMENSIONS {
+		let (degree, coefficients, initial) = JOE_KUO[dimension - 1];
+		let matrix = &mut matrices[dimension];
+		let mut bit = 0;
+		while bit < 32 {
+			if bit < degree {
+				matrix[bit] = initial[bit] << (31 - bit);
+			} else {
+				let mut value = matrix[bit - degree] ^ (matrix[bit - degree] >> degree);
+				let mut term = 1;
+				while term < degree {
+					if (coefficients >> (degree - 1 - term)) & 1 == 1 {
+						value ^= matrix[bit - term];
+					}
+					term += 1;
+				}
+				matrix[bit] = value;
+			}
+			bit += 1;
+		}
+		dimension += 1;
+	}
+	matrices
+}
+
+#[inline(always)]
+/// Get the bits of dimension `dimension` of the [`Sobol`] sample at `index`.
+pub const fn sobol_bits(index: u32, dimension: usize) -> u32 {
+	let matrix = &SOBOL_MATRICES[dimension];
+	let mut index = index;
+	let mut bits = 0;
+	let mut bit = 0;
+	while index != 0 {
+		if index & 1 == 1 {
+			bits ^= matrix[bit];
+		}
+		index >>= 1;
+		bit += 1;
+	}
+	bits
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+/// The Sobol sequence, with the direction numbers of Joe and Kuo.
+///
+/// The first two dimensions form a (0, 2)-sequence, so every aligned block of `2^m` samples is stratified in every
+/// elementary interval of area `2^-m`.
+pub struct Sobol {
+	/// The first dimension to use.
+	pub dimension: usize,
+}
+
+impl Sobol {
+	#[inline(always)]
+	/// Create a [`Sobol`] sequence starting at `dimension`. Panics if it leaves fewer than three dimensions.
+	pub const fn new(dimension: usize) -> Self {
+		assert!(dimension + 3 <= SOBOL_DIMENSIONS);
+		Self { dimension }
+	}
+}
+
+impl Sequence for Sobol {
+	#[inline(always)]
+	fn sample(&self, index: u32) -> (f32, f32) {
+		(
+			to_f32(sobol_bits(index, self.dimension)),
+			to_f32(sobol_bits(index, self.dimension + 1)),
+		)
+	}
+
+	#[inline(always)]
+	fn sample_point(&self, index: u32) -> Point {
+		Point::new(
+			to_f32(sobol_bits(index, self.dimension)),
+			to_f32(sobol_bits(index, self.dimension + 1)),
+			to_f32(sobol_bits(index, self.dimension + 2)),
+		)
+	}
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+/// The [`Sobol`] sequence with Owen scrambling, which randomizes it while keeping its stratification.
+///
+/// Uses the hash-based scrambling from 'Practical Hash-based Owen Scrambling' (Burley 2020). Different seeds give
+/// independent sequences.
+pub struct OwenSobol {
+	/// The first dimension to use.
+	pub dimension: usize,
+	/// The seed of the scrambling.
+	pub seed: u32,
+}
+
+impl OwenSobol {
+	#[inline(always)]
+	/// Create an [`OwenSobol`] sequence starting at `dimension`. Panics if it leaves fewer than three dimensions.
+	pub const fn new(dimension: usize, seed: u32) -> Self {
+		assert!(dimension + 3 <= SOBOL_DIMENSIONS);
+		Self { dimension, seed }
+	}
+
+	#[inline(always)]
+	fn get(self, index: u32, dimension: usize) -> f32 {
+		let dimension = self.dimension + dimension;
+		to_f32(owen_scramble(
+			sobol_bits(index, dimension),
+			hash(self.seed ^ hash(dimension as u32)),
+		))
+	}
+}
+
+impl Sequence for OwenSobol {
+	#[inline(always)]
+	fn sample(&self, index: u32) -> (f32, f32) { (self.get(index, 0), self.get(index, 1)) }
+
+	#[inline(always)]
+	fn sample_point(&self, index: u32) -> Point {
+		Point::new(self.get(index, 0), self.get(index, 1), self.get(index, 2))
+	}
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+/// The R2 additive recurrence, from 'The Unreasonable Effectiveness of Quasirandom Sequences' (Roberts 2018), which
+/// has no power-of-two structure, so any number of samples is well spread out.
+///
+/// [`Sequence::sample_point`] uses the three-dimensional version of the recurrence.
+pub struct R2;
+
+/// The fractional parts of the R2 and R3 step sizes, in 0.32 fixed point.
+const R2_STEPS: [u32; 2] = [3242174889, 2447445414];
+const R3_STEPS: [u32; 3] = [3518319155, 2882110345, 2360945575];
+
+impl R2 {
+	#[inline(always)]
+	fn get(index: u32, step: u32) -> f32 { to_f32((1u32 << 31).wrapping_add(index.wrapping_mul(step))) }
+}
+
+impl Sequence for R2 {
+	#[inline(always)]
+	fn sample(&self, index: u32) -> (f32, f32) { (Self::get(index, R2_STEPS[0]), Self::get(index, R2_STEPS[1])) }
+
+	#[inline(always)]
+	fn sample_point(&self, index: u32) -> Point {
+		Point::new(
+			Self::get(index, R3_STEPS[0]),
+			Self::get(index, R3_STEPS[1]),
+			Self::get(index, R3_STEPS[2]),
+		)
+	}
+}
+
+#[inline(always)]
+/// Shuffle the order of sample indices with a nested uniform scramble.
+///
+/// The first `2^m` shuffled indices are a permuted, aligned block of `2^m` indices, so a shuffled (0, 2)-sequence
+/// like [`Sobol`] stays stratified. Giving each pixel its own seed decorrelates neighbouring pixels.
+pub const fn shuffle(index: u32, seed: u32) -> u32 { owen_scramble(index, hash(seed)) }
+
+#[inline(always)]
+/// Owen scramble the bits of `x`, flipping each bit depending on the bits above it.
+const fn owen_scramble(x: u32, seed: u32) -> u32 {
+	// The improved Laine-Karras hash by Nathan Vegdahl, where every bit only depends on the bits below it.
+	let mut x = x.reverse_bits();
+	x ^= x.wrapping_mul(0x3d20adea);
+	x = x.wrapping_add(seed);
+	x = x.wrapping_mul((seed >> 16) | 1);
+	x ^= x.wrapping_mul(0x05526c56);
+	x ^= x.wrapping_mul(0x53a22864);
+	x.reverse_bits()
+}
+
+#[inline(always)]
+/// Mix the bits of `x`, using the finalizer of MurmurHash3.
+const fn hash(mut x: u32) -> u32 {
+	x ^= x >> 16;
+	x = x.wrapping_mul(0x85ebca6b);
+	x ^= x >> 13;
+	x = x.wrapping_mul(0xc2b2ae35);
+	x ^ (x >> 16)
+}
+
+#[inline(always)]
+/// Convert the bits of a 0.32 fixed point number in [0, 1) to an `f32`, rounding down.
+fn to_f32(bits: u32) -> f32 { (bits >> 8) as f32 * (1f32 / (1u32 << 24) as f32) }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::shapes::Contains;
+
+	/// Check that every elementary interval of area `1 / count` in two dimensions has exactly one sample.
+	fn is_net(samples: &[(f32, f32)]) -> bool {
+		let count = samples.len();
+		let log = count.trailing_zeros();
+		(0..=log).all(|x_log| {
+			let (x_cells, y_cells) = (1usize << x_log, 1usize << (log - x_log));
+			let mut seen = vec![false; count];
+			samples.iter().all(|&(x, y)| {
+				let cell = (x * x_cells as f32) as usize * y_cells + (y * y_cells as f32) as usize;
+				!std::mem::replace(&mut seen[cell], true)
+			})
+		})
+	}
+
+	#[test]
+	fn radical_inverse_digits() {
+		assert_eq!(radical_inverse(2, 0), 0f32);
+		assert_eq!(radical_inverse(2, 1), 0.5f32);
+		assert_eq!(radical_inverse(2, 6), 0.375f32);
+		assert_eq!(radical_inverse(3, 5), 7f32 / 9f32);
+		for idx in 0..100 {
+			assert_eq!(radical_inverse(2, idx), van_der_corput(idx as u32));
+		}
+		assert!(radical_inverse(131, u64::MAX) < 1f32);
+	}
+
+	#[test]
+	fn halton() {
+		let halton = Halton::new(0);
+		let mut samples = [(0f32, 0f32); 6];
+		halton.fill(0, &mut samples);
+		assert_eq!(samples[1], (0.5f32, 1f32 / 3f32));
+		assert_eq!(samples[5], (0.625f32, 7f32 / 9f32));
+		assert_eq!(halton.sample_point(1).z(), 0.2f32);
+	}
+
+	#[test]
+	fn sobol_stratification() {
+		// Every dimension on its own is stratified in blocks of every power of two.
+		for dimension in 0..SOBOL_DIMENSIONS {
+			for block in 0..4u32 {
+				let mut seen = vec![false; 256];
+				for idx in 0..256 {
+					let bits = sobol_bits(block * 256 + idx, dimension);
+					assert!(!std::mem::replace(&mut seen[(bits >> 24) as usize], true));
+				}
+			}
+		}
+
+		let sobol = Sobol::new(0);
+		let mut samples = [(0f32, 0f32); 256];
+		for start in [0, 256, 512].iter() {
+			sobol.fill(*start, &mut samples);
+			assert!(is_net(&samples));
+		}
+	}
+
+	#[test]
+	fn owen_sobol_stratification() {
+		let mut samples = [(0f32, 0f32); 64];
+		for seed in 0..8 {
+			let sequence = OwenSobol::new(0, seed);
+			sequence.fill(0, &mut samples);
+			assert!(is_net(&samples));
+		}
+		assert_ne!(OwenSobol::new(0, 1).sample(3), OwenSobol::new(0, 2).sample(3));
+		assert_ne!(OwenSobol::new(0, 1).sample(3), Sobol::new(0).sample(3));
+	}
+
+	#[test]
+	fn shuffled() {
+		let sobol = Sobol::new(0);
+		for seed in 0..8 {
+			let samples: Vec<_> = (0..16).map(|idx| sobol.sample(shuffle(idx, seed))).collect();
+			assert!(is_net(&samples));
+		}
+
+		let mut indices: Vec<_> = (0..1024).map(|idx| shuffle(idx, 7)).collect();
+		indices.sort_unstable();
+		indices.dedup();
+		assert_eq!(indices.len(), 1024);
+	}
+
+	#[test]
+	fn r2() {
+		// Every prefix is spread out, so no two samples get too close.
+		let mut samples = [(0f32, 0f32); 256];
+		R2.fill(0, &mut samples);
+		for (idx, &(x1, y1)) in samples.iter().enumerate() {
+			assert!((0f32..1f32).contains(&x1) && (0f32..1f32).contains(&y1));
+			for &(x2, y2) in samples[..idx].iter() {
+				let (dx, dy) = ((x1 - x2).abs(), (y1 - y2).abs());
+				let (dx, dy) = (dx.min(1f32 - dx), dy.min(1f32 - dy));
+				assert!(dx * dx + dy * dy > 0.1f32 / 256f32);
+			}
+		}
+	}
+
+	#[test]
+	fn points() {
+		let bounds = Bounds3::new(Point::new(-1f32, 2f32, 0f32), Point::new(3f32, 4f32, 1f32));
+		let mut points = [Point::new(0f32, 0f32, 0f32); 32];
+		for sequence in [
+			&Halton::new(0) as &dyn Sequence,
+			&Sobol::new(0),
+			&OwenSobol::new(0, 3),
+			&R2,
+		]
+		.iter()
+		{
+			sequence.fill_points(5, bounds, &mut points);
+			for (idx, point) in points.iter().enumerate() {
+				let unit = sequence.sample_point(5 + idx as u32);
+				assert!(bounds.contains(*point));
+				assert!((*point - bounds.lerp(Direction::new(unit.x(), unit.y(), unit.z()))).length() < 0.0001f32);
+			}
+		}
+	}
+}
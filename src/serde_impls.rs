@@ -0,0 +1,421 @@
+//! `serde` support.
+//!
+//! Human-readable formats get named fields, like `{ "x": 1.0, "y": 2.0, "z": 3.0 }` for a [`Point`]. Other formats get
+//! plain arrays of `f32`s. A [`Transform`] only stores its matrix, and recomputes the inverse when deserialized.
+//!
+//! Deserializing a [`Rotation`] normalizes it, and fails if its length is zero or not finite. Deserializing a
+//! [`Transform`] fails if its matrix is not invertible.
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+	base::{Matrix, Quaternion, Vector},
+	bounds::Bounds3,
+	direction::Direction,
+	normal::Normal,
+	point::Point,
+	rotation::Rotation,
+	transform::Transform,
+};
+
+/// Implement `Serialize` and `Deserialize` for a type with x, y, z, and w components. `$from` returns an error
+/// message for invalid values.
+macro_rules! impl_xyzw {
+	($ty:ty, $fields:ident, $name:literal, $to:expr, $from:expr) => {
+		#[derive(Serialize, Deserialize)]
+		#[serde(rename = $name)]
+		struct $fields {
+			x: f32,
+			y: f32,
+			z: f32,
+			w: f32,
+		}
+
+		impl Serialize for $ty {
+			fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+				let [x, y, z, w]: [f32; 4] = ($to)(*self);
+				if serializer.is_human_readable() {
+					$fields { x, y, z, w }.serialize(serializer)
+				} else {
+					[x, y, z, w].serialize(serializer)
+				}
+			}
+		}
+
+		impl<'de> Deserialize<'de> for $ty {
+			fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+				let values = if deserializer.is_human_readable() {
+					let $fields { x, y, z, w } = $fields::deserialize(deserializer)?;
+					[x, y, z, w]
+				} else {
+					<[f32; 4]>::deserialize(deserializer)?
+				};
+				let from: fn([f32; 4]) -> Result<$ty, &'static str> = $from;
+				from(values).map_err(D::Error::custom)
+			}
+		}
+	};
+}
+
+/// Implement `Serialize` and `Deserialize` for a type with x, y, and z components.
+macro_rules! impl_xyz {
+	($ty:ident, $fields:ident, $name:literal) => {
+		#[derive(Serialize, Deserialize)]
+		#[serde(rename = $name)]
+		struct $fields {
+			x: f32,
+			y: f32,
+			z: f32,
+		}
+
+		impl Serialize for $ty {
+			fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+				let (x, y, z) = (self.x(), self.y(), self.z());
+				if serializer.is_human_readable() {
+					$fields { x, y, z }.serialize(serializer)
+				} else {
+					[x, y, z].serialize(serializer)
+				}
+			}
+		}
+
+		impl<'de> Deserialize<'de> for $ty {
+			fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+				let [x, y, z] = if deserializer.is_human_readable() {
+					let $fields { x, y, z } = $fields::deserialize(deserializer)?;
+					[x, y, z]
+				} else {
+					<[f32; 3]>::deserialize(deserializer)?
+				};
+				Ok($ty::new(x, y, z))
+			}
+		}
+	};
+}
+
+impl_xyzw!(
+	Vector,
+	VectorFields,
+	"Vector",
+	|vec: Vector| vec.into(),
+	|val: [f32; 4]| Ok(Vector::from(val))
+);
+impl_xyzw!(
+	Quaternion,
+	QuaternionFields,
+	"Quaternion",
+	|quat: Quaternion| quat.0.into(),
+	|val: [f32; 4]| Ok(Quaternion(val.into()))
+);
+impl_xyzw!(
+	Rotation,
+	RotationFields,
+	"Rotation",
+	|rot: Rotation| rot.0 .0.into(),
+	|val: [f32; 4]| {
+		let quat = Quaternion(val.into());
+		let length_square = Quaternion::dot(quat, quat);
+		if length_square.is_finite() && length_square > 0f32 {
+			Ok(Rotation(quat.normalize()))
+		} else {
+			Err("rotation must have a finite, non-zero length")
+		}
+	}
+);
+
+impl_xyz!(Point, PointFields, "Point");
+impl_xyz!(Direction, DirectionFields, "Direction");
+impl_xyz!(Normal, NormalFields, "Normal");
+
+impl Serialize for Matrix {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let rows: [[f32; 4]; 4] = [
+			self.get_row(0).into(),
+			self.get_row(1).into(),
+			self.get_row(2).into(),
+			self.get_row(3).into(),
+		];
+		rows.serialize(serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for Matrix {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		<[[f32; 4]; 4]>::deserialize(deserializer).map(Self::rows)
+	}
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Transform")]
+struct TransformFields {
+	matrix: Matrix,
+}
+
+impl Serialize for Transform {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		if serializer.is_human_readable() {
+			TransformFields { matrix: self.matrix }.serialize(serializer)
+		} else {
+			self.matrix.serialize(serializer)
+		}
+	}
+}
+
+impl<'de> Deserialize<'de> for Transform {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let matrix = if deserializer.is_human_readable() {
+			TransformFields::deserialize(deserializer)?.matrix
+		} else {
+			Matrix::deserialize(deserializer)?
+		};
+		let det = matrix.det();
+		if det.is_finite() && det != 0f32 {
+			Ok(matrix.into())
+		} else {
+			Err(D::Error::custom("transform matrix must be invertible"))
+		}
+	}
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Bounds3")]
+struct Bounds3Fields {
+	min: Point,
+	max: Point,
+}
+
+impl Serialize for Bounds3 {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		if serializer.is_human_readable() {
+			Bounds3Fields {
+				min: self.min,
+				max: self.max,
+			}
+			.serialize(serializer)
+		} else {
+			(self.min, self.max).serialize(serializer)
+		}
+	}
+}
+
+impl<'de> Deserialize<'de> for Bounds3 {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let (min, max) = if deserializer.is_human_readable() {
+			let Bounds3Fields { min, max } = Bounds3Fields::deserialize(deserializer)?;
+			(min, max)
+		} else {
+			<(Point, Point)>::deserialize(deserializer)?
+		};
+		Ok(Self { min, max })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_test::{assert_de_tokens_error, assert_ser_tokens, assert_tokens, Compact, Configure, Token};
+
+	use super::*;
+	use crate::{
+		coordinate_system::CoordinateSystem,
+		rotation::{EulerAngles, RotationOrder},
+	};
+
+	fn floats(values: &[f32]) -> impl Iterator<Item = Token> + '_ { values.iter().map(|&val| Token::F32(val)) }
+
+	fn fields(name: &'static str, names: &[&'static str], values: &[f32]) -> Vec<Token> {
+		let mut tokens = vec![Token::Struct { name, len: names.len() }];
+		for (&field, value) in names.iter().zip(floats(values)) {
+			tokens.push(Token::Str(field));
+			tokens.push(value);
+		}
+		tokens.push(Token::StructEnd);
+		tokens
+	}
+
+	fn array(values: &[f32]) -> Vec<Token> {
+		let mut tokens = vec![Token::Tuple { len: values.len() }];
+		tokens.extend(floats(values));
+		tokens.push(Token::TupleEnd);
+		tokens
+	}
+
+	#[test]
+	fn vectors() {
+		let values = [1f32, -2f32, 3.5f32, 4f32];
+		let vec = Vector::from(values);
+		assert_tokens(&vec.readable(), &fields("Vector", &["x", "y", "z", "w"], &values));
+		assert_tokens(&vec.compact(), &array(&values));
+
+		let quat = Quaternion(vec);
+		assert_tokens(&quat.readable(), &fields("Quaternion", &["x", "y", "z", "w"], &values));
+		assert_tokens(&quat.compact(), &array(&values));
+
+		let unit = [0.5f32, -0.5f32, 0.5f32, 0.5f32];
+		let rot = Rotation(Quaternion(unit.into()));
+		assert_tokens(&rot.readable(), &fields("Rotation", &["x", "y", "z", "w"], &unit));
+		assert_tokens(&rot.compact(), &array(&unit));
+
+		let values = [1f32, -2f32, 3.5f32];
+		let point = Point::new(1f32, -2f32, 3.5f32);
+		assert_tokens(&point.readable(), &fields("Point", &["x", "y", "z"], &values));
+		assert_tokens(&point.compact(), &array(&values));
+
+		let dir = Direction::new(1f32, -2f32, 3.5f32);
+		assert_tokens(&dir.readable(), &fields("Direction", &["x", "y", "z"], &values));
+		assert_tokens(&dir.compact(), &array(&values));
+
+		let normal = Normal::new(1f32, -2f32, 3.5f32);
+		assert_tokens(&normal.readable(), &fields("Normal", &["x", "y", "z"], &values));
+		assert_tokens(&normal.compact(), &array(&values));
+	}
+
+	#[test]
+	fn bounds() {
+		let bounds = Bounds3::new(Point::new(-1f32, 0f32, 2f32), Point::new(3f32, 4.5f32, 5f32));
+		let mut readable = vec![
+			Token::Struct {
+				name: "Bounds3",
+				len: 2,
+			},
+			Token::Str("min"),
+		];
+		readable.extend(fields("Point", &["x", "y", "z"], &[-1f32, 0f32, 2f32]));
+		readable.push(Token::Str("max"));
+		readable.extend(fields("Point", &["x", "y", "z"], &[3f32, 4.5f32, 5f32]));
+		readable.push(Token::StructEnd);
+		assert_tokens(&bounds.readable(), &readable);
+
+		let mut compact = vec![Token::Tuple { len: 2 }];
+		compact.extend(array(&[-1f32, 0f32, 2f32]));
+		compact.extend(array(&[3f32, 4.5f32, 5f32]));
+		compact.push(Token::TupleEnd);
+		assert_tokens(&bounds.compact(), &compact);
+
+		// JSON has no infinities, so only check binary formats.
+		let empty = Bounds3::empty();
+		let back: Bounds3 = bincode::deserialize(&bincode::serialize(&empty).unwrap()).unwrap();
+		assert_eq!(back, empty);
+		assert!(back.is_empty());
+	}
+
+	#[test]
+	fn transforms() {
+		let transform =
+			Transform::translate(Direction::new(1f32, 2f32, 3f32)) * Transform::scale(Direction::new(2f32, 2f32, 2f32));
+		let mut rows = vec![Token::Tuple { len: 4 }];
+		for idx in 0..4 {
+			let row: [f32; 4] = transform.matrix.get_row(idx).into();
+			rows.extend(array(&row));
+		}
+		rows.push(Token::TupleEnd);
+		assert_tokens(&transform.matrix.readable(), &rows);
+		assert_tokens(&transform.matrix.compact(), &rows);
+
+		let mut readable = vec![
+			Token::Struct {
+				name: "Transform",
+				len: 1,
+			},
+			Token::Str("matrix"),
+		];
+		readable.extend(rows.iter().cloned());
+		readable.push(Token::StructEnd);
+		assert_ser_tokens(&transform.readable(), &readable);
+		assert_ser_tokens(&transform.compact(), &rows);
+
+		let json: Transform = serde_json::from_str(&serde_json::to_string(&transform).unwrap()).unwrap();
+		let binary: Transform = bincode::deserialize(&bincode::serialize(&transform).unwrap()).unwrap();
+		for back in [json, binary].iter() {
+			assert_eq!(back.matrix, transform.matrix);
+			assert_eq!(back.inverse, transform.inverse);
+		}
+	}
+
+	#[test]
+	fn invalid() {
+		let rot: Rotation = serde_json::from_str(r#"{ "x": 0, "y": 0, "z": 3, "w": 4 }"#).unwrap();
+		assert_eq!(<[f32; 4]>::from(rot.0 .0), [0f32, 0f32, 0.6f32, 0.8f32]);
+		let error = "rotation must have a finite, non-zero length";
+		assert_de_tokens_error::<Compact<Rotation>>(&array(&[0f32; 4]), error);
+		assert_de_tokens_error::<Compact<Rotation>>(&array(&[f32::NAN, 0f32, 0f32, 1f32]), error);
+		assert_de_tokens_error::<Compact<Rotation>>(&array(&[f32::MAX, f32::MAX, 0f32, 0f32]), error);
+
+		let singular = |row: [f32; 4]| {
+			let mut tokens = vec![Token::Tuple { len: 4 }];
+			for _ in 0..4 {
+				tokens.extend(array(&row));
+			}
+			tokens.push(Token::TupleEnd);
+			tokens
+		};
+		let error = "transform matrix must be invertible";
+		assert_de_tokens_error::<Compact<Transform>>(&singular([0f32; 4]), error);
+		assert_de_tokens_error::<Compact<Transform>>(&singular([1f32, 2f32, 3f32, 4f32]), error);
+		assert_de_tokens_error::<Compact<Transform>>(&singular([f32::INFINITY, 0f32, 0f32, 1f32]), error);
+	}
+
+	#[test]
+	fn derived() {
+		let angles = EulerAngles {
+			pitch: 0.5f32,
+			yaw: 1f32,
+			roll: -0.25f32,
+			order: RotationOrder::RYP,
+		};
+		let mut tokens = vec![Token::Struct {
+			name: "EulerAngles",
+			len: 4,
+		}];
+		for (&field, value) in ["pitch", "yaw", "roll"].iter().zip(floats(&[0.5f32, 1f32, -0.25f32])) {
+			tokens.push(Token::Str(field));
+			tokens.push(value);
+		}
+		tokens.extend(vec![
+			Token::Str("order"),
+			Token::UnitVariant {
+				name: "RotationOrder",
+				variant: "RYP",
+			},
+			Token::StructEnd,
+		]);
+		assert_ser_tokens(&angles, &tokens);
+
+		let json: EulerAngles = serde_json::from_str(&serde_json::to_string(&angles).unwrap()).unwrap();
+		let binary: EulerAngles = bincode::deserialize(&bincode::serialize(&angles).unwrap()).unwrap();
+		for back in [json, binary].iter() {
+			assert_eq!(
+				(back.pitch, back.yaw, back.roll),
+				(angles.pitch, angles.yaw, angles.roll)
+			);
+			assert!(matches!(back.order, RotationOrder::RYP));
+		}
+
+		let system = CoordinateSystem {
+			right: Direction::new(1f32, 0f32, 0f32),
+			forward: Direction::new(0f32, 0f32, 1f32),
+			up: Direction::new(0f32, 1f32, 0f32),
+		};
+		let mut tokens = vec![Token::Struct {
+			name: "CoordinateSystem",
+			len: 3,
+		}];
+		for (&field, values) in ["right", "forward", "up"]
+			.iter()
+			.zip([[1f32, 0f32, 0f32], [0f32, 0f32, 1f32], [0f32, 1f32, 0f32]].iter())
+		{
+			tokens.push(Token::Str(field));
+			tokens.extend(array(values));
+		}
+		tokens.push(Token::StructEnd);
+		assert_ser_tokens(&system.compact(), &tokens);
+
+		let json: CoordinateSystem = serde_json::from_str(&serde_json::to_string(&system).unwrap()).unwrap();
+		let binary: CoordinateSystem = bincode::deserialize(&bincode::serialize(&system).unwrap()).unwrap();
+		for back in [json, binary].iter() {
+			assert_eq!(
+				(back.right, back.forward, back.up),
+				(system.right, system.forward, system.up)
+			);
+		}
+	}
+}
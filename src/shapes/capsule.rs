@@ -0,0 +1,53 @@
+//! Capsules.
+
+use std::fmt::{Debug, Display};
+
+use crate::{bounds::Bounds3, closest, direction::Direction, point::Point};
+
+#[derive(Copy, Clone, PartialEq)]
+/// A solid capsule: all the [`Point`]s within `radius` of the segment from `start` to `end`.
+pub struct Capsule {
+	/// The center of one end of the [`Capsule`].
+	pub start: Point,
+	/// The center of the other end of the [`Capsule`].
+	pub end: Point,
+	/// The radius of the [`Capsule`].
+	pub radius: f32,
+}
+
+impl Debug for Capsule {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Capsule [{:?}, {:?}, {}]", self.start, self.end, self.radius)
+	}
+}
+
+impl Display for Capsule {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Capsule [{}, {}, {}]", self.start, self.end, self.radius)
+	}
+}
+
+impl Capsule {
+	#[inline(always)]
+	/// Create a new [`Capsule`].
+	pub const fn new(start: Point, end: Point, radius: f32) -> Self { Self { start, end, radius } }
+
+	#[inline(always)]
+	/// Get the closest [`Point`] on the central segment of the [`Capsule`] to `point`.
+	pub fn closest_on_segment(self, point: Point) -> Point {
+		closest::point_segment(point, self.start, self.end).point_b
+	}
+
+	#[inline(always)]
+	/// Get the smallest [`Bounds3`] containing the [`Capsule`].
+	pub fn bounds(self) -> Bounds3 {
+		let offset = Direction::new(self.radius, self.radius, self.radius);
+		let segment = Bounds3::new(self.start, self.end);
+		Bounds3 {
+			min: segment.min - offset,
+			max: segment.max + offset,
+		}
+	}
+}
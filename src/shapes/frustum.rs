@@ -0,0 +1,387 @@
+//! View frustums.
+
+use std::fmt::{Debug, Display};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use super::{Plane, Sphere};
+#[cfg(feature = "rayon")]
+use crate::PAR_CHUNK;
+use crate::{
+	base::{Matrix, Vector},
+	bounds::Bounds3,
+	point::Point,
+	transform::Transform,
+};
+
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+/// A view frustum, bounded by six [`Plane`]s.
+pub struct Frustum {
+	planes: [Plane; 6],
+	corners: [Point; 8],
+}
+
+impl Debug for Frustum {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { write!(f, "Frustum {:?}", self.planes) }
+}
+
+impl Display for Frustum {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"Frustum [{}, {}, {}, {}, {}, {}]",
+			self.planes[0], self.planes[1], self.planes[2], self.planes[3], self.planes[4], self.planes[5]
+		)
+	}
+}
+
+impl From<Transform> for Frustum {
+	#[inline(always)]
+	fn from(val: Transform) -> Self { Self::new(val) }
+}
+
+impl Frustum {
+	#[inline(always)]
+	/// Extract the [`Frustum`] from a view-projection [`Transform`], using the Gribb-Hartmann method.
+	/// The projection must map the visible volume to x and y in the range [-1, 1], and depth in the range [0, 1], like
+	/// [`Transform::perspective`].
+	pub fn new(view_projection: Transform) -> Self {
+		let matrix = view_projection.matrix;
+		let x = matrix.get_column(0);
+		let y = matrix.get_column(1);
+		let z = matrix.get_column(2);
+		let w = matrix.get_column(3);
+
+		let planes = [w + x, w - x, w + y, w - y, z, w - z];
+		let mut corners = [Point::default(); 8];
+		for (idx, corner) in corners.iter_mut().enumerate() {
+			let clip = Vector::new(
+				if idx & 1 == 0 { -1f32 } else { 1f32 },
+				if idx & 2 == 0 { -1f32 } else { 1f32 },
+				if idx & 4 == 0 { 0f32 } else { 1f32 },
+				1f32,
+			) * view_projection.inverse;
+			*corner = Point(clip / clip.w());
+		}
+
+		Self {
+			planes: [
+				Plane(planes[0]).normalize(),
+				Plane(planes[1]).normalize(),
+				Plane(planes[2]).normalize(),
+				Plane(planes[3]).normalize(),
+				Plane(planes[4]).normalize(),
+				Plane(planes[5]).normalize(),
+			],
+			corners,
+		}
+	}
+
+	#[inline(always)]
+	/// Get the left, right, bottom, top, near, and far [`Plane`]s of the [`Frustum`], in that order.
+	/// Their normals point inwards.
+	pub const fn planes(&self) -> &[Plane; 6] { &self.planes }
+
+	#[inline(always)]
+	/// Get the eight corners of the [`Frustum`]. Bits 0, 1, and 2 of the index select the right, top, and far side.
+	pub const fn corners(&self) -> &[Point; 8] { &self.corners }
+
+	#[inline(always)]
+	/// Check if a [`Sphere`] is at least partially on the inner side of every [`Plane`] of the [`Frustum`].
+	///
+	/// This is the scalar reference for [`Frustum::cull_spheres`].
+	pub fn cull_sphere(&self, sphere: Sphere) -> bool {
+		let center = sphere.center();
+		self.planes
+			.iter()
+			.all(|plane| plane_distance(plane, center, sphere.radius()) >= 0f32)
+	}
+
+	#[inline(always)]
+	/// Check if a [`Bounds3`] is at least partially on the inner side of every [`Plane`] of the [`Frustum`].
+	///
+	/// This is the scalar reference for [`Frustum::cull_aabbs`].
+	pub fn cull_aabb(&self, bounds: Bounds3) -> bool {
+		let center = bounds.center();
+		let half = (bounds.max - bounds.min).0 * 0.5f32;
+		self.planes.iter().all(|plane| {
+			let normal = plane.0.abs();
+			let radius = half.x() * normal.x() + half.y() * normal.y() + half.z() * normal.z();
+			plane_distance(plane, center, radius) >= 0f32
+		})
+	}
+
+	/// Cull a slice of [`Sphere`]s against the [`Frustum`], writing `true` into `visible` for the ones that are at
+	/// least partially inside, and `false` for the rest.
+	///
+	/// Tests four [`Sphere`]s at a time. Gives the same results as [`Frustum::cull_sphere`].
+	///
+	/// # Panics
+	/// If `spheres` and `visible` have different lengths.
+	pub fn cull_spheres(&self, spheres: &[Sphere], visible: &mut [bool]) {
+		assert_eq!(
+			spheres.len(),
+			visible.len(),
+			"spheres and visible have different lengths"
+		);
+
+		let mut sphere_chunks = spheres.chunks_exact(4);
+		let mut visible_chunks = visible.chunks_exact_mut(4);
+		for (spheres, visible) in (&mut sphere_chunks).zip(&mut visible_chunks) {
+			// Transpose to x, y, z, and radius of each sphere.
+			let soa = Matrix::row_vectors([spheres[0].0, spheres[1].0, spheres[2].0, spheres[3].0]).transpose();
+			let radius = soa.get_row(3);
+
+			let mut nearest = Vector::splat(f32::INFINITY);
+			for plane in self.planes.iter() {
+				let distance = soa_dot(plane, soa) + radius;
+				nearest = Vector::min(nearest, distance);
+			}
+			write_mask(nearest, visible);
+		}
+
+		for (&sphere, visible) in sphere_chunks.remainder().iter().zip(visible_chunks.into_remainder()) {
+			*visible = self.cull_sphere(sphere);
+		}
+	}
+
+	/// Cull a slice of [`Bounds3`]s against the [`Frustum`], writing `true` into `visible` for the ones that are at
+	/// least partially inside, and `false` for the rest.
+	///
+	/// Tests four [`Bounds3`]s at a time. Gives the same results as [`Frustum::cull_aabb`].
+	///
+	/// # Panics
+	/// If `bounds` and `visible` have different lengths.
+	pub fn cull_aabbs(&self, bounds: &[Bounds3], visible: &mut [bool]) {
+		assert_eq!(bounds.len(), visible.len(), "bounds and visible have different lengths");
+
+		let mut bounds_chunks = bounds.chunks_exact(4);
+		let mut visible_chunks = visible.chunks_exact_mut(4);
+		for (bounds, visible) in (&mut bounds_chunks).zip(&mut visible_chunks) {
+			let center = Matrix::row_vectors([
+				bounds[0].center().0,
+				bounds[1].center().0,
+				bounds[2].center().0,
+				bounds[3].center().0,
+			])
+			.transpose();
+			let half = Matrix::row_vectors([
+				(bounds[0].max - bounds[0].min).0 * 0.5f32,
+				(bounds[1].max - bounds[1].min).0 * 0.5f32,
+				(bounds[2].max - bounds[2].min).0 * 0.5f32,
+				(bounds[3].max - bounds[3].min).0 * 0.5f32,
+			])
+			.transpose();
+
+			let mut nearest = Vector::splat(f32::INFINITY);
+			for plane in self.planes.iter() {
+				let normal = plane.0.abs();
+				let radius = half.get_row(0) * normal.x() + half.get_row(1) * normal.y() + half.get_row(2) * normal.z();
+				let distance = soa_dot(plane, center) + radius;
+				nearest = Vector::min(nearest, distance);
+			}
+			write_mask(nearest, visible);
+		}
+
+		for (&bounds, visible) in bounds_chunks.remainder().iter().zip(visible_chunks.into_remainder()) {
+			*visible = self.cull_aabb(bounds);
+		}
+	}
+
+	#[cfg(feature = "rayon")]
+	/// Cull a slice of [`Sphere`]s against the [`Frustum`] in parallel. Gives the same results as
+	/// [`Frustum::cull_spheres`].
+	///
+	/// # Panics
+	/// If `spheres` and `visible` have different lengths.
+	pub fn par_cull_spheres(&self, spheres: &[Sphere], visible: &mut [bool]) {
+		assert_eq!(
+			spheres.len(),
+			visible.len(),
+			"spheres and visible have different lengths"
+		);
+		spheres
+			.par_chunks(PAR_CHUNK)
+			.zip(visible.par_chunks_mut(PAR_CHUNK))
+			.for_each(|(spheres, visible)| self.cull_spheres(spheres, visible));
+	}
+
+	#[cfg(feature = "rayon")]
+	/// Cull a slice of [`Bounds3`]s against the [`Frustum`] in parallel. Gives the same results as
+	/// [`Frustum::cull_aabbs`].
+	///
+	/// # Panics
+	/// If `bounds` and `visible` have different lengths.
+	pub fn par_cull_aabbs(&self, bounds: &[Bounds3], visible: &mut [bool]) {
+		assert_eq!(bounds.len(), visible.len(), "bounds and visible have different lengths");
+		bounds
+			.par_chunks(PAR_CHUNK)
+			.zip(visible.par_chunks_mut(PAR_CHUNK))
+			.for_each(|(bounds, visible)| self.cull_aabbs(bounds, visible));
+	}
+}
+
+#[inline(always)]
+/// Get the signed distance of `center` from `plane`, plus `radius`. The operations are done in the same order as
+/// [`soa_dot`], so the scalar and batched culls round in the same way.
+fn plane_distance(plane: &Plane, center: Point, radius: f32) -> f32 {
+	center.x() * plane.0.x() + center.y() * plane.0.y() + center.z() * plane.0.z() + plane.0.w() + radius
+}
+
+#[inline(always)]
+/// Get the signed distances of four [`Point`]s, with their x, y, and z in the first three rows of `soa`, from `plane`.
+fn soa_dot(plane: &Plane, soa: Matrix) -> Vector {
+	soa.get_row(0) * plane.0.x()
+		+ soa.get_row(1) * plane.0.y()
+		+ soa.get_row(2) * plane.0.z()
+		+ Vector::splat(plane.0.w())
+}
+
+#[inline(always)]
+/// Write whether each component of `nearest` is non-negative into `visible`.
+fn write_mask(nearest: Vector, visible: &mut [bool]) {
+	for (idx, visible) in visible.iter_mut().enumerate() {
+		*visible = nearest.get(idx as u8) >= 0f32;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		direction::Direction,
+		random::{self, Pcg32, Rng},
+	};
+
+	fn frustum() -> Frustum { Frustum::new(Transform::perspective(std::f32::consts::FRAC_PI_2, 1f32, 1f32, 100f32)) }
+
+	/// The region to scatter test shapes in, which extends past the frustum on every side.
+	fn region() -> Bounds3 { Bounds3::new(Point::new(-60f32, -60f32, -120f32), Point::new(60f32, 60f32, 120f32)) }
+
+	#[test]
+	fn cull_spheres() {
+		let frustum = frustum();
+		let mut rng = Pcg32::new(1, 0);
+		let spheres: Vec<_> = (0..103)
+			.map(|_| Sphere::new(random::point_in_bounds(&mut rng, region()), rng.next_range(0f32, 5f32)))
+			.collect();
+		let mut visible = vec![false; spheres.len()];
+		frustum.cull_spheres(&spheres, &mut visible);
+
+		for (&sphere, &visible) in spheres.iter().zip(visible.iter()) {
+			assert_eq!(visible, frustum.cull_sphere(sphere), "{}", sphere);
+		}
+		assert!(visible.iter().any(|&x| x));
+		assert!(visible.iter().any(|&x| !x));
+	}
+
+	#[test]
+	fn cull_aabbs() {
+		let frustum = frustum();
+		let mut rng = Pcg32::new(2, 0);
+		let bounds: Vec<_> = (0..103)
+			.map(|_| {
+				let center = random::point_in_bounds(&mut rng, region());
+				let size = rng.next_range(0f32, 5f32);
+				Bounds3::new(center, center + Direction::new(size, size * 0.5f32, size * 2f32))
+			})
+			.collect();
+		let mut visible = vec![false; bounds.len()];
+		frustum.cull_aabbs(&bounds, &mut visible);
+
+		for (&bounds, &visible) in bounds.iter().zip(visible.iter()) {
+			assert_eq!(visible, frustum.cull_aabb(bounds), "{}", bounds);
+		}
+		assert!(visible.iter().any(|&x| x));
+		assert!(visible.iter().any(|&x| !x));
+	}
+
+	#[test]
+	fn cull_on_planes() {
+		// Spheres and boxes that touch a plane to within a float or two, where rounding decides the result.
+		let frustum = frustum();
+		let mut rng = Pcg32::new(4, 0);
+		let mut spheres = Vec::new();
+		let mut bounds = Vec::new();
+		for plane in frustum.planes().iter() {
+			for _ in 0..40 {
+				let center = random::point_in_bounds(&mut rng, region());
+				let distance = plane_distance(plane, center, 0f32);
+				if distance >= 0f32 {
+					continue;
+				}
+				for ulps in [-2i32, -1, 0, 1, 2].iter() {
+					let radius = f32::from_bits((-distance).to_bits().wrapping_add(*ulps as u32));
+					spheres.push(Sphere::new(center, radius));
+
+					let normal = plane.0.abs();
+					let half = radius / (normal.x() + normal.y() + normal.z());
+					let half = Direction::new(half, half, half);
+					bounds.push(Bounds3::new(center - half, center + half));
+				}
+			}
+		}
+
+		let mut visible = vec![false; spheres.len()];
+		frustum.cull_spheres(&spheres, &mut visible);
+		for (&sphere, &visible) in spheres.iter().zip(visible.iter()) {
+			assert_eq!(visible, frustum.cull_sphere(sphere), "{}", sphere);
+		}
+
+		let mut visible = vec![false; bounds.len()];
+		frustum.cull_aabbs(&bounds, &mut visible);
+		for (&bounds, &visible) in bounds.iter().zip(visible.iter()) {
+			assert_eq!(visible, frustum.cull_aabb(bounds), "{}", bounds);
+		}
+	}
+
+	#[test]
+	fn cull_known() {
+		let frustum = frustum();
+		let inside = Bounds3::new(Point::new(-1f32, -1f32, 9f32), Point::new(1f32, 1f32, 11f32));
+		let behind = Bounds3::new(Point::new(-1f32, -1f32, -3f32), Point::new(1f32, 1f32, -2f32));
+		let straddling = Bounds3::new(Point::new(9f32, 0f32, 9f32), Point::new(12f32, 1f32, 11f32));
+		let beside = Bounds3::new(Point::new(12f32, 0f32, 9f32), Point::new(13f32, 1f32, 11f32));
+		let bounds = [inside, behind, straddling, beside, inside];
+		let mut visible = [false; 5];
+		frustum.cull_aabbs(&bounds, &mut visible);
+		assert_eq!(visible, [true, false, true, false, true]);
+
+		let spheres = [
+			Sphere::new(Point::new(0f32, 0f32, 10f32), 1f32),
+			Sphere::new(Point::new(0f32, 0f32, -5f32), 1f32),
+			Sphere::new(Point::new(11f32, 0f32, 10f32), 2f32),
+			Sphere::new(Point::new(13f32, 0f32, 10f32), 1f32),
+		];
+		let mut visible = [false; 4];
+		frustum.cull_spheres(&spheres, &mut visible);
+		assert_eq!(visible, [true, false, true, false]);
+	}
+
+	#[cfg(feature = "rayon")]
+	#[test]
+	fn par_cull() {
+		let frustum = frustum();
+		let mut rng = Pcg32::new(3, 0);
+		let spheres: Vec<_> = (0..5003)
+			.map(|_| Sphere::new(random::point_in_bounds(&mut rng, region()), rng.next_range(0f32, 5f32)))
+			.collect();
+		let (mut serial, mut parallel) = (vec![false; spheres.len()], vec![true; spheres.len()]);
+		frustum.cull_spheres(&spheres, &mut serial);
+		frustum.par_cull_spheres(&spheres, &mut parallel);
+		assert_eq!(serial, parallel);
+
+		let bounds: Vec<_> = spheres
+			.iter()
+			.map(|sphere| Bounds3::new(sphere.center(), sphere.center() + Direction::new(1f32, 2f32, 3f32)))
+			.collect();
+		let (mut serial, mut parallel) = (vec![false; bounds.len()], vec![true; bounds.len()]);
+		frustum.cull_aabbs(&bounds, &mut serial);
+		frustum.par_cull_aabbs(&bounds, &mut parallel);
+		assert_eq!(serial, parallel);
+	}
+}
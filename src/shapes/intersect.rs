@@ -0,0 +1,713 @@
+//! The [`Intersects`] and [`Contains`] implementations between every pair of shapes.
+
+use super::{Capsule, Contains, Frustum, Intersects, Obb, Plane, Sphere};
+use crate::{base::Vector, bounds::Bounds3, closest, direction::Direction, point::Point};
+
+/// Implement `Intersects<$lhs> for $rhs` using `Intersects<$rhs> for $lhs`.
+macro_rules! symmetric {
+	($($lhs:ty => $rhs:ty),* $(,)?) => {
+		$(
+			impl Intersects<$lhs> for $rhs {
+				#[inline(always)]
+				fn intersects(self, rhs: $lhs) -> bool { rhs.intersects(self) }
+			}
+		)*
+	};
+}
+
+/// Implement containment of every closed shape in `$container`, using `Contains<Point>` and `Contains<Sphere>`.
+/// This works because every container is convex.
+macro_rules! contains_shapes {
+	($($container:ty),* $(,)?) => {
+		$(
+			impl Contains<Bounds3> for $container {
+				#[inline(always)]
+				fn contains(self, rhs: Bounds3) -> bool { (0..8).all(|idx| self.contains(rhs.corner(idx))) }
+			}
+
+			impl Contains<Obb> for $container {
+				#[inline(always)]
+				fn contains(self, rhs: Obb) -> bool { rhs.corners().iter().all(|&corner| self.contains(corner)) }
+			}
+
+			impl Contains<Capsule> for $container {
+				#[inline(always)]
+				fn contains(self, rhs: Capsule) -> bool {
+					self.contains(Sphere::new(rhs.start, rhs.radius)) && self.contains(Sphere::new(rhs.end, rhs.radius))
+				}
+			}
+		)*
+	};
+}
+
+#[inline(always)]
+/// Get the half-length of the projection of a box with `axes` and `half_extents` onto `axis`.
+fn projected_radius(axes: [Direction; 3], half_extents: Direction, axis: Direction) -> f32 {
+	let projected = Direction::new(
+		Direction::dot(axes[0], axis).abs(),
+		Direction::dot(axes[1], axis).abs(),
+		Direction::dot(axes[2], axis).abs(),
+	);
+	Direction::dot(half_extents, projected)
+}
+
+#[inline(always)]
+/// Check if all `points` are on the negative side of `plane`.
+fn all_behind(plane: Plane, points: &[Point]) -> bool {
+	points.iter().all(|&point| plane.signed_distance(point) < 0f32)
+}
+
+#[inline(always)]
+/// Check if an [`Obb`] and a [`Frustum`] overlap, using the planes of the [`Frustum`] and the axes of the [`Obb`].
+fn obb_frustum(obb: Obb, frustum: Frustum) -> bool {
+	let axes = obb.axes();
+	let outside_plane = frustum.planes().iter().any(|&plane| {
+		let radius = projected_radius(axes, obb.half_extents, Direction(plane.normal().0));
+		plane.signed_distance(obb.center) < -radius
+	});
+	if outside_plane {
+		return false;
+	}
+
+	let half_extents = [obb.half_extents.x(), obb.half_extents.y(), obb.half_extents.z()];
+	!axes.iter().zip(half_extents.iter()).any(|(&axis, &half)| {
+		let center = Vector::dot(axis.0, obb.center.0);
+		let (min, max) = frustum
+			.corners()
+			.iter()
+			.map(|&corner| Vector::dot(axis.0, corner.0))
+			.fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), x| {
+				(min.min(x), max.max(x))
+			});
+		min > center + half || max < center - half
+	})
+}
+
+/// Get the squared distance between an [`Obb`] and the segment from `start` to `end`.
+fn obb_segment_distance_square(obb: Obb, start: Point, end: Point) -> f32 {
+	let local = obb.to_local(start);
+	let delta = obb.to_local(end) - local;
+	let start = [local.x(), local.y(), local.z()];
+	let delta = [delta.x(), delta.y(), delta.z()];
+	let half = [obb.half_extents.x(), obb.half_extents.y(), obb.half_extents.z()];
+
+	// Along the segment, each axis is below, inside, or above the box between the times the segment crosses its faces.
+	// The squared distance is a quadratic between those times, so the exact minimum is at the vertex of one of the
+	// quadratics, or at the end of one of the pieces.
+	let mut times = [0f32; 8];
+	times[1] = 1f32;
+	let mut count = 2;
+	for axis in 0..3 {
+		for &face in [-half[axis], half[axis]].iter() {
+			let t = (face - start[axis]) / delta[axis];
+			if t > 0f32 && t < 1f32 {
+				times[count] = t;
+				count += 1;
+			}
+		}
+	}
+	let times = &mut times[..count];
+	times.sort_unstable_by(f32::total_cmp);
+
+	// How far past the faces of the box the segment is along `axis` at `t`.
+	let excess = |axis: usize, t: f32| {
+		let x = delta[axis].mul_add(t, start[axis]);
+		x - x.max(-half[axis]).min(half[axis])
+	};
+	let distance = |t: f32| (0..3).map(|axis| excess(axis, t) * excess(axis, t)).sum::<f32>();
+
+	times
+		.windows(2)
+		.map(|piece| {
+			// The excess changes linearly at the rate of `delta` on the axes that are outside the box.
+			let mid = (piece[0] + piece[1]) / 2f32;
+			let (mut speed_square, mut dot) = (0f32, 0f32);
+			for (axis, &speed) in delta.iter().enumerate() {
+				let excess = excess(axis, mid);
+				if excess != 0f32 {
+					speed_square = speed.mul_add(speed, speed_square);
+					dot = excess.mul_add(speed, dot);
+				}
+			}
+
+			let t = if speed_square > 0f32 {
+				mid - dot / speed_square
+			} else {
+				mid
+			};
+			distance(t.clamp(piece[0], piece[1]))
+		})
+		.fold(f32::INFINITY, f32::min)
+}
+
+// Plane.
+
+impl Intersects<Self> for Plane {
+	#[inline(always)]
+	fn intersects(self, rhs: Self) -> bool {
+		let lhs = self.normalize();
+		let rhs = rhs.normalize();
+		let cross = Direction::cross(Direction(lhs.normal().0), Direction(rhs.normal().0));
+		cross.length_square() > f32::EPSILON || lhs.signed_distance(rhs.project(Point::default())).abs() <= f32::EPSILON
+	}
+}
+
+impl Intersects<Sphere> for Plane {
+	#[inline(always)]
+	fn intersects(self, rhs: Sphere) -> bool { self.normalize().signed_distance(rhs.center()).abs() <= rhs.radius() }
+}
+
+impl Intersects<Bounds3> for Plane {
+	#[inline(always)]
+	fn intersects(self, rhs: Bounds3) -> bool { self.intersects(Obb::from(rhs)) }
+}
+
+impl Intersects<Obb> for Plane {
+	#[inline(always)]
+	fn intersects(self, rhs: Obb) -> bool {
+		let plane = self.normalize();
+		let radius = projected_radius(rhs.axes(), rhs.half_extents, Direction(plane.normal().0));
+		plane.signed_distance(rhs.center).abs() <= radius
+	}
+}
+
+impl Intersects<Capsule> for Plane {
+	#[inline(always)]
+	fn intersects(self, rhs: Capsule) -> bool {
+		let plane = self.normalize();
+		let start = plane.signed_distance(rhs.start);
+		let end = plane.signed_distance(rhs.end);
+		start * end <= 0f32 || start.abs().min(end.abs()) <= rhs.radius
+	}
+}
+
+impl Intersects<Frustum> for Plane {
+	#[inline(always)]
+	fn intersects(self, rhs: Frustum) -> bool {
+		let distances = rhs.corners().iter().map(|&corner| self.signed_distance(corner));
+		let (min, max) = distances.fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), x| {
+			(min.min(x), max.max(x))
+		});
+		min <= 0f32 && max >= 0f32
+	}
+}
+
+// Sphere.
+
+impl Intersects<Self> for Sphere {
+	#[inline(always)]
+	fn intersects(self, rhs: Self) -> bool {
+		let radius = self.radius() + rhs.radius();
+		(self.center() - rhs.center()).length_square() <= radius * radius
+	}
+}
+
+impl Intersects<Bounds3> for Sphere {
+	#[inline(always)]
+	fn intersects(self, rhs: Bounds3) -> bool {
+		let center = self.center();
+		let closest = Point(Vector::clamp(center.0, rhs.min.0, rhs.max.0));
+		(closest - center).length_square() <= self.radius() * self.radius()
+	}
+}
+
+impl Intersects<Obb> for Sphere {
+	#[inline(always)]
+	fn intersects(self, rhs: Obb) -> bool {
+		let center = self.center();
+		(rhs.closest_point(center) - center).length_square() <= self.radius() * self.radius()
+	}
+}
+
+impl Intersects<Capsule> for Sphere {
+	#[inline(always)]
+	fn intersects(self, rhs: Capsule) -> bool {
+		let center = self.center();
+		let radius = self.radius() + rhs.radius;
+		(rhs.closest_on_segment(center) - center).length_square() <= radius * radius
+	}
+}
+
+impl Intersects<Frustum> for Sphere {
+	#[inline(always)]
+	fn intersects(self, rhs: Frustum) -> bool { rhs.cull_sphere(self) }
+}
+
+// Bounds3.
+
+impl Intersects<Self> for Bounds3 {
+	#[inline(always)]
+	fn intersects(self, rhs: Self) -> bool { Self::overlaps(self, rhs) }
+}
+
+impl Intersects<Obb> for Bounds3 {
+	#[inline(always)]
+	fn intersects(self, rhs: Obb) -> bool { Obb::from(self).intersects(rhs) }
+}
+
+impl Intersects<Capsule> for Bounds3 {
+	#[inline(always)]
+	fn intersects(self, rhs: Capsule) -> bool { Obb::from(self).intersects(rhs) }
+}
+
+impl Intersects<Frustum> for Bounds3 {
+	#[inline(always)]
+	fn intersects(self, rhs: Frustum) -> bool { obb_frustum(Obb::from(self), rhs) }
+}
+
+// Obb.
+
+impl Intersects<Self> for Obb {
+	#[inline(always)]
+	fn intersects(self, rhs: Self) -> bool {
+		// Real-Time Collision Detection, 4.4.1.
+		let lhs_axes = self.axes();
+		let rhs_axes = rhs.axes();
+		let lhs_half = [self.half_extents.x(), self.half_extents.y(), self.half_extents.z()];
+		let rhs_half = [rhs.half_extents.x(), rhs.half_extents.y(), rhs.half_extents.z()];
+
+		let mut rot = [[0f32; 3]; 3];
+		let mut abs_rot = [[0f32; 3]; 3];
+		for i in 0..3 {
+			for j in 0..3 {
+				rot[i][j] = Direction::dot(lhs_axes[i], rhs_axes[j]);
+				// Add an epsilon to counteract errors when two edges are parallel.
+				abs_rot[i][j] = rot[i][j].abs() + 0.00001f32;
+			}
+		}
+
+		let offset = rhs.center - self.center;
+		let t = [
+			Direction::dot(offset, lhs_axes[0]),
+			Direction::dot(offset, lhs_axes[1]),
+			Direction::dot(offset, lhs_axes[2]),
+		];
+
+		// The face axes of `self`.
+		for i in 0..3 {
+			let rb = rhs_half[2].mul_add(
+				abs_rot[i][2],
+				rhs_half[1].mul_add(abs_rot[i][1], rhs_half[0] * abs_rot[i][0]),
+			);
+			if t[i].abs() > lhs_half[i] + rb {
+				return false;
+			}
+		}
+
+		// The face axes of `rhs`.
+		for j in 0..3 {
+			let ra = lhs_half[2].mul_add(
+				abs_rot[2][j],
+				lhs_half[1].mul_add(abs_rot[1][j], lhs_half[0] * abs_rot[0][j]),
+			);
+			let dist = t[2].mul_add(rot[2][j], t[1].mul_add(rot[1][j], t[0] * rot[0][j]));
+			if dist.abs() > ra + rhs_half[j] {
+				return false;
+			}
+		}
+
+		// The cross products of every pair of edges.
+		for i in 0..3 {
+			let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+			for j in 0..3 {
+				let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+				let ra = lhs_half[i2].mul_add(abs_rot[i1][j], lhs_half[i1] * abs_rot[i2][j]);
+				let rb = rhs_half[j2].mul_add(abs_rot[i][j1], rhs_half[j1] * abs_rot[i][j2]);
+				let dist = t[i2].mul_add(rot[i1][j], -t[i1] * rot[i2][j]);
+				if dist.abs() > ra + rb {
+					return false;
+				}
+			}
+		}
+
+		true
+	}
+}
+
+impl Intersects<Capsule> for Obb {
+	#[inline(always)]
+	fn intersects(self, rhs: Capsule) -> bool {
+		obb_segment_distance_square(self, rhs.start, rhs.end) <= rhs.radius * rhs.radius
+	}
+}
+
+impl Intersects<Frustum> for Obb {
+	#[inline(always)]
+	fn intersects(self, rhs: Frustum) -> bool { obb_frustum(self, rhs) }
+}
+
+// Capsule.
+
+impl Intersects<Self> for Capsule {
+	#[inline(always)]
+	fn intersects(self, rhs: Self) -> bool {
+		let radius = self.radius + rhs.radius;
+		closest::segment_segment(self.start, self.end, rhs.start, rhs.end).distance_square <= radius * radius
+	}
+}
+
+impl Intersects<Frustum> for Capsule {
+	#[inline(always)]
+	fn intersects(self, rhs: Frustum) -> bool {
+		rhs.planes().iter().all(|&plane| {
+			plane.signed_distance(self.start) >= -self.radius || plane.signed_distance(self.end) >= -self.radius
+		})
+	}
+}
+
+// Frustum.
+
+impl Intersects<Self> for Frustum {
+	#[inline(always)]
+	fn intersects(self, rhs: Self) -> bool {
+		!self.planes().iter().any(|&plane| all_behind(plane, rhs.corners()))
+			&& !rhs.planes().iter().any(|&plane| all_behind(plane, self.corners()))
+	}
+}
+
+symmetric! {
+	Plane => Sphere,
+	Plane => Bounds3,
+	Plane => Obb,
+	Plane => Capsule,
+	Plane => Frustum,
+	Sphere => Bounds3,
+	Sphere => Obb,
+	Sphere => Capsule,
+	Sphere => Frustum,
+	Bounds3 => Obb,
+	Bounds3 => Capsule,
+	Bounds3 => Frustum,
+	Obb => Capsule,
+	Obb => Frustum,
+	Capsule => Frustum,
+}
+
+// Containment.
+
+impl Contains<Point> for Sphere {
+	#[inline(always)]
+	fn contains(self, rhs: Point) -> bool { (rhs - self.center()).length_square() <= self.radius() * self.radius() }
+}
+
+impl Contains<Self> for Sphere {
+	#[inline(always)]
+	fn contains(self, rhs: Self) -> bool {
+		let radius = self.radius() - rhs.radius();
+		radius >= 0f32 && (rhs.center() - self.center()).length_square() <= radius * radius
+	}
+}
+
+impl Contains<Point> for Bounds3 {
+	#[inline(always)]
+	fn contains(self, rhs: Point) -> bool {
+		Vector::max(self.min.0, rhs.0) == rhs.0 && Vector::min(self.max.0, rhs.0) == rhs.0
+	}
+}
+
+impl Contains<Sphere> for Bounds3 {
+	#[inline(always)]
+	fn contains(self, rhs: Sphere) -> bool {
+		let radius = rhs.radius();
+		let offset = Direction::new(radius, radius, radius);
+		Self {
+			min: self.min + offset,
+			max: self.max - offset,
+		}
+		.contains(rhs.center())
+	}
+}
+
+impl Contains<Point> for Obb {
+	#[inline(always)]
+	fn contains(self, rhs: Point) -> bool {
+		let local = self.to_local(rhs).0.abs();
+		Vector::max(local, self.half_extents.0) == self.half_extents.0
+	}
+}
+
+impl Contains<Sphere> for Obb {
+	#[inline(always)]
+	fn contains(self, rhs: Sphere) -> bool {
+		let radius = rhs.radius();
+		let half_extents = self.half_extents - Direction::new(radius, radius, radius);
+		let local = self.to_local(rhs.center()).0.abs();
+		Vector::max(local, half_extents.0) == half_extents.0
+	}
+}
+
+impl Contains<Point> for Capsule {
+	#[inline(always)]
+	fn contains(self, rhs: Point) -> bool {
+		(self.closest_on_segment(rhs) - rhs).length_square() <= self.radius * self.radius
+	}
+}
+
+impl Contains<Sphere> for Capsule {
+	#[inline(always)]
+	fn contains(self, rhs: Sphere) -> bool {
+		let center = rhs.center();
+		let radius = self.radius - rhs.radius();
+		radius >= 0f32 && closest::point_segment(center, self.start, self.end).distance_square <= radius * radius
+	}
+}
+
+impl Contains<Point> for Frustum {
+	#[inline(always)]
+	fn contains(self, rhs: Point) -> bool { self.planes().iter().all(|&plane| plane.signed_distance(rhs) >= 0f32) }
+}
+
+impl Contains<Sphere> for Frustum {
+	#[inline(always)]
+	fn contains(self, rhs: Sphere) -> bool {
+		let center = rhs.center();
+		self.planes()
+			.iter()
+			.all(|&plane| plane.signed_distance(center) >= rhs.radius())
+	}
+}
+
+contains_shapes!(Sphere, Bounds3, Obb, Capsule, Frustum);
+
+#[cfg(test)]
+mod tests {
+	use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+	use super::*;
+	use crate::{
+		random::{self, Pcg32},
+		Normal,
+		Rotation,
+		Transform,
+	};
+
+	#[derive(Copy, Clone, Debug)]
+	enum Shape {
+		Plane(Plane),
+		Sphere(Sphere),
+		Bounds(Bounds3),
+		Obb(Obb),
+		Capsule(Capsule),
+		Frustum(Frustum),
+	}
+
+	trait IntersectsAll:
+		Intersects<Plane>
+		+ Intersects<Sphere>
+		+ Intersects<Bounds3>
+		+ Intersects<Obb>
+		+ Intersects<Capsule>
+		+ Intersects<Frustum>
+		+ Copy
+	{
+	}
+	impl<T> IntersectsAll for T where
+		T: Intersects<Plane>
+			+ Intersects<Sphere>
+			+ Intersects<Bounds3>
+			+ Intersects<Obb>
+			+ Intersects<Capsule>
+			+ Intersects<Frustum>
+			+ Copy
+	{
+	}
+
+	trait ContainsAll:
+		Contains<Point> + Contains<Sphere> + Contains<Bounds3> + Contains<Obb> + Contains<Capsule> + Copy
+	{
+	}
+	impl<T> ContainsAll for T where
+		T: Contains<Point> + Contains<Sphere> + Contains<Bounds3> + Contains<Obb> + Contains<Capsule> + Copy
+	{
+	}
+
+	fn intersects_with(lhs: impl IntersectsAll, rhs: Shape) -> bool {
+		match rhs {
+			Shape::Plane(rhs) => lhs.intersects(rhs),
+			Shape::Sphere(rhs) => lhs.intersects(rhs),
+			Shape::Bounds(rhs) => lhs.intersects(rhs),
+			Shape::Obb(rhs) => lhs.intersects(rhs),
+			Shape::Capsule(rhs) => lhs.intersects(rhs),
+			Shape::Frustum(rhs) => lhs.intersects(rhs),
+		}
+	}
+
+	fn intersects(lhs: Shape, rhs: Shape) -> bool {
+		match lhs {
+			Shape::Plane(lhs) => intersects_with(lhs, rhs),
+			Shape::Sphere(lhs) => intersects_with(lhs, rhs),
+			Shape::Bounds(lhs) => intersects_with(lhs, rhs),
+			Shape::Obb(lhs) => intersects_with(lhs, rhs),
+			Shape::Capsule(lhs) => intersects_with(lhs, rhs),
+			Shape::Frustum(lhs) => intersects_with(lhs, rhs),
+		}
+	}
+
+	fn contains_with(lhs: impl ContainsAll, rhs: Shape) -> Option<bool> {
+		Some(match rhs {
+			Shape::Plane(_) | Shape::Frustum(_) => return None,
+			Shape::Sphere(rhs) => lhs.contains(rhs),
+			Shape::Bounds(rhs) => lhs.contains(rhs),
+			Shape::Obb(rhs) => lhs.contains(rhs),
+			Shape::Capsule(rhs) => lhs.contains(rhs),
+		})
+	}
+
+	fn contains(lhs: Shape, rhs: Shape) -> Option<bool> {
+		match lhs {
+			Shape::Plane(_) => None,
+			Shape::Sphere(lhs) => contains_with(lhs, rhs),
+			Shape::Bounds(lhs) => contains_with(lhs, rhs),
+			Shape::Obb(lhs) => contains_with(lhs, rhs),
+			Shape::Capsule(lhs) => contains_with(lhs, rhs),
+			Shape::Frustum(lhs) => contains_with(lhs, rhs),
+		}
+	}
+
+	/// One of every shape, around `center`, with a size of roughly `size`.
+	fn shapes(center: Point, size: f32) -> [Shape; 6] {
+		let offset = Direction::new(size, size, size);
+		let camera = center - Direction::new(0f32, 0f32, size * 2f32);
+		let view = Transform::translate(Point::default() - camera);
+
+		[
+			Shape::Plane(Plane::new(
+				Normal::new(1f32, 0f32, 0f32),
+				Vector::dot(Vector::new(1f32, 0f32, 0f32, 0f32), center.0),
+			)),
+			Shape::Sphere(Sphere::new(center, size)),
+			Shape::Bounds(Bounds3::new(center - offset, center + offset)),
+			Shape::Obb(Obb::new(
+				center,
+				Rotation::axis_angle(Direction::new(1f32, 1f32, 0f32), FRAC_PI_4),
+				offset,
+			)),
+			Shape::Capsule(Capsule::new(
+				center - Direction::new(0f32, size, 0f32),
+				center + Direction::new(0f32, size, 0f32),
+				size / 2f32,
+			)),
+			Shape::Frustum(Frustum::new(
+				view * Transform::perspective(FRAC_PI_2, 1f32, size, size * 4f32),
+			)),
+		]
+	}
+
+	#[test]
+	fn intersection_matrix() {
+		let near = shapes(Point::default(), 1f32);
+		let far = shapes(Point::new(100f32, 0f32, 0f32), 1f32);
+
+		for &lhs in near.iter() {
+			for &rhs in near.iter() {
+				assert!(intersects(lhs, rhs), "{:?} and {:?} should intersect", lhs, rhs);
+			}
+			for &rhs in far.iter() {
+				assert!(!intersects(lhs, rhs), "{:?} and {:?} should not intersect", lhs, rhs);
+				assert!(!intersects(rhs, lhs), "{:?} and {:?} should not intersect", rhs, lhs);
+			}
+		}
+	}
+
+	#[test]
+	fn containment_matrix() {
+		let small = shapes(Point::default(), 0.1f32);
+		let big = shapes(Point::default(), 2f32);
+		let far = shapes(Point::new(100f32, 0f32, 0f32), 0.1f32);
+
+		for &lhs in big.iter() {
+			for &rhs in small.iter() {
+				assert_ne!(contains(lhs, rhs), Some(false), "{:?} should contain {:?}", lhs, rhs);
+				assert_ne!(contains(rhs, lhs), Some(true), "{:?} should not contain {:?}", rhs, lhs);
+			}
+			for &rhs in far.iter() {
+				assert_ne!(contains(lhs, rhs), Some(true), "{:?} should not contain {:?}", lhs, rhs);
+			}
+		}
+	}
+
+	#[test]
+	fn separating_axes() {
+		let unit = Bounds3::new(Point::new(-1f32, -1f32, -1f32), Point::new(1f32, 1f32, 1f32));
+		let diamond = |x: f32| {
+			Obb::new(
+				Point::new(x, x, 0f32),
+				Rotation::axis_angle(Direction::new(0f32, 0f32, 1f32), FRAC_PI_4),
+				Direction::new(1f32, 1f32, 1f32),
+			)
+		};
+
+		// The corners of the bounding boxes overlap, but the boxes only do when close enough.
+		assert!(unit.intersects(diamond(1.7f32)));
+		assert!(!unit.intersects(diamond(1.75f32)));
+		assert!(Bounds3::overlaps(unit, diamond(1.75f32).bounds()));
+
+		let capsule = |x: f32| Capsule::new(Point::new(x, -5f32, 0f32), Point::new(x + 10f32, 5f32, 0f32), 0.1f32);
+		assert!(unit.intersects(capsule(-2.9f32)));
+		assert!(!unit.intersects(capsule(-2.8f32)));
+		assert!(!diamond(0f32).intersects(capsule(-2.9f32)));
+	}
+
+	#[test]
+	fn obb_segment() {
+		let unit = Obb::from(Bounds3::new(
+			Point::new(-1f32, -1f32, -1f32),
+			Point::new(1f32, 1f32, 1f32),
+		));
+		let distance = |start, end| obb_segment_distance_square(unit, start, end);
+		assert_eq!(
+			distance(Point::new(2f32, -5f32, 0f32), Point::new(2f32, 5f32, 0f32)),
+			1f32
+		);
+		assert_eq!(
+			distance(Point::new(3f32, 3f32, -1f32), Point::new(3f32, 3f32, 1f32)),
+			8f32
+		);
+		assert_eq!(
+			distance(Point::new(-5f32, 0f32, 0f32), Point::new(5f32, 0f32, 0f32)),
+			0f32
+		);
+		assert_eq!(
+			distance(Point::new(2f32, 3f32, 0f32), Point::new(2f32, 3f32, 0f32)),
+			5f32
+		);
+
+		// Compare against sampling the segment densely.
+		let obb = Obb::new(
+			Point::new(1f32, 2f32, 3f32),
+			Rotation::axis_angle(Direction::new(1f32, 2f32, -1f32), 0.7f32),
+			Direction::new(1f32, 0.5f32, 2f32),
+		);
+		let mut rng = Pcg32::new(0, 3);
+		for _ in 0..100 {
+			let bounds = Bounds3::new(Point::new(-4f32, -3f32, -2f32), Point::new(6f32, 7f32, 8f32));
+			let start = random::point_in_bounds(&mut rng, bounds);
+			let end = random::point_in_bounds(&mut rng, bounds);
+			let sampled = (0..=1000)
+				.map(|idx| {
+					let point = Point::lerp(start, end, idx as f32 / 1000f32);
+					(obb.closest_point(point) - point).length_square()
+				})
+				.fold(f32::INFINITY, f32::min);
+			let exact = obb_segment_distance_square(obb, start, end);
+			assert!(
+				exact <= sampled + 0.0001f32 && exact >= sampled - 0.01f32,
+				"{} and {}",
+				exact,
+				sampled
+			);
+		}
+	}
+
+	#[test]
+	fn points() {
+		let sphere = Sphere::new(Point::new(1f32, 0f32, 0f32), 1f32);
+		let capsule = Capsule::new(Point::default(), Point::new(0f32, 2f32, 0f32), 0.5f32);
+
+		assert!(sphere.contains(Point::new(1.5f32, 0.5f32, 0f32)));
+		assert!(!sphere.contains(Point::new(2.5f32, 0f32, 0f32)));
+		assert!(capsule.contains(Point::new(0.4f32, 1f32, 0f32)));
+		assert!(!capsule.contains(Point::new(0f32, 2.6f32, 0f32)));
+		assert!(Bounds3::from(Point::default()).contains(Point::default()));
+	}
+}
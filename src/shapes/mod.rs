@@ -0,0 +1,36 @@
+//! Geometric primitives, and intersection and containment tests between them.
+//!
+//! [`Intersects`] is implemented between every pair of [`Plane`], [`Sphere`], [`Bounds3`], [`Obb`], [`Capsule`],
+//! and [`Frustum`], in both orders. [`Contains`] is implemented for every closed shape, containing [`Point`]s and
+//! every other closed shape.
+//!
+//! Tests involving a [`Frustum`] are conservative: they may report an intersection for shapes that are just outside
+//! of the corners of the [`Frustum`], but never miss one.
+//!
+//! [`Bounds3`]: crate::Bounds3
+//! [`Point`]: crate::Point
+
+pub mod capsule;
+pub mod frustum;
+mod intersect;
+pub mod obb;
+pub mod plane;
+pub mod sphere;
+
+pub use capsule::*;
+pub use frustum::*;
+pub use obb::*;
+pub use plane::*;
+pub use sphere::*;
+
+/// Check if two shapes overlap, including touching boundaries.
+pub trait Intersects<Rhs> {
+	/// Check if `self` and `rhs` overlap.
+	fn intersects(self, rhs: Rhs) -> bool;
+}
+
+/// Check if a shape completely contains another.
+pub trait Contains<Rhs> {
+	/// Check if `rhs` is inside `self`, including its boundary.
+	fn contains(self, rhs: Rhs) -> bool;
+}
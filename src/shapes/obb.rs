@@ -0,0 +1,112 @@
+//! Oriented bounding boxes.
+
+use std::fmt::{Debug, Display};
+
+use crate::{base::Vector, bounds::Bounds3, direction::Direction, point::Point, rotation::Rotation};
+
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+/// A box that can be rotated arbitrarily.
+pub struct Obb {
+	/// The center of the [`Obb`].
+	pub center: Point,
+	/// The rotation from the axes of the world to the axes of the [`Obb`].
+	pub rotation: Rotation,
+	/// The distance from the center to the faces of the [`Obb`], along each of its axes.
+	pub half_extents: Direction,
+}
+
+impl Debug for Obb {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"Obb [{:?}, {:?}, {:?}]",
+			self.center, self.rotation, self.half_extents
+		)
+	}
+}
+
+impl Display for Obb {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Obb [{}, {}, {}]", self.center, self.rotation, self.half_extents)
+	}
+}
+
+impl From<Bounds3> for Obb {
+	#[inline(always)]
+	fn from(val: Bounds3) -> Self { Self::new(val.center(), Rotation::identity(), val.extent() * 0.5f32) }
+}
+
+impl Obb {
+	#[inline(always)]
+	/// Create a new [`Obb`].
+	pub const fn new(center: Point, rotation: Rotation, half_extents: Direction) -> Self {
+		Self {
+			center,
+			rotation,
+			half_extents,
+		}
+	}
+
+	#[inline(always)]
+	/// Get the normalized x, y, and z axes of the [`Obb`].
+	pub fn axes(self) -> [Direction; 3] {
+		let quat = self.rotation.0;
+		[
+			Direction(quat.rotate(Vector::new(1f32, 0f32, 0f32, 0f32))),
+			Direction(quat.rotate(Vector::new(0f32, 1f32, 0f32, 0f32))),
+			Direction(quat.rotate(Vector::new(0f32, 0f32, 1f32, 0f32))),
+		]
+	}
+
+	#[inline(always)]
+	/// Get the position of `point` along the axes of the [`Obb`], relative to its center.
+	pub fn to_local(self, point: Point) -> Direction {
+		Direction(self.rotation.0.conjugate().rotate((point - self.center).0))
+	}
+
+	#[inline(always)]
+	/// Get the [`Point`] at `local` along the axes of the [`Obb`], relative to its center.
+	pub fn from_local(self, local: Direction) -> Point { self.center + Direction(self.rotation.0.rotate(local.0)) }
+
+	#[inline(always)]
+	/// Get the eight corners of the [`Obb`].
+	pub fn corners(self) -> [Point; 8] {
+		let mut corners = [self.center; 8];
+		for (idx, corner) in corners.iter_mut().enumerate() {
+			let sign = Vector::new(
+				if idx & 1 == 0 { -1f32 } else { 1f32 },
+				if idx & 2 == 0 { -1f32 } else { 1f32 },
+				if idx & 4 == 0 { -1f32 } else { 1f32 },
+				0f32,
+			);
+			*corner = self.from_local(Direction(self.half_extents.0 * sign));
+		}
+		corners
+	}
+
+	#[inline(always)]
+	/// Get the closest [`Point`] in the [`Obb`] to `point`.
+	pub fn closest_point(self, point: Point) -> Point {
+		let half = self.half_extents.0;
+		self.from_local(Direction(Vector::clamp(self.to_local(point).0, -half, half)))
+	}
+
+	#[inline(always)]
+	/// Get the smallest [`Bounds3`] containing the [`Obb`].
+	pub fn bounds(self) -> Bounds3 {
+		let [x, y, z] = self.axes();
+		let half = Direction(
+			(x * self.half_extents.x()).0.abs()
+				+ (y * self.half_extents.y()).0.abs()
+				+ (z * self.half_extents.z()).0.abs(),
+		);
+
+		Bounds3 {
+			min: self.center - half,
+			max: self.center + half,
+		}
+	}
+}
@@ -0,0 +1,121 @@
+//! Planes.
+
+use std::{
+	fmt::{Debug, Display},
+	ops::{Mul, MulAssign},
+};
+
+use crate::{base::Vector, direction::Direction, normal::Normal, point::Point, transform::Transform};
+
+#[repr(transparent)]
+#[derive(Copy, Clone, PartialEq)]
+/// An infinite plane, containing the [`Point`]s `p` where `dot(normal, p) = distance`.
+/// The `normal` points to the positive side of the [`Plane`]. May NOT be normalized.
+pub struct Plane(pub(crate) Vector);
+
+impl Debug for Plane {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Plane [{:?}, {}]", self.normal(), self.distance())
+	}
+}
+
+impl Display for Plane {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Plane [{}, {}]", self.normal(), self.distance())
+	}
+}
+
+impl Mul<Transform> for Plane {
+	type Output = Self;
+
+	#[inline(always)]
+	fn mul(self, rhs: Transform) -> Self::Output { Self(self.0 * rhs.inverse.transpose()) }
+}
+
+impl MulAssign<Transform> for Plane {
+	#[inline(always)]
+	fn mul_assign(&mut self, rhs: Transform) { *self = *self * rhs }
+}
+
+impl Plane {
+	#[inline(always)]
+	/// Create a new [`Plane`] from its `normal`, and its `distance` from the origin along the `normal`.
+	pub fn new(normal: Normal, distance: f32) -> Self {
+		let mut vec = normal.0;
+		vec.set_w(-distance);
+		Self(vec)
+	}
+
+	#[inline(always)]
+	/// Create the [`Plane`] through three [`Point`]s.
+	/// The `normal` is normalized, and points towards the side from which they are anti-clockwise.
+	pub fn from_points(p0: Point, p1: Point, p2: Point) -> Self {
+		let normal = Direction::cross(p1 - p0, p2 - p0).normalize();
+		Self::new(Normal(normal.0), Vector::dot(normal.0, p0.0))
+	}
+
+	#[inline(always)]
+	/// Get the normal of the [`Plane`].
+	pub fn normal(self) -> Normal { Normal(self.0 * Vector::new(1f32, 1f32, 1f32, 0f32)) }
+
+	#[inline(always)]
+	/// Get the distance of the [`Plane`] from the origin along the `normal`.
+	pub fn distance(self) -> f32 { -self.0.w() }
+
+	#[inline(always)]
+	/// Get the [`Plane`] with a normalized `normal`.
+	pub fn normalize(self) -> Self { Self(self.0 / self.normal().length()) }
+
+	#[inline(always)]
+	/// Get the signed distance of `point` from the [`Plane`], which is positive on the side the `normal` points to.
+	/// Only a distance if the [`Plane`] is normalized.
+	pub fn signed_distance(self, point: Point) -> f32 { Vector::dot(self.0, point.0) }
+
+	#[inline(always)]
+	/// Get the closest [`Point`] on a normalized [`Plane`] to `point`.
+	pub fn project(self, point: Point) -> Point { point - Direction(self.normal().0 * self.signed_distance(point)) }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::base::nearly_equal;
+
+	#[test]
+	fn from_points() {
+		let plane = Plane::from_points(
+			Point::new(0f32, 2f32, 0f32),
+			Point::new(1f32, 2f32, 0f32),
+			Point::new(0f32, 2f32, -1f32),
+		);
+
+		assert_eq!(plane.normal(), Normal::new(0f32, 1f32, 0f32));
+		assert_eq!(plane.distance(), 2f32);
+	}
+
+	#[test]
+	fn signed_distance_and_project() {
+		let plane = Plane::new(Normal::new(0f32, 0f32, 1f32), 1f32);
+
+		assert_eq!(plane.signed_distance(Point::new(3f32, 4f32, 5f32)), 4f32);
+		assert_eq!(plane.signed_distance(Point::new(3f32, 4f32, -1f32)), -2f32);
+		assert_eq!(
+			plane.project(Point::new(3f32, 4f32, 5f32)),
+			Point::new(3f32, 4f32, 1f32)
+		);
+		assert_eq!(Plane::new(Normal::new(0f32, 0f32, 2f32), 2f32).normalize(), plane);
+	}
+
+	#[test]
+	fn transform() {
+		let plane = Plane::new(Normal::new(0f32, 1f32, 0f32), 1f32)
+			* Transform::translate(Direction::new(0f32, 2f32, 0f32))
+			* Transform::scale(Direction::new(1f32, 2f32, 1f32));
+		let point = Point::new(5f32, 6f32, 7f32);
+
+		assert!(nearly_equal(plane.normalize().signed_distance(point), 0f32, 0.0001f32));
+		assert!(nearly_equal(plane.normalize().distance(), 6f32, 0.0001f32));
+	}
+}
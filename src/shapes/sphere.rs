@@ -0,0 +1,57 @@
+//! Spheres.
+
+use std::fmt::{Debug, Display};
+
+use crate::{base::Vector, bounds::Bounds3, direction::Direction, point::Point};
+
+#[repr(transparent)]
+#[derive(Copy, Clone, PartialEq)]
+/// A solid sphere.
+pub struct Sphere(pub(crate) Vector);
+
+impl Debug for Sphere {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Sphere [{:?}, {}]", self.center(), self.radius())
+	}
+}
+
+impl Display for Sphere {
+	#[inline(always)]
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Sphere [{}, {}]", self.center(), self.radius())
+	}
+}
+
+impl Sphere {
+	#[inline(always)]
+	/// Create a new [`Sphere`] with the given `center` and `radius`.
+	pub fn new(center: Point, radius: f32) -> Self {
+		let mut vec = center.0;
+		vec.set_w(radius);
+		Self(vec)
+	}
+
+	#[inline(always)]
+	/// Get the center of the [`Sphere`].
+	pub fn center(self) -> Point {
+		let mut vec = self.0;
+		vec.set_w(1f32);
+		Point(vec)
+	}
+
+	#[inline(always)]
+	/// Get the radius of the [`Sphere`].
+	pub fn radius(self) -> f32 { self.0.w() }
+
+	#[inline(always)]
+	/// Get the smallest [`Bounds3`] containing the [`Sphere`].
+	pub fn bounds(self) -> Bounds3 {
+		let radius = self.radius();
+		let offset = Direction::new(radius, radius, radius);
+		Bounds3 {
+			min: self.center() - offset,
+			max: self.center() + offset,
+		}
+	}
+}
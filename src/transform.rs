@@ -1,12 +1,21 @@
 //! Affine transformations.
 
 use std::{
+	convert::TryInto,
 	fmt::{Debug, Display, Formatter, Result},
 	ops::{Mul, MulAssign},
 };
 
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+#[cfg(feature = "rayon")]
+use crate::PAR_CHUNK;
 use crate::{
 	base::{Matrix, Vector},
+	normal::Normal,
+	point::Point,
+	wide::{self, Point3x4, Vector3x4},
 	Direction,
 	Rotation,
 };
@@ -34,6 +43,18 @@ impl Display for Transform {
 	fn fmt(&self, f: &mut Formatter<'_>) -> Result { write!(f, "{}", self.matrix) }
 }
 
+/// Does not check that `matrix` is invertible: a singular matrix gives an inverse full of infinities or NaNs.
+/// Check that [`Matrix::det`] is finite and non-zero first if unsure. Deserializing a [`Transform`] does this.
+impl From<Matrix> for Transform {
+	#[inline(always)]
+	fn from(matrix: Matrix) -> Self {
+		Self {
+			matrix,
+			inverse: matrix.inverse(),
+		}
+	}
+}
+
 impl Mul for Transform {
 	type Output = Self;
 
@@ -137,6 +158,31 @@ impl Transform {
 		}
 	}
 
+	#[inline(always)]
+	/// Get a perspective projection [`Transform`] for a camera looking along +z, with +y up and +x right.
+	/// `fov_y` is the vertical field of view in radians, and `aspect` is the width divided by the height.
+	/// Depth from `near` to `far` is mapped to the range [0, 1].
+	pub fn perspective(fov_y: f32, aspect: f32, near: f32, far: f32) -> Self {
+		let y = 1f32 / (fov_y / 2f32).tan();
+		let x = y / aspect;
+		let z = far / (far - near);
+
+		Self {
+			matrix: Matrix::rows([
+				[x, 0f32, 0f32, 0f32],
+				[0f32, y, 0f32, 0f32],
+				[0f32, 0f32, z, 1f32],
+				[0f32, 0f32, -near * z, 0f32],
+			]),
+			inverse: Matrix::rows([
+				[1f32 / x, 0f32, 0f32, 0f32],
+				[0f32, 1f32 / y, 0f32, 0f32],
+				[0f32, 0f32, 0f32, -1f32 / (near * z)],
+				[0f32, 0f32, 1f32, 1f32 / near],
+			]),
+		}
+	}
+
 	#[inline(always)]
 	/// Get the inverse of the [`Transform`].
 	/// Is quite fast (faster than [`Matrix::inverse`]).
@@ -146,13 +192,159 @@ impl Transform {
 			inverse: self.matrix,
 		}
 	}
+
+	/// Transform every [`Point`] in `src`, writing the results into `dst`. Panics if they have different lengths.
+	///
+	/// Works on four [`Point`]s at a time, as a [`Point3x4`].
+	pub fn transform_points(&self, src: &[Point], dst: &mut [Point]) {
+		bulk(src, dst, |points| (Point3x4::from(points) * *self).into())
+	}
+
+	/// Transform every [`Direction`] in `src`, writing the results into `dst`. Panics if they have different lengths.
+	///
+	/// Works on four [`Direction`]s at a time, as a [`Vector3x4`].
+	pub fn transform_directions(&self, src: &[Direction], dst: &mut [Direction]) {
+		bulk(src, dst, |dirs| (Vector3x4::from(dirs) * *self).into())
+	}
+
+	/// Transform every [`Normal`] in `src` with the inverse transpose, writing the results into `dst`. Panics if they
+	/// have different lengths.
+	///
+	/// Works on four [`Normal`]s at a time, as a [`Vector3x4`].
+	pub fn transform_normals(&self, src: &[Normal], dst: &mut [Normal]) {
+		bulk(src, dst, |normals| {
+			Vector3x4::from(normals).transform_normal(*self).into()
+		})
+	}
+
+	/// Transform positions in place inside a buffer of interleaved vertices. Each position is three native-endian
+	/// `f32`s, the first of which starts `offset` bytes into the buffer, and the rest follow every `stride` bytes.
+	///
+	/// Panics if `stride` is smaller than a position.
+	pub fn transform_points_strided(&self, data: &mut [u8], offset: usize, stride: usize) {
+		strided(data, offset, stride, |x, y, z| {
+			wide::transform(x, y, z, &self.matrix, true)
+		})
+	}
+
+	/// Transform directions in place inside a buffer of interleaved vertices, laid out like in
+	/// [`Transform::transform_points_strided`].
+	pub fn transform_directions_strided(&self, data: &mut [u8], offset: usize, stride: usize) {
+		strided(data, offset, stride, |x, y, z| {
+			wide::transform(x, y, z, &self.matrix, false)
+		})
+	}
+
+	/// Transform normals in place with the inverse transpose inside a buffer of interleaved vertices, laid out like in
+	/// [`Transform::transform_points_strided`].
+	pub fn transform_normals_strided(&self, data: &mut [u8], offset: usize, stride: usize) {
+		strided(data, offset, stride, |x, y, z| {
+			wide::transform_transposed(x, y, z, &self.inverse)
+		})
+	}
+
+	#[cfg(feature = "rayon")]
+	/// Transform every [`Point`] in `src` in parallel, writing the results into `dst`. Gives the same results as
+	/// [`Transform::transform_points`].
+	pub fn par_transform_points(&self, src: &[Point], dst: &mut [Point]) {
+		par_bulk(src, dst, |src, dst| self.transform_points(src, dst))
+	}
+
+	#[cfg(feature = "rayon")]
+	/// Transform every [`Direction`] in `src` in parallel, writing the results into `dst`. Gives the same results as
+	/// [`Transform::transform_directions`].
+	pub fn par_transform_directions(&self, src: &[Direction], dst: &mut [Direction]) {
+		par_bulk(src, dst, |src, dst| self.transform_directions(src, dst))
+	}
+
+	#[cfg(feature = "rayon")]
+	/// Transform every [`Normal`] in `src` in parallel, writing the results into `dst`. Gives the same results as
+	/// [`Transform::transform_normals`].
+	pub fn par_transform_normals(&self, src: &[Normal], dst: &mut [Normal]) {
+		par_bulk(src, dst, |src, dst| self.transform_normals(src, dst))
+	}
+}
+
+/// Run `kernel` over `src` four elements at a time, writing into `dst`. The last partial group is padded.
+fn bulk<T: Copy>(src: &[T], dst: &mut [T], kernel: impl Fn([T; 4]) -> [T; 4]) {
+	assert_eq!(src.len(), dst.len(), "source and destination lengths differ");
+
+	let mut src_chunks = src.chunks_exact(4);
+	let mut dst_chunks = dst.chunks_exact_mut(4);
+	for (src, dst) in (&mut src_chunks).zip(&mut dst_chunks) {
+		dst.copy_from_slice(&kernel([src[0], src[1], src[2], src[3]]));
+	}
+
+	let rest = src_chunks.remainder();
+	if let Some(&first) = rest.first() {
+		let mut lanes = [first; 4];
+		lanes[..rest.len()].copy_from_slice(rest);
+		dst_chunks
+			.into_remainder()
+			.copy_from_slice(&kernel(lanes)[..rest.len()]);
+	}
+}
+
+#[cfg(feature = "rayon")]
+/// Split `src` and `dst` into chunks, and run the serial `kernel` on each in parallel.
+fn par_bulk<T: Copy + Send + Sync>(src: &[T], dst: &mut [T], kernel: impl Fn(&[T], &mut [T]) + Send + Sync) {
+	assert_eq!(src.len(), dst.len(), "source and destination lengths differ");
+	src.par_chunks(PAR_CHUNK)
+		.zip(dst.par_chunks_mut(PAR_CHUNK))
+		.for_each(|(src, dst)| kernel(src, dst));
+}
+
+/// Run `kernel` over the x, y, and z lanes of four vertices at a time, in place in an interleaved buffer.
+fn strided(
+	data: &mut [u8], offset: usize, stride: usize, kernel: impl Fn(Vector, Vector, Vector) -> (Vector, Vector, Vector),
+) {
+	const SIZE: usize = 3 * std::mem::size_of::<f32>();
+	assert!(stride >= SIZE, "stride is smaller than a vertex");
+
+	let start = offset.min(data.len());
+	let mut vertices = data[start..]
+		.chunks_mut(stride)
+		.filter(|vertex| vertex.len() >= SIZE)
+		.map(|vertex| (&mut vertex[..SIZE]).try_into().unwrap());
+	loop {
+		let mut group: [Option<&mut [u8; SIZE]>; 4] = [None, None, None, None];
+		for slot in group.iter_mut() {
+			*slot = vertices.next();
+		}
+		let lanes = group.iter().take_while(|vertex| vertex.is_some()).count();
+		if lanes == 0 {
+			break;
+		}
+
+		let mut vals = [[0f32; 3]; 4];
+		for (lane, vals) in vals.iter_mut().enumerate() {
+			let vertex = group[lane.min(lanes - 1)].as_ref().unwrap();
+			for (val, bytes) in vals.iter_mut().zip(vertex.chunks_exact(4)) {
+				*val = f32::from_ne_bytes(bytes.try_into().unwrap());
+			}
+		}
+		let gather = |axis: usize| Vector::new(vals[0][axis], vals[1][axis], vals[2][axis], vals[3][axis]);
+
+		let axes = <[Vector; 3]>::from(kernel(gather(0), gather(1), gather(2))).map(<[f32; 4]>::from);
+		for (lane, vertex) in group.iter_mut().take(lanes).enumerate() {
+			let vertex = vertex.as_mut().unwrap();
+			for (bytes, vals) in vertex.chunks_exact_mut(4).zip(axes.iter()) {
+				bytes.copy_from_slice(&vals[lane].to_ne_bytes());
+			}
+		}
+	}
 }
 
 #[cfg(test)]
 mod tests {
 
 	use super::*;
-	use crate::{Direction, Point};
+	use crate::{
+		fixtures,
+		random::{self, Pcg32},
+		Direction,
+		Point,
+	};
 
 	#[test]
 	fn translation() {
@@ -181,4 +373,154 @@ mod tests {
 			Point::new(1f32 / 5f32, 1f32 / 5f32, 1f32 / 5f32)
 		);
 	}
+
+	#[test]
+	fn perspective() {
+		let transform = Transform::perspective(std::f32::consts::FRAC_PI_2, 2f32, 1f32, 10f32);
+		let project = |point: Point| {
+			let clip = point.0 * transform.matrix;
+			clip / clip.w()
+		};
+
+		assert_eq!(project(Point::new(0f32, 0f32, 1f32)).z(), 0f32);
+		assert_eq!(project(Point::new(0f32, 0f32, 10f32)).z(), 1f32);
+		assert_eq!(
+			project(Point::new(4f32, 2f32, 2f32)),
+			Vector::new(1f32, 1f32, 0.5555556f32, 1f32)
+		);
+		assert_eq!(transform.matrix * transform.inverse, Matrix::identity());
+	}
+
+	#[test]
+	fn bulk() {
+		let transform = fixtures::transform();
+		let mut rng = Pcg32::new(1, 0);
+		for &count in [0, 3, 4, 13].iter() {
+			let dirs: Vec<_> = (0..count).map(|_| random::direction(&mut rng) * 5f32).collect();
+
+			let points: Vec<_> = dirs.iter().map(|&dir| Point::new(0f32, 0f32, 0f32) + dir).collect();
+			let mut out = vec![Point::new(0f32, 0f32, 0f32); count];
+			transform.transform_points(&points, &mut out);
+			for (&out, &point) in out.iter().zip(points.iter()) {
+				assert!((out - point * transform).length() < 0.0001f32);
+			}
+
+			let mut out = vec![Direction::default(); count];
+			transform.transform_directions(&dirs, &mut out);
+			for (&out, &dir) in out.iter().zip(dirs.iter()) {
+				assert!((out - dir * transform).length() < 0.0001f32);
+			}
+
+			let normals: Vec<_> = dirs.iter().map(|&dir| Normal(dir.0)).collect();
+			let mut out = vec![Normal(Vector::default()); count];
+			transform.transform_normals(&normals, &mut out);
+			for (&out, &normal) in out.iter().zip(normals.iter()) {
+				assert!((out.0 - (normal * transform).0).length() < 0.0001f32);
+			}
+		}
+	}
+
+	#[test]
+	#[should_panic]
+	fn bulk_mismatched() {
+		let mut out = [Point::new(0f32, 0f32, 0f32); 2];
+		Transform::identity().transform_points(&[Point::new(0f32, 0f32, 0f32); 3], &mut out);
+	}
+
+	#[test]
+	fn strided() {
+		// Interleaved vertices of a position, a normal, and a color, after a header of 4 bytes.
+		let transform = fixtures::transform();
+		let mut rng = Pcg32::new(2, 0);
+		let (header, stride) = (4, 40);
+		for &count in [0, 1, 5, 8].iter() {
+			let vertices: Vec<_> = (0..count)
+				.map(|_| {
+					let position = Point::new(0f32, 0f32, 0f32) + random::direction(&mut rng) * 5f32;
+					(position, random::direction(&mut rng), random::vector(&mut rng))
+				})
+				.collect();
+			let mut data = vec![0xABu8; header];
+			for &(position, normal, color) in vertices.iter() {
+				for val in [
+					position.x(),
+					position.y(),
+					position.z(),
+					normal.x(),
+					normal.y(),
+					normal.z(),
+					color.x(),
+					color.y(),
+					color.z(),
+					color.w(),
+				]
+				.iter()
+				{
+					data.extend_from_slice(&val.to_ne_bytes());
+				}
+			}
+			let read = |data: &[u8], vertex: usize, idx: usize| {
+				let start = header + vertex * stride + idx * 4;
+				f32::from_ne_bytes(data[start..start + 4].try_into().unwrap())
+			};
+
+			transform.transform_points_strided(&mut data, header, stride);
+			transform.transform_normals_strided(&mut data, header + 12, stride);
+			assert!(data[..header].iter().all(|&byte| byte == 0xAB));
+			for (vertex, &(position, normal, color)) in vertices.iter().enumerate() {
+				let expected = position * transform;
+				let moved = Point::new(read(&data, vertex, 0), read(&data, vertex, 1), read(&data, vertex, 2));
+				assert!((moved - expected).length() < 0.0001f32);
+
+				let expected = Normal(normal.0) * transform;
+				let moved = Vector::new(
+					read(&data, vertex, 3),
+					read(&data, vertex, 4),
+					read(&data, vertex, 5),
+					0f32,
+				);
+				assert!((moved - Vector::new(expected.x(), expected.y(), expected.z(), 0f32)).length() < 0.0001f32);
+
+				let untouched = Vector::new(
+					read(&data, vertex, 6),
+					read(&data, vertex, 7),
+					read(&data, vertex, 8),
+					read(&data, vertex, 9),
+				);
+				assert_eq!(untouched, color);
+			}
+
+			transform.inverse().transform_points_strided(&mut data, header, stride);
+			transform.transform_directions_strided(&mut data, header + 12, stride);
+			for (vertex, &(position, ..)) in vertices.iter().enumerate() {
+				let back = Point::new(read(&data, vertex, 0), read(&data, vertex, 1), read(&data, vertex, 2));
+				assert!((back - position).length() < 0.0001f32);
+			}
+		}
+	}
+
+	#[cfg(feature = "rayon")]
+	#[test]
+	fn par_bulk() {
+		let transform = fixtures::transform();
+		let mut rng = Pcg32::new(3, 0);
+		let dirs: Vec<_> = (0..5003).map(|_| random::direction(&mut rng) * 5f32).collect();
+
+		let points: Vec<_> = dirs.iter().map(|&dir| Point::new(0f32, 0f32, 0f32) + dir).collect();
+		let (mut serial, mut parallel) = (points.clone(), points.clone());
+		transform.transform_points(&points, &mut serial);
+		transform.par_transform_points(&points, &mut parallel);
+		assert_eq!(serial, parallel);
+
+		let (mut serial, mut parallel) = (dirs.clone(), dirs.clone());
+		transform.transform_directions(&dirs, &mut serial);
+		transform.par_transform_directions(&dirs, &mut parallel);
+		assert_eq!(serial, parallel);
+
+		let normals: Vec<_> = dirs.iter().map(|&dir| Normal(dir.0)).collect();
+		let (mut serial, mut parallel) = (normals.clone(), normals.clone());
+		transform.transform_normals(&normals, &mut serial);
+		transform.par_transform_normals(&normals, &mut parallel);
+		assert_eq!(serial, parallel);
+	}
 }
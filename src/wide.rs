@@ -0,0 +1,685 @@
+//! Structure-of-arrays types, which hold four or eight 3D values as separate x, y, and z lanes.
+//!
+//! Every lane is independent, so operations like dot and cross products need no shuffles or horizontal sums, and no
+//! lane is wasted on `w`. Convert to and from arrays of [`Point`]s, [`Direction`]s, and [`Normal`]s at the edges of
+//! batch computations.
+//!
+//! [`Vector3x4`] and [`Point3x4`] store each coordinate in a [`Vector`]. [`Vector3x8`] and [`Point3x8`] store them in
+//! a [`Vector8`], which is a single AVX register when the `avx` target feature is enabled, and two [`Vector`]s
+//! otherwise.
+
+use std::{
+	fmt::{Debug, Display},
+	ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{
+	base::{Matrix, Vector, Vector8},
+	direction::Direction,
+	normal::Normal,
+	point::Point,
+	transform::Transform,
+};
+
+/// The lanes of a structure-of-arrays coordinate.
+pub(crate) trait Lanes: Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+	/// Create lanes from a [`Vector`] with every lane already set to the same value.
+	fn broadcast(val: Vector) -> Self;
+}
+
+impl Lanes for Vector {
+	#[inline(always)]
+	fn broadcast(val: Vector) -> Self { val }
+}
+
+impl Lanes for Vector8 {
+	#[inline(always)]
+	fn broadcast(val: Vector) -> Self { Self::from_halves(val, val) }
+}
+
+/// Define a vector and a point type with `$count` lanes, each coordinate of which is a `$lanes`.
+macro_rules! wide {
+	($vector:ident, $point:ident, $lanes:ident, $count:literal, $vector_doc:literal, $point_doc:literal) => {
+		#[repr(C)]
+		#[derive(Copy, Clone, Default, PartialEq)]
+		#[doc = $vector_doc]
+		pub struct $vector {
+			/// The x coordinates.
+			pub x: $lanes,
+			/// The y coordinates.
+			pub y: $lanes,
+			/// The z coordinates.
+			pub z: $lanes,
+		}
+
+		#[repr(C)]
+		#[derive(Copy, Clone, Default, PartialEq)]
+		#[doc = $point_doc]
+		pub struct $point {
+			/// The x coordinates.
+			pub x: $lanes,
+			/// The y coordinates.
+			pub y: $lanes,
+			/// The z coordinates.
+			pub z: $lanes,
+		}
+
+		impl Add for $vector {
+			type Output = Self;
+
+			#[inline(always)]
+			fn add(self, rhs: Self) -> Self::Output { Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z) }
+		}
+
+		impl AddAssign for $vector {
+			#[inline(always)]
+			fn add_assign(&mut self, rhs: Self) { *self = *self + rhs }
+		}
+
+		impl Debug for $vector {
+			#[inline(always)]
+			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				write!(
+					f,
+					"{} {:?}",
+					stringify!($vector),
+					<[Direction; $count]>::from(*self)
+				)
+			}
+		}
+
+		impl Display for $vector {
+			#[inline(always)]
+			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				write!(f, "{} [", stringify!($vector))?;
+				for (idx, dir) in <[Direction; $count]>::from(*self).iter().enumerate() {
+					if idx > 0 {
+						write!(f, ", ")?;
+					}
+					write!(f, "{}", dir)?;
+				}
+				write!(f, "]")
+			}
+		}
+
+		impl Div<$lanes> for $vector {
+			type Output = Self;
+
+			#[inline(always)]
+			fn div(self, rhs: $lanes) -> Self::Output { Self::new(self.x / rhs, self.y / rhs, self.z / rhs) }
+		}
+
+		impl Div<f32> for $vector {
+			type Output = Self;
+
+			#[inline(always)]
+			fn div(self, rhs: f32) -> Self::Output { self * (1f32 / rhs) }
+		}
+
+		impl DivAssign<$lanes> for $vector {
+			#[inline(always)]
+			fn div_assign(&mut self, rhs: $lanes) { *self = *self / rhs }
+		}
+
+		impl DivAssign<f32> for $vector {
+			#[inline(always)]
+			fn div_assign(&mut self, rhs: f32) { *self = *self / rhs }
+		}
+
+		impl Mul<$lanes> for $vector {
+			type Output = Self;
+
+			#[inline(always)]
+			fn mul(self, rhs: $lanes) -> Self::Output { Self::new(self.x * rhs, self.y * rhs, self.z * rhs) }
+		}
+
+		impl Mul<f32> for $vector {
+			type Output = Self;
+
+			#[inline(always)]
+			fn mul(self, rhs: f32) -> Self::Output { Self::new(self.x * rhs, self.y * rhs, self.z * rhs) }
+		}
+
+		impl Mul<Transform> for $vector {
+			type Output = Self;
+
+			#[inline(always)]
+			fn mul(self, rhs: Transform) -> Self::Output {
+				let (x, y, z) = transform(self.x, self.y, self.z, &rhs.matrix, false);
+				Self::new(x, y, z)
+			}
+		}
+
+		impl MulAssign<$lanes> for $vector {
+			#[inline(always)]
+			fn mul_assign(&mut self, rhs: $lanes) { *self = *self * rhs }
+		}
+
+		impl MulAssign<f32> for $vector {
+			#[inline(always)]
+			fn mul_assign(&mut self, rhs: f32) { *self = *self * rhs }
+		}
+
+		impl MulAssign<Transform> for $vector {
+			#[inline(always)]
+			fn mul_assign(&mut self, rhs: Transform) { *self = *self * rhs }
+		}
+
+		impl Neg for $vector {
+			type Output = Self;
+
+			#[inline(always)]
+			fn neg(self) -> Self::Output { Self::default() - self }
+		}
+
+		impl Sub for $vector {
+			type Output = Self;
+
+			#[inline(always)]
+			fn sub(self, rhs: Self) -> Self::Output { Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z) }
+		}
+
+		impl SubAssign for $vector {
+			#[inline(always)]
+			fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs }
+		}
+
+		impl $vector {
+			#[inline(always)]
+			#[doc = concat!("Create a [`", stringify!($vector), "`] from the coordinates of each lane.")]
+			pub const fn new(x: $lanes, y: $lanes, z: $lanes) -> Self { Self { x, y, z } }
+
+			#[inline(always)]
+			#[doc = concat!("Create a [`", stringify!($vector), "`] with `dir` in every lane.")]
+			pub fn splat(dir: Direction) -> Self {
+				Self::new(
+					$lanes::splat(dir.x()),
+					$lanes::splat(dir.y()),
+					$lanes::splat(dir.z()),
+				)
+			}
+
+			#[inline(always)]
+			/// Get the dot product of each lane.
+			pub fn dot(lhs: Self, rhs: Self) -> $lanes { lhs.x * rhs.x + lhs.y * rhs.y + lhs.z * rhs.z }
+
+			#[inline(always)]
+			/// Get the cross product of each lane.
+			pub fn cross(lhs: Self, rhs: Self) -> Self {
+				Self::new(
+					lhs.y * rhs.z - lhs.z * rhs.y,
+					lhs.z * rhs.x - lhs.x * rhs.z,
+					lhs.x * rhs.y - lhs.y * rhs.x,
+				)
+			}
+
+			#[inline(always)]
+			/// Get the square of the length of each lane.
+			pub fn length_square(self) -> $lanes { Self::dot(self, self) }
+
+			#[inline(always)]
+			/// Get the length of each lane.
+			pub fn length(self) -> $lanes { self.length_square().sqrt() }
+
+			#[inline(always)]
+			/// Normalize each lane.
+			pub fn normalize(self) -> Self { self / self.length() }
+
+			#[inline(always)]
+			/// Transform each lane as a [`Normal`], with the inverse transpose of the [`Transform`].
+			pub fn transform_normal(self, transform: Transform) -> Self {
+				let (x, y, z) = transform_transposed(self.x, self.y, self.z, &transform.inverse);
+				Self::new(x, y, z)
+			}
+		}
+
+		impl Add<$vector> for $point {
+			type Output = Self;
+
+			#[inline(always)]
+			fn add(self, rhs: $vector) -> Self::Output { Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z) }
+		}
+
+		impl AddAssign<$vector> for $point {
+			#[inline(always)]
+			fn add_assign(&mut self, rhs: $vector) { *self = *self + rhs }
+		}
+
+		impl Debug for $point {
+			#[inline(always)]
+			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				write!(f, "{} {:?}", stringify!($point), <[Point; $count]>::from(*self))
+			}
+		}
+
+		impl Display for $point {
+			#[inline(always)]
+			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				write!(f, "{} [", stringify!($point))?;
+				for (idx, point) in <[Point; $count]>::from(*self).iter().enumerate() {
+					if idx > 0 {
+						write!(f, ", ")?;
+					}
+					write!(f, "{}", point)?;
+				}
+				write!(f, "]")
+			}
+		}
+
+		impl Mul<Transform> for $point {
+			type Output = Self;
+
+			#[inline(always)]
+			fn mul(self, rhs: Transform) -> Self::Output {
+				let (x, y, z) = transform(self.x, self.y, self.z, &rhs.matrix, true);
+				Self::new(x, y, z)
+			}
+		}
+
+		impl MulAssign<Transform> for $point {
+			#[inline(always)]
+			fn mul_assign(&mut self, rhs: Transform) { *self = *self * rhs }
+		}
+
+		impl Sub for $point {
+			type Output = $vector;
+
+			#[inline(always)]
+			fn sub(self, rhs: Self) -> Self::Output { $vector::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z) }
+		}
+
+		impl Sub<$vector> for $point {
+			type Output = Self;
+
+			#[inline(always)]
+			fn sub(self, rhs: $vector) -> Self::Output { Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z) }
+		}
+
+		impl SubAssign<$vector> for $point {
+			#[inline(always)]
+			fn sub_assign(&mut self, rhs: $vector) { *self = *self - rhs }
+		}
+
+		impl $point {
+			#[inline(always)]
+			#[doc = concat!("Create a [`", stringify!($point), "`] from the coordinates of each lane.")]
+			pub const fn new(x: $lanes, y: $lanes, z: $lanes) -> Self { Self { x, y, z } }
+
+			#[inline(always)]
+			#[doc = concat!("Create a [`", stringify!($point), "`] with `point` in every lane.")]
+			pub fn splat(point: Point) -> Self {
+				Self::new(
+					$lanes::splat(point.x()),
+					$lanes::splat(point.y()),
+					$lanes::splat(point.z()),
+				)
+			}
+
+			#[inline(always)]
+			/// Get the distance between each lane.
+			pub fn distance(lhs: Self, rhs: Self) -> $lanes { (lhs - rhs).length() }
+
+			#[inline(always)]
+			/// Linearly interpolate each lane from `from` to `to`.
+			pub fn lerp(from: Self, to: Self, t: $lanes) -> Self { from + (to - from) * t }
+		}
+	};
+}
+
+wide!(
+	Vector3x4,
+	Point3x4,
+	Vector,
+	4,
+	"Four 3D vectors, used for [`Direction`]s and [`Normal`]s.",
+	"Four [`Point`]s."
+);
+
+wide!(
+	Vector3x8,
+	Point3x8,
+	Vector8,
+	8,
+	"Eight 3D vectors, used for [`Direction`]s and [`Normal`]s.",
+	"Eight [`Point`]s."
+);
+
+impl From<[Direction; 4]> for Vector3x4 {
+	#[inline(always)]
+	fn from(val: [Direction; 4]) -> Self {
+		let (x, y, z) = transpose([val[0].0, val[1].0, val[2].0, val[3].0]);
+		Self::new(x, y, z)
+	}
+}
+
+impl From<[Normal; 4]> for Vector3x4 {
+	#[inline(always)]
+	fn from(val: [Normal; 4]) -> Self {
+		let (x, y, z) = transpose([val[0].0, val[1].0, val[2].0, val[3].0]);
+		Self::new(x, y, z)
+	}
+}
+
+impl From<Vector3x4> for [Direction; 4] {
+	#[inline(always)]
+	fn from(val: Vector3x4) -> Self { untranspose(val.x, val.y, val.z, 0f32).map(Direction) }
+}
+
+impl From<Vector3x4> for [Normal; 4] {
+	#[inline(always)]
+	fn from(val: Vector3x4) -> Self { untranspose(val.x, val.y, val.z, 0f32).map(Normal) }
+}
+
+impl From<[Point; 4]> for Point3x4 {
+	#[inline(always)]
+	fn from(val: [Point; 4]) -> Self {
+		let (x, y, z) = transpose([val[0].0, val[1].0, val[2].0, val[3].0]);
+		Self::new(x, y, z)
+	}
+}
+
+impl From<Point3x4> for [Point; 4] {
+	#[inline(always)]
+	fn from(val: Point3x4) -> Self { untranspose(val.x, val.y, val.z, 1f32).map(Point) }
+}
+
+impl From<[Direction; 8]> for Vector3x8 {
+	#[inline(always)]
+	fn from(val: [Direction; 8]) -> Self {
+		let (low, high) = split(val);
+		Self::from_halves(low.into(), high.into())
+	}
+}
+
+impl From<[Normal; 8]> for Vector3x8 {
+	#[inline(always)]
+	fn from(val: [Normal; 8]) -> Self {
+		let (low, high) = split(val);
+		Self::from_halves(low.into(), high.into())
+	}
+}
+
+impl From<Vector3x8> for [Direction; 8] {
+	#[inline(always)]
+	fn from(val: Vector3x8) -> Self { join(val.low().into(), val.high().into()) }
+}
+
+impl From<Vector3x8> for [Normal; 8] {
+	#[inline(always)]
+	fn from(val: Vector3x8) -> Self { join(val.low().into(), val.high().into()) }
+}
+
+impl Vector3x8 {
+	#[inline(always)]
+	/// Create a [`Vector3x8`] from the [`Vector3x4`]s of lanes 0 to 3 and 4 to 7.
+	pub fn from_halves(low: Vector3x4, high: Vector3x4) -> Self {
+		Self::new(
+			Vector8::from_halves(low.x, high.x),
+			Vector8::from_halves(low.y, high.y),
+			Vector8::from_halves(low.z, high.z),
+		)
+	}
+
+	#[inline(always)]
+	/// Get lanes 0 to 3.
+	pub fn low(self) -> Vector3x4 { Vector3x4::new(self.x.low(), self.y.low(), self.z.low()) }
+
+	#[inline(always)]
+	/// Get lanes 4 to 7.
+	pub fn high(self) -> Vector3x4 { Vector3x4::new(self.x.high(), self.y.high(), self.z.high()) }
+}
+
+impl From<[Point; 8]> for Point3x8 {
+	#[inline(always)]
+	fn from(val: [Point; 8]) -> Self {
+		let (low, high) = split(val);
+		Self::from_halves(low.into(), high.into())
+	}
+}
+
+impl From<Point3x8> for [Point; 8] {
+	#[inline(always)]
+	fn from(val: Point3x8) -> Self { join(val.low().into(), val.high().into()) }
+}
+
+impl Point3x8 {
+	#[inline(always)]
+	/// Create a [`Point3x8`] from the [`Point3x4`]s of lanes 0 to 3 and 4 to 7.
+	pub fn from_halves(low: Point3x4, high: Point3x4) -> Self {
+		Self::new(
+			Vector8::from_halves(low.x, high.x),
+			Vector8::from_halves(low.y, high.y),
+			Vector8::from_halves(low.z, high.z),
+		)
+	}
+
+	#[inline(always)]
+	/// Get lanes 0 to 3.
+	pub fn low(self) -> Point3x4 { Point3x4::new(self.x.low(), self.y.low(), self.z.low()) }
+
+	#[inline(always)]
+	/// Get lanes 4 to 7.
+	pub fn high(self) -> Point3x4 { Point3x4::new(self.x.high(), self.y.high(), self.z.high()) }
+}
+
+#[inline(always)]
+/// Split eight values into lanes 0 to 3 and 4 to 7.
+const fn split<T: Copy>(val: [T; 8]) -> ([T; 4], [T; 4]) {
+	([val[0], val[1], val[2], val[3]], [val[4], val[5], val[6], val[7]])
+}
+
+#[inline(always)]
+/// Join lanes 0 to 3 and 4 to 7, undoing [`split`].
+const fn join<T: Copy>(low: [T; 4], high: [T; 4]) -> [T; 8] {
+	[low[0], low[1], low[2], low[3], high[0], high[1], high[2], high[3]]
+}
+
+#[inline(always)]
+/// Split four [`Vector`]s into their x, y, and z lanes.
+fn transpose(vectors: [Vector; 4]) -> (Vector, Vector, Vector) {
+	let soa = Matrix::row_vectors(vectors).transpose();
+	(soa.get_row(0), soa.get_row(1), soa.get_row(2))
+}
+
+#[inline(always)]
+/// Join x, y, and z lanes back into four [`Vector`]s, with `w` in every one.
+fn untranspose(x: Vector, y: Vector, z: Vector, w: f32) -> [Vector; 4] {
+	let aos = Matrix::row_vectors([x, y, z, Vector::splat(w)]).transpose();
+	[aos.get_row(0), aos.get_row(1), aos.get_row(2), aos.get_row(3)]
+}
+
+#[inline(always)]
+/// Multiply every lane as a row vector by `matrix`, with a `w` of 1 if `translate` is true, and 0 otherwise.
+pub(crate) fn transform<L: Lanes>(x: L, y: L, z: L, matrix: &Matrix, translate: bool) -> (L, L, L) {
+	let m = [0, 1, 2, 3].map(|row| splat_row::<L>(matrix.get_row(row)));
+	let axis = |column: usize| {
+		let val = x * m[0][column] + y * m[1][column] + z * m[2][column];
+		if translate {
+			val + m[3][column]
+		} else {
+			val
+		}
+	};
+	(axis(0), axis(1), axis(2))
+}
+
+#[inline(always)]
+/// Multiply every lane as a row vector with a `w` of 0 by the transpose of `matrix`.
+pub(crate) fn transform_transposed<L: Lanes>(x: L, y: L, z: L, matrix: &Matrix) -> (L, L, L) {
+	let m = [0, 1, 2].map(|row| splat_row::<L>(matrix.get_row(row)));
+	let axis = |row: usize| x * m[row][0] + y * m[row][1] + z * m[row][2];
+	(axis(0), axis(1), axis(2))
+}
+
+#[inline(always)]
+/// Splat each of the first three elements of `row` across its own set of lanes.
+fn splat_row<L: Lanes>(row: Vector) -> [L; 3] {
+	[
+		L::broadcast(row.shuffle::<0, 0, 0, 0>()),
+		L::broadcast(row.shuffle::<1, 1, 1, 1>()),
+		L::broadcast(row.shuffle::<2, 2, 2, 2>()),
+	]
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		base::nearly_equal,
+		fixtures,
+		random::{self, Pcg32},
+	};
+
+	fn directions(rng: &mut Pcg32) -> [Direction; 4] { [(); 4].map(|_| random::direction(rng) * 3f32) }
+
+	fn assert_lanes(wide: Vector, expected: [f32; 4]) {
+		for (lane, &expected) in expected.iter().enumerate() {
+			assert!(nearly_equal(wide.get(lane as u8), expected, 0.0001f32));
+		}
+	}
+
+	fn assert_directions(wide: Vector3x4, expected: [Direction; 4]) {
+		let lanes: [Direction; 4] = wide.into();
+		for (lane, expected) in lanes.iter().zip(expected.iter()) {
+			assert!((*lane - *expected).length() < 0.0001f32, "{} {}", lane, expected);
+		}
+	}
+
+	#[test]
+	fn conversions() {
+		let mut rng = Pcg32::new(1, 0);
+		let dirs = directions(&mut rng);
+		assert_eq!(<[Direction; 4]>::from(Vector3x4::from(dirs)), dirs);
+		let normals = dirs.map(|dir| Normal(dir.0));
+		assert_eq!(<[Normal; 4]>::from(Vector3x4::from(normals)), normals);
+		let points = dirs.map(|dir| Point::new(0f32, 0f32, 0f32) + dir);
+		assert_eq!(<[Point; 4]>::from(Point3x4::from(points)), points);
+	}
+
+	#[test]
+	fn arithmetic() {
+		let mut rng = Pcg32::new(2, 0);
+		let (a, b) = (directions(&mut rng), directions(&mut rng));
+		let (wide_a, wide_b) = (Vector3x4::from(a), Vector3x4::from(b));
+
+		assert_directions(wide_a + wide_b, [0, 1, 2, 3].map(|idx| a[idx] + b[idx]));
+		assert_directions(wide_a - wide_b, [0, 1, 2, 3].map(|idx| a[idx] - b[idx]));
+		assert_directions(-wide_a, a.map(|dir| -dir));
+		assert_directions(wide_a * 2f32, a.map(|dir| dir * 2f32));
+		let scale = Vector::new(1f32, 2f32, 3f32, 4f32);
+		assert_directions(wide_a * scale, [0, 1, 2, 3].map(|idx| a[idx] * scale.get(idx as u8)));
+		assert_directions(wide_a / scale, [0, 1, 2, 3].map(|idx| a[idx] / scale.get(idx as u8)));
+
+		assert_lanes(
+			Vector3x4::dot(wide_a, wide_b),
+			[0, 1, 2, 3].map(|idx| Direction::dot(a[idx], b[idx])),
+		);
+		assert_directions(
+			Vector3x4::cross(wide_a, wide_b),
+			[0, 1, 2, 3].map(|idx| Direction::cross(a[idx], b[idx])),
+		);
+		assert_lanes(wide_a.length(), a.map(|dir| dir.length()));
+		assert_directions(wide_a.normalize(), a.map(|dir| dir.normalize()));
+		assert_directions(Vector3x4::splat(a[0]), [a[0]; 4]);
+	}
+
+	#[test]
+	fn points() {
+		let mut rng = Pcg32::new(3, 0);
+		let origin = Point::new(1f32, 2f32, 3f32);
+		let (a, b) = (directions(&mut rng), directions(&mut rng));
+		let (p, q) = (a.map(|dir| origin + dir), b.map(|dir| origin + dir));
+		let (wide_p, wide_q) = (Point3x4::from(p), Point3x4::from(q));
+
+		assert_directions(wide_p - wide_q, [0, 1, 2, 3].map(|idx| p[idx] - q[idx]));
+		assert_lanes(
+			Point3x4::distance(wide_p, wide_q),
+			[0, 1, 2, 3].map(|idx| (p[idx] - q[idx]).length()),
+		);
+		let moved: [Point; 4] = (wide_p + Vector3x4::from(b) - Vector3x4::splat(a[0])).into();
+		for idx in 0..4 {
+			assert!((moved[idx] - (p[idx] + b[idx] - a[0])).length() < 0.0001f32);
+		}
+		let halfway: [Point; 4] = Point3x4::lerp(wide_p, wide_q, Vector::splat(0.5f32)).into();
+		for idx in 0..4 {
+			assert!((halfway[idx] - Point::lerp(p[idx], q[idx], 0.5f32)).length() < 0.0001f32);
+		}
+		assert_eq!(<[Point; 4]>::from(Point3x4::splat(origin)), [origin; 4]);
+	}
+
+	#[test]
+	fn transforms() {
+		let mut rng = Pcg32::new(4, 0);
+		let transform = fixtures::transform();
+		let dirs = directions(&mut rng);
+		let points = dirs.map(|dir| Point::new(0f32, 0f32, 0f32) + dir);
+
+		assert_directions(Vector3x4::from(dirs) * transform, dirs.map(|dir| dir * transform));
+		let moved: [Point; 4] = (Point3x4::from(points) * transform).into();
+		for (moved, &point) in moved.iter().zip(points.iter()) {
+			assert!((*moved - point * transform).length() < 0.0001f32);
+		}
+
+		let normals = dirs.map(|dir| Normal(dir.0));
+		// The w of transformed Normals picks up the translation, so only compare x, y, and z.
+		let expected = normals.map(|normal| {
+			let normal = normal * transform;
+			Direction::new(normal.x(), normal.y(), normal.z())
+		});
+		assert_directions(Vector3x4::from(normals).transform_normal(transform), expected);
+	}
+
+	#[test]
+	fn eight_lanes() {
+		let mut rng = Pcg32::new(5, 0);
+		let transform = fixtures::transform();
+		let (a, b) = (
+			join(directions(&mut rng), directions(&mut rng)),
+			join(directions(&mut rng), directions(&mut rng)),
+		);
+		let points = a.map(|dir| Point::new(1f32, 2f32, 3f32) + dir);
+		let (wide_a, wide_b) = (Vector3x8::from(a), Vector3x8::from(b));
+		assert_eq!(<[Direction; 8]>::from(wide_a), a);
+		assert_eq!(
+			<[Normal; 8]>::from(Vector3x8::from(a.map(|dir| Normal(dir.0)))),
+			a.map(|dir| Normal(dir.0))
+		);
+		assert_eq!(<[Point; 8]>::from(Point3x8::from(points)), points);
+
+		// Every operation must match the four-wide one on each half.
+		let halves = |wide: Vector3x8| [wide.low(), wide.high()];
+		let check = |wide: Vector3x8, expected: [Vector3x4; 2]| {
+			assert_directions(wide.low(), expected[0].into());
+			assert_directions(wide.high(), expected[1].into());
+		};
+		let (four_a, four_b) = (halves(wide_a), halves(wide_b));
+		check(wide_a + wide_b, [four_a[0] + four_b[0], four_a[1] + four_b[1]]);
+		check(-wide_a * 2f32, [-four_a[0] * 2f32, -four_a[1] * 2f32]);
+		check(
+			Vector3x8::cross(wide_a, wide_b),
+			[0, 1].map(|idx| Vector3x4::cross(four_a[idx], four_b[idx])),
+		);
+		check(wide_a.normalize(), [four_a[0].normalize(), four_a[1].normalize()]);
+		check(wide_a * transform, [four_a[0] * transform, four_a[1] * transform]);
+		check(
+			wide_a.transform_normal(transform),
+			[0, 1].map(|idx| four_a[idx].transform_normal(transform)),
+		);
+		let dot = Vector3x8::dot(wide_a, wide_b);
+		assert_lanes(dot.low(), <[f32; 4]>::from(Vector3x4::dot(four_a[0], four_b[0])));
+		assert_lanes(dot.high(), <[f32; 4]>::from(Vector3x4::dot(four_a[1], four_b[1])));
+
+		let moved: [Point; 8] = (Point3x8::from(points) * transform + wide_b).into();
+		for idx in 0..8 {
+			assert!((moved[idx] - (points[idx] * transform + b[idx])).length() < 0.0001f32);
+		}
+		let distance = Point3x8::distance(Point3x8::from(points), Point3x8::splat(points[0]));
+		for (idx, &point) in points.iter().enumerate() {
+			assert!(nearly_equal(
+				distance.get(idx as u8),
+				(point - points[0]).length(),
+				0.0001f32
+			));
+		}
+	}
+}
@@ -0,0 +1,106 @@
+//! Algebraic invariants, checked on random inputs.
+
+use proptest::prelude::*;
+use spaceform::{
+	approx::Tolerance,
+	assert_approx_eq,
+	base::{Matrix, Quaternion},
+	random::{self, Pcg32},
+	Direction,
+	Normal,
+	Rotation,
+	Transform,
+};
+
+/// Sample with a [`Pcg32`] seeded by proptest, so the values have the distribution `sample` is documented to have.
+fn seeded<T: std::fmt::Debug>(sample: fn(&mut Pcg32) -> T) -> impl Strategy<Value = T> {
+	any::<u64>().prop_map(move |seed| sample(&mut Pcg32::new(seed, 0)))
+}
+
+fn unit_direction() -> impl Strategy<Value = Direction> { seeded(random::direction) }
+
+fn direction() -> impl Strategy<Value = Direction> {
+	(unit_direction(), 0f32..10f32).prop_map(|(dir, length)| dir * length)
+}
+
+fn quaternion() -> impl Strategy<Value = Quaternion> {
+	[-1f32..1f32, -1f32..1f32, -1f32..1f32, -1f32..1f32].prop_map(|[x, y, z, w]| Quaternion::new(x, y, z, w))
+}
+
+fn rotation() -> impl Strategy<Value = Rotation> { seeded(random::rotation) }
+
+/// A scale in [0.5, 2] or [-2, -0.5] along each axis, so the [`Transform`] is well-conditioned.
+fn scale() -> impl Strategy<Value = Direction> {
+	let factor = || (0.5f32..2f32, any::<bool>()).prop_map(|(scale, flip)| if flip { -scale } else { scale });
+	(factor(), factor(), factor()).prop_map(|(x, y, z)| Direction::new(x, y, z))
+}
+
+fn transform() -> impl Strategy<Value = Transform> {
+	(scale(), rotation(), direction()).prop_map(|(scale, rotation, translation)| {
+		Transform::scale(scale) * Transform::rotate(rotation) * Transform::translate(translation)
+	})
+}
+
+fn rows(range: std::ops::Range<f32>) -> impl Strategy<Value = [[f32; 4]; 4]> {
+	let row = || [range.clone(), range.clone(), range.clone(), range.clone()];
+	[row(), row(), row(), row()]
+}
+
+/// A diagonally dominant [`Matrix`], which is always well-conditioned.
+fn matrix() -> impl Strategy<Value = Matrix> {
+	rows(-1f32..1f32).prop_map(|mut rows| {
+		for (idx, row) in rows.iter_mut().enumerate() {
+			row[idx] += if row[idx] < 0f32 { -5f32 } else { 5f32 };
+		}
+		Matrix::rows(rows)
+	})
+}
+
+fn length(quat: Quaternion) -> f32 { Quaternion::dot(quat, quat).sqrt() }
+
+proptest! {
+	#[test]
+	fn matrix_inverse(mat in matrix()) {
+		assert_approx_eq!(mat * mat.inverse(), Matrix::identity());
+		assert_approx_eq!(mat.inverse() * mat, Matrix::identity());
+	}
+
+	#[test]
+	fn matrix_transpose(rows in rows(-10f32..10f32)) {
+		let mat = Matrix::rows(rows);
+		assert_approx_eq!(mat.transpose().transpose(), mat, Tolerance::Ulps(0));
+	}
+
+	#[test]
+	fn quaternion_associativity(a in quaternion(), b in quaternion(), c in quaternion()) {
+		assert_approx_eq!((a * b) * c, a * (b * c));
+	}
+
+	#[test]
+	fn quaternion_length(a in quaternion(), b in quaternion()) {
+		assert_approx_eq!(length(a * b), length(a) * length(b), Tolerance::Relative(0.0001f32));
+	}
+
+	#[test]
+	fn rotation_preserves_length(rotation in rotation(), dir in direction()) {
+		let rotated = dir * Transform::rotate(rotation);
+		assert_approx_eq!(rotated.length(), dir.length(), Tolerance::Relative(0.0001f32));
+	}
+
+	#[test]
+	fn transform_inverse(transform in transform()) {
+		assert_approx_eq!(transform * transform.inverse(), Transform::identity(), Tolerance::Absolute(0.0005f32));
+		assert_approx_eq!(transform.inverse() * transform, Transform::identity(), Tolerance::Absolute(0.0005f32));
+	}
+
+	#[test]
+	fn normal_stays_perpendicular(transform in transform(), normal in unit_direction(), other in unit_direction()) {
+		let tangent = Direction::cross(normal, other);
+		prop_assume!(tangent.length() > 0.1f32);
+
+		let normal = Normal::new(normal.x(), normal.y(), normal.z()) * transform;
+		let normal = Direction::new(normal.x(), normal.y(), normal.z()).normalize();
+		let tangent = (tangent * transform).normalize();
+		assert_approx_eq!(Direction::dot(normal, tangent), 0f32);
+	}
+}